@@ -93,13 +93,19 @@ impl<'a> Analyzer<'a> {
     self.exec_exhaustively("async/generator", false, true, Rc::new(runner));
   }
 
+  /// Runs a callback which is called asynchronously, like the reactions of promises.
+  /// Returns whether it reads anything that may be changed later, in which case it is re-run then.
+  pub fn exec_async_callback(&mut self, runner: impl Fn(&mut Analyzer<'a>) + 'a) -> bool {
+    self.exec_exhaustively("async callback", false, true, Rc::new(runner))
+  }
+
   fn exec_exhaustively(
     &mut self,
     _kind: &str,
     drain: bool,
     register: bool,
     runner: Rc<dyn Fn(&mut Analyzer<'a>) + 'a>,
-  ) {
+  ) -> bool {
     self.push_cf_scope(
       CfScopeKind::Exhaustive(ExhaustiveData {
         clean: true,
@@ -129,7 +135,11 @@ impl<'a> Analyzer<'a> {
     let id = self.pop_cf_scope();
     let data = self.scoping.cf.get_mut(id).exhaustive_data_mut().unwrap();
     if let Some(register_deps) = data.register_deps.take() {
+      let registered = !register_deps.is_empty();
       self.register_exhaustive_callbacks(drain, runner, register_deps);
+      registered
+    } else {
+      false
     }
   }
 
@@ -1,6 +1,7 @@
 use std::{
   cell::{Cell, RefCell},
   fmt::Debug,
+  marker::PhantomData,
 };
 
 use oxc::allocator::{self, Allocator};
@@ -9,20 +10,25 @@ use oxc_syntax::operator::LogicalOperator;
 
 use crate::{
   TreeShakeConfig,
+  builtins::BuiltinPrototype,
   dep::{CustomDepTrait, Dep, DepCollector, DepTrait, LazyDep, OnceDep},
   entity::Entity,
   mangling::{AlwaysMangableDep, MangleAtom, MangleConstraint, ManglingDep},
   scope::CfScopeId,
   utils::F64WithEq,
   value::{
-    LiteralValue, ObjectId, ObjectProperty, ObjectPrototype, ObjectValue,
+    LiteralValue, ObjectId, ObjectIntegrity, ObjectProperty, ObjectPrototype, ObjectValue,
     arguments::ArgumentsValue,
     array::ArrayValue,
-    builtin_fn::{BuiltinFnImplementation, ImplementedBuiltinFnValue, PureBuiltinFnValue},
+    builtin_fn::{
+      BuiltinConstructorCall, BuiltinConstructorImplementation, BuiltinFnImplementation,
+      ImplementedBuiltinConstructorValue, ImplementedBuiltinFnValue, PureBuiltinFnValue,
+    },
     logical_result::LogicalResultValue,
     never::NeverValue,
     primitive::PrimitiveValue,
     react_element::ReactElementValue,
+    symbol::well_known_symbol_id,
     union::UnionValues,
     unknown::UnknownValue,
   },
@@ -179,10 +185,13 @@ impl<'a> Factory<'a> {
       cf_scope: CfScopeId::new(0),
       object_id,
       keyed: allocator::HashMap::new_in(self.allocator).into(),
+      key_order: None.into(),
       unknown: ObjectProperty::new_in(self.allocator).into(),
       rest: Default::default(),
       prototype: Cell::new(prototype),
       mangling_group: Cell::new(None),
+      integrity: Cell::new(Some(ObjectIntegrity::Extensible)),
+      integrity_deps: DepCollector::new(self.vec()).into(),
     })
   }
 
@@ -194,10 +203,12 @@ impl<'a> Factory<'a> {
     self.alloc(ArrayValue {
       consumed: Cell::new(false),
       deps: RefCell::new(DepCollector::new(self.vec())),
+      mutations: RefCell::new(DepCollector::new(self.vec())),
       cf_scope,
       object_id,
       elements: RefCell::new(self.vec()),
       rest: RefCell::new(self.vec()),
+      named: RefCell::new(self.vec()),
     })
   }
 
@@ -216,6 +227,28 @@ impl<'a> Factory<'a> {
       .into()
   }
 
+  /// A builtin class with static members, whose instances inherit from `prototype` if they are
+  /// tracked. Calling it without `new` throws unless `call` is provided.
+  pub fn implemented_builtin_class<C: BuiltinConstructorImplementation<'a> + 'a>(
+    &self,
+    name: &'static str,
+    object: &'a ObjectValue<'a>,
+    prototype: Option<&'a BuiltinPrototype<'a>>,
+    call: Option<BuiltinConstructorCall<'a>>,
+    implementation: C,
+  ) -> Entity<'a> {
+    self
+      .alloc(ImplementedBuiltinConstructorValue {
+        name,
+        implementation,
+        object,
+        prototype,
+        call,
+        phantom: PhantomData,
+      })
+      .into()
+  }
+
   pub fn implemented_consumable_fn<F: BuiltinFnImplementation<'a> + 'a>(
     &self,
     name: &'static str,
@@ -280,6 +313,12 @@ impl<'a> Factory<'a> {
     self.alloc(LiteralValue::Symbol(id, str_rep)).into()
   }
 
+  /// `Symbol.iterator` and the like
+  pub fn well_known_symbol(&self, name: &str) -> Entity<'a> {
+    let str_rep = self.allocator.alloc_str(&format!("Symbol(Symbol.{name})"));
+    self.symbol(well_known_symbol_id(name), str_rep)
+  }
+
   /// Only used when (maybe_left, maybe_right) == (true, true)
   pub fn logical_result(
     &self,
@@ -5,7 +5,7 @@ use crate::{
   analyzer::Analyzer,
   entity::Entity,
   mangling::MangleConstraint,
-  value::{LiteralValue, TypeofResult, ValueTrait},
+  value::{LiteralValue, ObjectPrototype, TypeofResult, ValueTrait},
 };
 
 impl<'a> Analyzer<'a> {
@@ -158,20 +158,10 @@ impl<'a> Analyzer<'a> {
     self.op_lt(rhs, lhs, eq)
   }
 
-  pub fn op_instanceof(&self, lhs: Entity<'a>, _rhs: Entity<'a>) -> Option<bool> {
-    if (TypeofResult::String
-      | TypeofResult::Number
-      | TypeofResult::BigInt
-      | TypeofResult::Boolean
-      | TypeofResult::Symbol
-      | TypeofResult::Undefined)
-      .contains(lhs.test_typeof())
-      || lhs.test_nullish() == Some(true)
-    {
-      Some(false)
-    } else {
-      None
-    }
+  pub fn op_instanceof(&self, lhs: Entity<'a>, rhs: Entity<'a>) -> Option<bool> {
+    let prototype =
+      rhs.get_instance_prototype().unwrap_or(ObjectPrototype::Unknown(self.factory.no_dep));
+    lhs.test_instanceof(self, prototype)
   }
 
   pub fn op_add(&self, lhs: Entity<'a>, rhs: Entity<'a>) -> Entity<'a> {
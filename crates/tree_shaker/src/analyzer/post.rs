@@ -1,12 +1,18 @@
-use crate::module::ModuleId;
-
 use super::Analyzer;
 
 impl Analyzer<'_> {
   pub fn post_analysis(&mut self) {
-    self.module_stack.push(ModuleId::new(0));
+    let entries = self.modules.entries.clone();
+    for &entry in &entries {
+      self.module_stack.push(entry);
+      self.consume_exports(entry);
+      self.module_stack.pop();
+    }
 
-    self.consume_exports(ModuleId::new(0));
+    let Some(&main_entry) = entries.first() else {
+      return;
+    };
+    self.module_stack.push(main_entry);
 
     let mut round = 0usize;
     loop {
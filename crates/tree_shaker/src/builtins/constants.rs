@@ -8,3 +8,25 @@ pub const REACT_NAMESPACE_OBJECT_ID: ObjectId = unsafe { mem::transmute(2u32) };
 pub const REACT_JSX_RUNTIME_NAMESPACE_OBJECT_ID: ObjectId = unsafe { mem::transmute(3u32) };
 pub const OBJECT_CONSTRUCTOR_OBJECT_ID: ObjectId = unsafe { mem::transmute(4u32) };
 pub const SYMBOL_CONSTRUCTOR_OBJECT_ID: ObjectId = unsafe { mem::transmute(5u32) };
+pub const MATH_OBJECT_ID: ObjectId = unsafe { mem::transmute(6u32) };
+pub const JSON_OBJECT_ID: ObjectId = unsafe { mem::transmute(7u32) };
+pub const ARRAY_CONSTRUCTOR_OBJECT_ID: ObjectId = unsafe { mem::transmute(8u32) };
+pub const NUMBER_CONSTRUCTOR_OBJECT_ID: ObjectId = unsafe { mem::transmute(9u32) };
+pub const STRING_CONSTRUCTOR_OBJECT_ID: ObjectId = unsafe { mem::transmute(10u32) };
+pub const BOOLEAN_CONSTRUCTOR_OBJECT_ID: ObjectId = unsafe { mem::transmute(11u32) };
+pub const BIGINT_CONSTRUCTOR_OBJECT_ID: ObjectId = unsafe { mem::transmute(12u32) };
+pub const REFLECT_OBJECT_ID: ObjectId = unsafe { mem::transmute(13u32) };
+pub const PROMISE_CONSTRUCTOR_OBJECT_ID: ObjectId = unsafe { mem::transmute(14u32) };
+pub const DEFINE_OBJECT_ID: ObjectId = unsafe { mem::transmute(15u32) };
+pub const REGEXP_CONSTRUCTOR_OBJECT_ID: ObjectId = unsafe { mem::transmute(16u32) };
+pub const ERROR_CONSTRUCTOR_OBJECT_ID: ObjectId = unsafe { mem::transmute(17u32) };
+pub const CONSOLE_OBJECT_ID: ObjectId = unsafe { mem::transmute(18u32) };
+pub const DATE_CONSTRUCTOR_OBJECT_ID: ObjectId = unsafe { mem::transmute(19u32) };
+pub const INTL_OBJECT_ID: ObjectId = unsafe { mem::transmute(20u32) };
+pub const INTL_CONSTRUCTOR_OBJECT_ID: ObjectId = unsafe { mem::transmute(21u32) };
+pub const ARRAY_BUFFER_CONSTRUCTOR_OBJECT_ID: ObjectId = unsafe { mem::transmute(22u32) };
+pub const TYPED_ARRAY_CONSTRUCTOR_OBJECT_ID: ObjectId = unsafe { mem::transmute(23u32) };
+pub const DATA_VIEW_CONSTRUCTOR_OBJECT_ID: ObjectId = unsafe { mem::transmute(24u32) };
+pub const TEXT_CODEC_CONSTRUCTOR_OBJECT_ID: ObjectId = unsafe { mem::transmute(25u32) };
+pub const PROXY_CONSTRUCTOR_OBJECT_ID: ObjectId = unsafe { mem::transmute(26u32) };
+pub const COLLECTION_CONSTRUCTOR_OBJECT_ID: ObjectId = unsafe { mem::transmute(27u32) };
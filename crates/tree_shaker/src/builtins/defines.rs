@@ -0,0 +1,200 @@
+use std::collections::BTreeMap;
+
+use oxc::{
+  ast::ast::{ArrayExpressionElement, Expression, PropertyKey, PropertyKind},
+  span::{GetSpan, Span},
+};
+
+use super::{Builtins, constants::DEFINE_OBJECT_ID, prototypes::BuiltinPrototypes};
+use crate::{
+  TreeShakeConfig,
+  analyzer::Factory,
+  entity::Entity,
+  init_object,
+  scope::CfScopeId,
+  transformer::Transformer,
+  value::{LiteralValue, ObjectPropertyValue, ObjectPrototype, ObjectValue},
+};
+
+/// The defines grouped by the segments of their paths
+#[derive(Default)]
+pub struct DefineTree<'a> {
+  value: Option<&'a serde_json::Value>,
+  children: BTreeMap<&'a str, DefineTree<'a>>,
+}
+
+impl<'a> DefineTree<'a> {
+  pub fn new(config: &'a TreeShakeConfig) -> Self {
+    let mut tree = DefineTree::default();
+    for (path, value) in &config.defines {
+      let mut node = &mut tree;
+      for segment in path.split('.') {
+        node = node.children.entry(segment).or_default();
+      }
+      node.value = Some(value);
+    }
+    tree
+  }
+
+  /// The defines under `import.meta`
+  pub fn import_meta(&self) -> Option<&DefineTree<'a>> {
+    self.children.get("import")?.children.get("meta")
+  }
+}
+
+impl<'a> Builtins<'a> {
+  pub fn init_defines(&mut self, tree: &DefineTree<'a>) {
+    for (name, node) in &tree.children {
+      if *name == "import" {
+        // Handled by `create_import_meta`
+        continue;
+      }
+      let value = Self::create_defined_value(self.factory, self.prototypes, node);
+      self.globals.insert(name, value);
+    }
+  }
+
+  /// A defined value, or a namespace whose unknown properties are left as is
+  fn create_defined_value(
+    factory: &'a Factory<'a>,
+    prototypes: &'a BuiltinPrototypes<'a>,
+    node: &DefineTree<'a>,
+  ) -> Entity<'a> {
+    if let Some(value) = node.value {
+      return Self::create_json_value(factory, prototypes, value);
+    }
+    let object =
+      factory.builtin_object(DEFINE_OBJECT_ID, ObjectPrototype::Builtin(&prototypes.object), false);
+    object.init_rest(
+      factory,
+      ObjectPropertyValue::Property(Some(factory.unknown), Some(factory.unknown)),
+    );
+    Self::init_defined_properties(factory, prototypes, object, node);
+    object.into()
+  }
+
+  pub fn init_defined_properties(
+    factory: &'a Factory<'a>,
+    prototypes: &'a BuiltinPrototypes<'a>,
+    object: &ObjectValue<'a>,
+    node: &DefineTree<'a>,
+  ) {
+    for (key, child) in &node.children {
+      let value = Self::create_defined_value(factory, prototypes, child);
+      init_object!(object, factory, {
+        key => value,
+      });
+    }
+  }
+
+  fn create_json_value(
+    factory: &'a Factory<'a>,
+    prototypes: &'a BuiltinPrototypes<'a>,
+    value: &'a serde_json::Value,
+  ) -> Entity<'a> {
+    match value {
+      serde_json::Value::Null => factory.null,
+      serde_json::Value::Bool(value) => factory.boolean(*value),
+      serde_json::Value::Number(value) => factory.number(value.as_f64().unwrap(), None),
+      serde_json::Value::String(value) => factory.string(value),
+      serde_json::Value::Array(elements) => {
+        let array = factory.array(CfScopeId::new(0), DEFINE_OBJECT_ID);
+        for element in elements {
+          array.push_element(Self::create_json_value(factory, prototypes, element));
+        }
+        array.into()
+      }
+      serde_json::Value::Object(properties) => {
+        let object = factory.builtin_object(
+          DEFINE_OBJECT_ID,
+          ObjectPrototype::Builtin(&prototypes.object),
+          false,
+        );
+        for (key, value) in properties {
+          let value = Self::create_json_value(factory, prototypes, value);
+          init_object!(object, factory, {
+            key => value,
+          });
+        }
+        object.into()
+      }
+    }
+  }
+}
+
+impl<'a> Transformer<'a> {
+  /// Substitutes a defined path, like `process.env.NODE_ENV`, with its value. This is needed where
+  /// the value isn't folded, because the path may not exist at runtime.
+  pub fn build_defined_value(&self, node: &'a Expression<'a>) -> Option<Expression<'a>> {
+    if self.config.defines.is_empty() {
+      return None;
+    }
+    let mut path = String::new();
+    self.get_define_path(node, &mut path)?;
+    let value = self.config.defines.get(&path)?;
+    Some(self.build_json_value(node.span(), value))
+  }
+
+  fn get_define_path(&self, node: &'a Expression<'a>, path: &mut String) -> Option<()> {
+    match node {
+      Expression::Identifier(node) => {
+        let reference = self.semantic.scoping().get_reference(node.reference_id());
+        if reference.symbol_id().is_some() {
+          return None;
+        }
+        path.push_str(&node.name);
+      }
+      Expression::MetaProperty(node) => {
+        path.push_str(&node.meta.name);
+        path.push('.');
+        path.push_str(&node.property.name);
+      }
+      Expression::StaticMemberExpression(node) if !node.optional => {
+        self.get_define_path(&node.object, path)?;
+        path.push('.');
+        path.push_str(&node.property.name);
+      }
+      _ => return None,
+    }
+    Some(())
+  }
+
+  fn build_json_value(&self, span: Span, value: &'a serde_json::Value) -> Expression<'a> {
+    let literal = match value {
+      serde_json::Value::Null => LiteralValue::Null,
+      serde_json::Value::Bool(value) => LiteralValue::Boolean(*value),
+      serde_json::Value::Number(value) => {
+        LiteralValue::Number(value.as_f64().unwrap().into(), None)
+      }
+      serde_json::Value::String(value) => LiteralValue::String(value, None),
+      serde_json::Value::Array(elements) => {
+        let mut transformed_elements = self.ast_builder.vec();
+        for element in elements {
+          transformed_elements
+            .push(ArrayExpressionElement::from(self.build_json_value(span, element)));
+        }
+        return self.ast_builder.expression_array(span, transformed_elements);
+      }
+      serde_json::Value::Object(properties) => {
+        let mut transformed_properties = self.ast_builder.vec();
+        for (key, value) in properties {
+          transformed_properties.push(self.ast_builder.object_property_kind_object_property(
+            span,
+            PropertyKind::Init,
+            PropertyKey::StringLiteral(self.ast_builder.alloc_string_literal(
+              span,
+              key.as_str(),
+              None,
+            )),
+            self.build_json_value(span, value),
+            false,
+            false,
+            false,
+          ));
+        }
+        return self.ast_builder.expression_object(span, transformed_properties);
+      }
+    };
+    literal.build_expr(self, span, None)
+  }
+}
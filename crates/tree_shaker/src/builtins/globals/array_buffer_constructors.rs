@@ -0,0 +1,262 @@
+use crate::{
+  analyzer::Analyzer,
+  builtins::{
+    Builtins,
+    constants::{
+      ARRAY_BUFFER_CONSTRUCTOR_OBJECT_ID, DATA_VIEW_CONSTRUCTOR_OBJECT_ID,
+      TYPED_ARRAY_CONSTRUCTOR_OBJECT_ID,
+    },
+  },
+  dep::Dep,
+  entity::Entity,
+  init_namespace,
+  value::{
+    LiteralValue, ObjectPropertyValue, ObjectPrototype, TypeofResult,
+    array_buffer::ArrayBufferViewKind,
+  },
+};
+
+/// The largest length of a buffer, above which the constructors throw
+const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+
+impl Builtins<'_> {
+  pub fn init_array_buffer_constructors(&mut self) {
+    self.init_array_buffer_constructor();
+    for kind in ArrayBufferViewKind::TYPED_ARRAYS {
+      self.init_typed_array_constructor(kind);
+    }
+    self.init_data_view_constructor();
+  }
+
+  fn init_array_buffer_constructor(&mut self) {
+    let factory = self.factory;
+
+    let object = factory.builtin_object(
+      ARRAY_BUFFER_CONSTRUCTOR_OBJECT_ID,
+      ObjectPrototype::Builtin(&self.prototypes.function),
+      false,
+    );
+    object.init_rest(factory, ObjectPropertyValue::Field(factory.unknown, true));
+
+    init_namespace!(object, factory, {
+      "prototype" => factory.unknown,
+      "isView" => factory.pure_fn_returns_boolean,
+    });
+
+    let constructor = factory.implemented_builtin_class(
+      "ArrayBuffer",
+      object,
+      Some(&self.prototypes.array_buffer),
+      None,
+      construct_array_buffer,
+    );
+    self.globals.insert("ArrayBuffer", constructor);
+  }
+
+  fn init_typed_array_constructor(&mut self, kind: ArrayBufferViewKind) {
+    let factory = self.factory;
+
+    let object = factory.builtin_object(
+      TYPED_ARRAY_CONSTRUCTOR_OBJECT_ID,
+      ObjectPrototype::Builtin(&self.prototypes.function),
+      false,
+    );
+    object.init_rest(factory, ObjectPropertyValue::Field(factory.unknown, true));
+
+    init_namespace!(object, factory, {
+      "prototype" => factory.unknown,
+      "BYTES_PER_ELEMENT" => factory.number(kind.bytes_per_element() as f64, None),
+      // Takes a callback
+      "from" => factory.unknown,
+      "of" => factory.pure_fn_returns_unknown,
+    });
+
+    let constructor = factory.implemented_builtin_class(
+      kind.name(),
+      object,
+      Some(kind.prototype(self.prototypes)),
+      None,
+      move |analyzer, dep, args| construct_typed_array(analyzer, dep, args, kind),
+    );
+    self.globals.insert(kind.name(), constructor);
+  }
+
+  fn init_data_view_constructor(&mut self) {
+    let factory = self.factory;
+
+    let object = factory.builtin_object(
+      DATA_VIEW_CONSTRUCTOR_OBJECT_ID,
+      ObjectPrototype::Builtin(&self.prototypes.function),
+      false,
+    );
+    object.init_rest(factory, ObjectPropertyValue::Field(factory.unknown, true));
+
+    init_namespace!(object, factory, {
+      "prototype" => factory.unknown,
+    });
+
+    let constructor = factory.implemented_builtin_class(
+      "DataView",
+      object,
+      Some(&self.prototypes.data_view),
+      None,
+      |analyzer, dep, args| {
+        construct_typed_array(analyzer, dep, args, ArrayBufferViewKind::DataView)
+      },
+    );
+    self.globals.insert("DataView", constructor);
+  }
+}
+
+/// A resizable buffer, which is created with `maxByteLength`, has an unknown length
+fn construct_array_buffer<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  args: Entity<'a>,
+) -> Entity<'a> {
+  let args_arr = args.destruct_as_array(analyzer, dep, 2, false).0;
+  let (length, options) = (args_arr[0], args_arr[1]);
+  let dep = analyzer.dep((dep, args));
+  args.unknown_mutate(analyzer, dep);
+
+  let byte_length = match get_index_arg(analyzer, length) {
+    Some(Some(byte_length)) => {
+      Some(byte_length).filter(|_| options.test_is_undefined() == Some(true))
+    }
+    Some(None) => return throw_invalid_length(analyzer, dep, args, "Invalid array buffer length"),
+    None => None,
+  };
+  let buffer = analyzer.new_array_buffer(byte_length);
+  analyzer.factory.computed(buffer.into(), dep)
+}
+
+/// Creates a view on the given buffer, or on a new buffer. A view on any other object may share
+/// memory with it, so writes to the view are mutations of that object.
+fn construct_typed_array<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  args: Entity<'a>,
+  kind: ArrayBufferViewKind,
+) -> Entity<'a> {
+  let args_arr = args.destruct_as_array(analyzer, dep, 3, false).0;
+  let (source, byte_offset, length) = (args_arr[0], args_arr[1], args_arr[2]);
+  let dep = analyzer.dep((dep, args));
+  // Only the numbers are converted, the buffer is not mutated
+  byte_offset.unknown_mutate(analyzer, dep);
+  length.unknown_mutate(analyzer, dep);
+
+  let bytes_per_element = kind.bytes_per_element();
+  let mut dep = dep;
+  let view = if let Some(buffer) = source.as_array_buffer() {
+    let byte_length = buffer.get_known_byte_length(analyzer);
+    let byte_offset = get_index_arg(analyzer, byte_offset);
+    let length_omitted = length.test_is_undefined() == Some(true);
+    let length = get_index_arg(analyzer, length);
+    let length = match get_view_length(kind, byte_length, byte_offset, length, length_omitted) {
+      Some(Some(length)) => Some(length),
+      Some(None) => {
+        let message = if kind.is_typed_array() {
+          "Invalid typed array length"
+        } else {
+          "Invalid DataView length"
+        };
+        return throw_invalid_length(analyzer, dep, args, message);
+      }
+      None => {
+        if analyzer.config.preserve_exceptions {
+          // May throw
+          analyzer.consume(dep);
+          analyzer.refer_to_global();
+        }
+        None
+      }
+    };
+    analyzer.new_array_buffer_view(kind, source, byte_offset.flatten(), length)
+  } else if !kind.is_typed_array() {
+    analyzer.new_array_buffer_view(kind, source, None, None)
+  } else if !(TypeofResult::Object | TypeofResult::Function).intersects(source.test_typeof()) {
+    let length = match get_index_arg(analyzer, source) {
+      Some(Some(length)) => Some(length),
+      Some(None) => return throw_invalid_length(analyzer, dep, args, "Invalid typed array length"),
+      None => None,
+    };
+    let buffer =
+      analyzer.new_array_buffer(length.and_then(|length| length.checked_mul(bytes_per_element)));
+    analyzer.new_array_buffer_view(kind, buffer.into(), Some(0), length)
+  } else {
+    let (elements, rest, iterate_dep) = source.iterate(analyzer, dep);
+    dep = analyzer.dep((dep, iterate_dep));
+    if source.as_array().is_some() {
+      // The elements are copied to a new buffer
+      let length = rest.is_none().then_some(elements.len());
+      let buffer =
+        analyzer.new_array_buffer(length.and_then(|length| length.checked_mul(bytes_per_element)));
+      analyzer.new_array_buffer_view(kind, buffer.into(), Some(0), length)
+    } else {
+      analyzer.new_array_buffer_view(kind, source, None, None)
+    }
+  };
+  analyzer.factory.computed(view, dep)
+}
+
+fn throw_invalid_length<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  args: Entity<'a>,
+  message: &str,
+) -> Entity<'a> {
+  analyzer.throw_builtin_error(message);
+  // The exception can only be dropped if it is not caught
+  if analyzer.config.preserve_exceptions || analyzer.scoping.try_catch_depth.is_some() {
+    analyzer.consume((dep, args));
+    analyzer.factory.unknown
+  } else {
+    analyzer.factory.never
+  }
+}
+
+/// Checks the offset and the length of a view on a buffer of `byte_length` bytes like the
+/// constructors do, and returns the length of the view. `None` if it is unknown whether they are
+/// valid, and `Some(None)` if the constructor throws.
+fn get_view_length(
+  kind: ArrayBufferViewKind,
+  byte_length: Option<usize>,
+  byte_offset: Option<Option<usize>>,
+  length: Option<Option<usize>>,
+  length_omitted: bool,
+) -> Option<Option<usize>> {
+  if byte_offset == Some(None) || (!length_omitted && length == Some(None)) {
+    return Some(None);
+  }
+  let bytes_per_element = kind.bytes_per_element();
+  let byte_offset = byte_offset.flatten()?;
+  if byte_offset % bytes_per_element != 0 {
+    return Some(None);
+  }
+  let byte_length = byte_length?;
+  if length_omitted {
+    let rest = byte_length.checked_sub(byte_offset);
+    Some(rest.filter(|_| byte_length % bytes_per_element == 0).map(|rest| rest / bytes_per_element))
+  } else {
+    let length = length.flatten()?;
+    let end = length.checked_mul(bytes_per_element).and_then(|size| size.checked_add(byte_offset));
+    Some(end.filter(|end| *end <= byte_length).map(|_| length))
+  }
+}
+
+/// Converts an optional length or offset argument like `ToIndex`. `None` if it is unknown, and
+/// `Some(None)` if the conversion throws.
+fn get_index_arg<'a>(analyzer: &Analyzer<'a>, arg: Entity<'a>) -> Option<Option<usize>> {
+  if arg.test_is_undefined() == Some(true) {
+    return Some(Some(0));
+  }
+  match arg.get_to_numeric(analyzer).get_literal(analyzer)? {
+    LiteralValue::Number(value, _) => {
+      let value = value.0.trunc();
+      Some((value > -1.0 && value <= MAX_SAFE_INTEGER).then_some(value as usize))
+    }
+    LiteralValue::NaN => Some(Some(0)),
+    LiteralValue::Infinity(_) | LiteralValue::BigInt(_) => Some(None),
+    _ => None,
+  }
+}
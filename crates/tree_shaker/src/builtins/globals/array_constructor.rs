@@ -0,0 +1,162 @@
+use oxc::allocator;
+
+use crate::{
+  analyzer::Analyzer,
+  builtins::{Builtins, constants::ARRAY_CONSTRUCTOR_OBJECT_ID},
+  dep::Dep,
+  entity::Entity,
+  init_namespace,
+  value::{LiteralValue, ObjectPropertyValue, ObjectPrototype, TypeofResult, array::ArrayValue},
+};
+
+/// `new Array(length)` with a larger length only tracks that the elements are `undefined`
+const MAX_TRACKED_INITIAL_LENGTH: f64 = 1024.0;
+
+impl<'a> Builtins<'a> {
+  pub fn init_array_constructor(&mut self) {
+    let factory = self.factory;
+
+    let object = factory.builtin_object(
+      ARRAY_CONSTRUCTOR_OBJECT_ID,
+      ObjectPrototype::Builtin(&self.prototypes.function),
+      false,
+    );
+    object.init_rest(factory, ObjectPropertyValue::Field(factory.unknown, true));
+
+    init_namespace!(object, factory, {
+      "prototype" => factory.unknown,
+      "isArray" => self.create_array_is_array_impl(),
+      "from" => self.create_array_from_impl(),
+      "of" => self.create_array_of_impl(),
+    });
+
+    let constructor = factory.implemented_builtin_class(
+      "Array",
+      object,
+      Some(&self.prototypes.array),
+      Some(construct_array),
+      construct_array,
+    );
+
+    self.globals.insert("Array", constructor);
+  }
+
+  fn create_array_is_array_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Array.isArray", |analyzer, dep, _, args| {
+      let value = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+      match value.test_is_array() {
+        Some(result) => analyzer.factory.computed(analyzer.factory.boolean(result), (dep, value)),
+        None => analyzer.factory.computed_unknown_boolean((dep, value)),
+      }
+    })
+  }
+
+  fn create_array_from_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Array.from", |analyzer, dep, _, args| {
+      let args_arr = args.destruct_as_array(analyzer, dep, 3, false).0;
+      let (items, map_fn, this_arg) = (args_arr[0], args_arr[1], args_arr[2]);
+
+      let (elements, rest, iterate_dep) = items.iterate(analyzer, dep);
+      let array = analyzer.new_empty_array();
+
+      if map_fn.test_is_undefined() == Some(true) {
+        array.extend_elements(elements, rest);
+        return analyzer.factory.computed(array.into(), (dep, iterate_dep));
+      }
+
+      let dep = analyzer.dep((iterate_dep, map_fn));
+      let call = |analyzer: &mut Analyzer<'a>, element: Entity<'a>, index: Entity<'a>| {
+        let args = analyzer.factory.arguments(allocator::Vec::from_iter_in(
+          [(false, element), (false, index)],
+          analyzer.allocator,
+        ));
+        map_fn.call(analyzer, dep, this_arg, args)
+      };
+
+      for (index, element) in elements.into_iter().enumerate() {
+        let index = analyzer.factory.number(index as f64, None);
+        let mapped = call(analyzer, element, index);
+        array.push_element(mapped);
+      }
+      if let Some(rest) = rest {
+        analyzer.push_indeterminate_cf_scope();
+        let mapped = call(analyzer, rest, analyzer.factory.unknown_number);
+        analyzer.pop_cf_scope();
+        array.init_rest(mapped);
+      }
+
+      analyzer.factory.computed(array.into(), dep)
+    })
+  }
+
+  fn create_array_of_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Array.of", |analyzer, dep, _, args| {
+      let (elements, rest, iterate_dep) = args.iterate(analyzer, dep);
+      let array = analyzer.new_empty_array();
+      array.extend_elements(elements, rest);
+      analyzer.factory.computed(array.into(), (dep, iterate_dep))
+    })
+  }
+}
+
+fn construct_array<'a>(analyzer: &mut Analyzer<'a>, dep: Dep<'a>, args: Entity<'a>) -> Entity<'a> {
+  let (elements, rest, iterate_dep) = args.iterate(analyzer, dep);
+  let array = analyzer.new_empty_array();
+
+  if let (None, [length]) = (rest, elements.as_slice()) {
+    if !analyzer.init_array_with_length(array, *length) {
+      analyzer.throw_builtin_error("Invalid array length");
+      return if analyzer.config.preserve_exceptions {
+        analyzer.consume((dep, args));
+        analyzer.factory.unknown
+      } else {
+        analyzer.factory.never
+      };
+    }
+  } else {
+    array.extend_elements(elements, rest);
+  }
+
+  analyzer.factory.computed(array.into(), (dep, iterate_dep))
+}
+
+impl<'a> ArrayValue<'a> {
+  fn extend_elements(&self, elements: Vec<Entity<'a>>, rest: Option<Entity<'a>>) {
+    for element in elements {
+      self.push_element(element);
+    }
+    if let Some(rest) = rest {
+      self.init_rest(rest);
+    }
+  }
+}
+
+impl<'a> Analyzer<'a> {
+  /// Initializes the elements of `new Array(arg)`. Returns `false` if the length is invalid.
+  fn init_array_with_length(&self, array: &ArrayValue<'a>, arg: Entity<'a>) -> bool {
+    if !arg.test_typeof().contains(TypeofResult::Number) {
+      array.push_element(arg);
+      return true;
+    }
+
+    match arg.get_literal(self) {
+      Some(LiteralValue::Number(length, _)) => {
+        let length = length.0;
+        if length.fract() != 0.0 || !(0.0..4294967296.0).contains(&length) {
+          return false;
+        }
+        if length > MAX_TRACKED_INITIAL_LENGTH {
+          array.init_rest(self.factory.undefined);
+        } else {
+          for _ in 0..(length as usize) {
+            array.push_element(self.factory.undefined);
+          }
+        }
+      }
+      Some(LiteralValue::NaN | LiteralValue::Infinity(_)) => return false,
+      // Either the length, or the single element
+      _ => array.init_rest(self.factory.union((self.factory.undefined, arg))),
+    }
+    true
+  }
+}
@@ -0,0 +1,68 @@
+use crate::{
+  builtins::{Builtins, constants::COLLECTION_CONSTRUCTOR_OBJECT_ID},
+  entity::Entity,
+  init_namespace,
+  value::{
+    ObjectPropertyValue, ObjectPrototype,
+    collection::{CollectionKind, CollectionValue},
+  },
+};
+
+impl<'a> Builtins<'a> {
+  pub fn init_collection_constructors(&mut self) {
+    for (name, kind) in [
+      ("Map", CollectionKind::Map),
+      ("Set", CollectionKind::Set),
+      ("WeakMap", CollectionKind::WeakMap),
+      ("WeakSet", CollectionKind::WeakSet),
+    ] {
+      let constructor = self.create_collection_constructor(name, kind);
+      self.globals.insert(name, constructor);
+    }
+  }
+
+  fn create_collection_constructor(&self, name: &'static str, kind: CollectionKind) -> Entity<'a> {
+    let factory = self.factory;
+
+    let object = factory.builtin_object(
+      COLLECTION_CONSTRUCTOR_OBJECT_ID,
+      ObjectPrototype::Builtin(&self.prototypes.function),
+      false,
+    );
+    object.init_rest(factory, ObjectPropertyValue::Field(factory.unknown, true));
+
+    init_namespace!(object, factory, {
+      "prototype" => factory.unknown,
+    });
+
+    let prototype = kind.prototype(self.prototypes);
+    factory.implemented_builtin_class(
+      name,
+      object,
+      Some(prototype),
+      None,
+      move |analyzer, dep, args| {
+        let collection: &'a CollectionValue<'a> = analyzer.new_collection(kind);
+        let iterable = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+
+        if iterable.test_nullish() != Some(true) {
+          let (elements, rest, iterate_dep) = iterable.iterate(analyzer, dep);
+          for element in elements {
+            if kind.is_map() {
+              let entry = element.destruct_as_array(analyzer, iterate_dep, 2, false).0;
+              collection.init_entry(analyzer, entry[0], entry[1]);
+            } else {
+              let element = analyzer.factory.computed(element, iterate_dep);
+              collection.init_entry(analyzer, element, element);
+            }
+          }
+          if let Some(rest) = rest {
+            collection.deps.borrow_mut().push(analyzer.dep((iterate_dep, rest)));
+          }
+        }
+
+        analyzer.factory.computed(collection.into(), dep)
+      },
+    )
+  }
+}
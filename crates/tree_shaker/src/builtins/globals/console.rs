@@ -0,0 +1,78 @@
+use crate::{
+  analyzer::Analyzer,
+  builtins::{Builtins, constants::CONSOLE_OBJECT_ID},
+  dep::Dep,
+  entity::Entity,
+  init_namespace,
+  value::{ObjectPropertyValue, ObjectPrototype},
+};
+
+const CONSOLE_METHODS: &[(&str, &str)] = &[
+  ("assert", "console.assert"),
+  ("clear", "console.clear"),
+  ("count", "console.count"),
+  ("countReset", "console.countReset"),
+  ("debug", "console.debug"),
+  ("dir", "console.dir"),
+  ("dirxml", "console.dirxml"),
+  ("error", "console.error"),
+  ("group", "console.group"),
+  ("groupCollapsed", "console.groupCollapsed"),
+  ("groupEnd", "console.groupEnd"),
+  ("info", "console.info"),
+  ("log", "console.log"),
+  ("table", "console.table"),
+  ("time", "console.time"),
+  ("timeEnd", "console.timeEnd"),
+  ("timeLog", "console.timeLog"),
+  ("trace", "console.trace"),
+  ("warn", "console.warn"),
+];
+
+impl Builtins<'_> {
+  pub fn init_console(&mut self) {
+    let factory = self.factory;
+
+    let object = factory.builtin_object(
+      CONSOLE_OBJECT_ID,
+      ObjectPrototype::Builtin(&self.prototypes.object),
+      false,
+    );
+    object.init_rest(factory, ObjectPropertyValue::Field(factory.unknown, true));
+
+    for &(key, name) in CONSOLE_METHODS {
+      let method = if self.config.pure_console_methods.contains(key) {
+        factory.implemented_builtin_fn(name, call_pure_console_method)
+      } else {
+        factory.implemented_builtin_fn(name, call_console_method)
+      };
+      init_namespace!(object, factory, {
+        key => method,
+      });
+    }
+
+    self.globals.insert("console", object.into());
+  }
+}
+
+/// The arguments are observed by the host, including their properties and getters
+fn call_console_method<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  this: Entity<'a>,
+  args: Entity<'a>,
+) -> Entity<'a> {
+  analyzer.consume((dep, this, args));
+  analyzer.refer_to_global();
+  analyzer.factory.undefined
+}
+
+/// Configured as removable, so the arguments are not observed
+fn call_pure_console_method<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  _this: Entity<'a>,
+  _args: Entity<'a>,
+) -> Entity<'a> {
+  analyzer.factory.computed(analyzer.factory.undefined, dep)
+}
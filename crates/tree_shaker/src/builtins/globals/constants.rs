@@ -9,8 +9,6 @@ impl Builtins<'_> {
       "Infinity" => factory.infinity(true),
       "NaN" => factory.nan,
       "eval" => factory.unknown,
-      "RegExp" => factory.unknown,
-      "Array" => factory.unknown,
 
       "$$DEBUG$$" => factory.implemented_builtin_fn(
         "debug",
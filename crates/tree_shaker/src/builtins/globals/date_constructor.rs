@@ -0,0 +1,51 @@
+use crate::{
+  analyzer::Analyzer,
+  builtins::{Builtins, constants::DATE_CONSTRUCTOR_OBJECT_ID},
+  dep::Dep,
+  entity::Entity,
+  init_namespace,
+  value::{ObjectPropertyValue, ObjectPrototype},
+};
+
+impl Builtins<'_> {
+  pub fn init_date_constructor(&mut self) {
+    let factory = self.factory;
+
+    let object = factory.builtin_object(
+      DATE_CONSTRUCTOR_OBJECT_ID,
+      ObjectPrototype::Builtin(&self.prototypes.function),
+      false,
+    );
+    object.init_rest(factory, ObjectPropertyValue::Field(factory.unknown, true));
+
+    init_namespace!(object, factory, {
+      "prototype" => factory.unknown,
+      "now" => factory.pure_fn_returns_number,
+      "parse" => factory.pure_fn_returns_number,
+      "UTC" => factory.pure_fn_returns_number,
+    });
+
+    let constructor = factory.implemented_builtin_class(
+      "Date",
+      object,
+      Some(&self.prototypes.date),
+      Some(call_date),
+      construct_date,
+    );
+    self.globals.insert("Date", constructor);
+  }
+}
+
+/// `Date()` returns the current time as a string, ignoring the arguments
+fn call_date<'a>(analyzer: &mut Analyzer<'a>, dep: Dep<'a>, _args: Entity<'a>) -> Entity<'a> {
+  analyzer.factory.computed_unknown_string(dep)
+}
+
+/// The time is not tracked, but reading the clock has no side effects
+fn construct_date<'a>(analyzer: &mut Analyzer<'a>, dep: Dep<'a>, args: Entity<'a>) -> Entity<'a> {
+  let dep = analyzer.dep((dep, args));
+  args.unknown_mutate(analyzer, dep);
+  let prototypes = analyzer.builtins.prototypes;
+  let date = analyzer.new_empty_object(ObjectPrototype::Builtin(&prototypes.date), None);
+  analyzer.factory.computed(date.into(), dep)
+}
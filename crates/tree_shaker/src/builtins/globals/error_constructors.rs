@@ -0,0 +1,153 @@
+use crate::{
+  analyzer::Analyzer,
+  builtins::{BuiltinPrototype, Builtins, constants::ERROR_CONSTRUCTOR_OBJECT_ID},
+  dep::{Dep, DepCollector},
+  entity::Entity,
+  init_namespace,
+  value::{
+    ObjectProperty, ObjectPropertyValue, ObjectPrototype, ObjectValue, PropertyKeyValue,
+    TypeofResult, builtin_fn::BuiltinConstructorCall,
+  },
+};
+
+impl<'a> Builtins<'a> {
+  pub fn init_error_constructors(&mut self) {
+    let prototypes = self.prototypes;
+    self.init_error_constructor("Error", &prototypes.error, |analyzer, dep, args| {
+      let prototypes = analyzer.builtins.prototypes;
+      create_error(analyzer, dep, args, &prototypes.error, false)
+    });
+    self.init_error_constructor(
+      "AggregateError",
+      &prototypes.aggregate_error,
+      |analyzer, dep, args| {
+        let prototypes = analyzer.builtins.prototypes;
+        create_error(analyzer, dep, args, &prototypes.aggregate_error, true)
+      },
+    );
+    self.init_error_constructor("EvalError", &prototypes.eval_error, |analyzer, dep, args| {
+      let prototypes = analyzer.builtins.prototypes;
+      create_error(analyzer, dep, args, &prototypes.eval_error, false)
+    });
+    self.init_error_constructor("RangeError", &prototypes.range_error, |analyzer, dep, args| {
+      let prototypes = analyzer.builtins.prototypes;
+      create_error(analyzer, dep, args, &prototypes.range_error, false)
+    });
+    self.init_error_constructor(
+      "ReferenceError",
+      &prototypes.reference_error,
+      |analyzer, dep, args| {
+        let prototypes = analyzer.builtins.prototypes;
+        create_error(analyzer, dep, args, &prototypes.reference_error, false)
+      },
+    );
+    self.init_error_constructor("SyntaxError", &prototypes.syntax_error, |analyzer, dep, args| {
+      let prototypes = analyzer.builtins.prototypes;
+      create_error(analyzer, dep, args, &prototypes.syntax_error, false)
+    });
+    self.init_error_constructor("TypeError", &prototypes.type_error, |analyzer, dep, args| {
+      let prototypes = analyzer.builtins.prototypes;
+      create_error(analyzer, dep, args, &prototypes.type_error, false)
+    });
+    self.init_error_constructor("URIError", &prototypes.uri_error, |analyzer, dep, args| {
+      let prototypes = analyzer.builtins.prototypes;
+      create_error(analyzer, dep, args, &prototypes.uri_error, false)
+    });
+  }
+
+  /// Calling an error constructor without `new` is the same as constructing it
+  fn init_error_constructor(
+    &mut self,
+    name: &'static str,
+    prototype: &'a BuiltinPrototype<'a>,
+    create: BuiltinConstructorCall<'a>,
+  ) {
+    let factory = self.factory;
+
+    let object = factory.builtin_object(
+      ERROR_CONSTRUCTOR_OBJECT_ID,
+      ObjectPrototype::Builtin(&self.prototypes.function),
+      false,
+    );
+    object.init_rest(factory, ObjectPropertyValue::Field(factory.unknown, true));
+
+    init_namespace!(object, factory, {
+      "prototype" => factory.unknown,
+    });
+
+    let constructor =
+      factory.implemented_builtin_class(name, object, Some(prototype), Some(create), create);
+    self.globals.insert(name, constructor);
+  }
+}
+
+/// Creates an error with the own properties `message`, `cause` and `stack`, plus `errors` for
+/// `AggregateError`
+fn create_error<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  args: Entity<'a>,
+  prototype: &'a BuiltinPrototype<'a>,
+  aggregate: bool,
+) -> Entity<'a> {
+  let args = args.destruct_as_array(analyzer, dep, if aggregate { 3 } else { 2 }, false).0;
+  let (errors, message, options) =
+    if aggregate { (Some(args[0]), args[1], args[2]) } else { (None, args[0], args[1]) };
+
+  let error = analyzer.new_empty_object(ObjectPrototype::Builtin(prototype), None);
+
+  if message.test_is_undefined() != Some(true) {
+    let definite = message.test_is_undefined() == Some(false);
+    let message = message.get_to_string(analyzer);
+    init_own_property(analyzer, error, "message", message, definite);
+  }
+
+  if (TypeofResult::Object | TypeofResult::Function).intersects(options.test_typeof())
+    && options.test_nullish() != Some(true)
+  {
+    let cause = options.get_property(analyzer, dep, analyzer.factory.string("cause"));
+    // A `cause` which is explicitly `undefined` is not distinguished from a missing one
+    if cause.test_is_undefined() != Some(true) {
+      let definite = cause.test_is_undefined() == Some(false);
+      init_own_property(analyzer, error, "cause", cause, definite);
+    }
+  }
+
+  if let Some(errors) = errors {
+    let (elements, rest, iterate_dep) = errors.iterate(analyzer, dep);
+    let array = analyzer.new_empty_array();
+    for element in elements {
+      array.push_element(element);
+    }
+    if let Some(rest) = rest {
+      array.init_rest(rest);
+    }
+    let errors = analyzer.factory.computed(array.into(), iterate_dep);
+    init_own_property(analyzer, error, "errors", errors, true);
+  }
+
+  init_own_property(analyzer, error, "stack", analyzer.factory.unknown_string, true);
+
+  analyzer.factory.computed(error.into(), dep)
+}
+
+/// The own properties of errors are writable but not enumerable
+fn init_own_property<'a>(
+  analyzer: &Analyzer<'a>,
+  object: &ObjectValue<'a>,
+  key: &'static str,
+  value: Entity<'a>,
+  definite: bool,
+) {
+  object.keyed.borrow_mut().insert(
+    PropertyKeyValue::String(key),
+    ObjectProperty {
+      definite,
+      enumerable: false,
+      possible_values: analyzer.factory.vec1(ObjectPropertyValue::Field(value, false)),
+      non_existent: DepCollector::new(analyzer.factory.vec()),
+      key: None,
+      mangling: None,
+    },
+  );
+}
@@ -0,0 +1,48 @@
+use crate::{TreeShakeTargetEnv, builtins::Builtins, value::host_object::HostObjectValue};
+
+impl Builtins<'_> {
+  pub fn init_host_globals(&mut self) {
+    let factory = self.factory;
+
+    let global_object = factory.alloc(HostObjectValue { is_global: true }).into();
+    self.globals.insert("globalThis", global_object);
+    self.host_globals.insert("globalThis");
+
+    // (aliases of the global object, other host objects, globals which don't exist)
+    let (aliases, host_objects, absent): (&[_], &[_], &[_]) = match self.config.target_env {
+      TreeShakeTargetEnv::Unknown => (&[], &[], &[]),
+      TreeShakeTargetEnv::Browser => {
+        (&["window", "self"], &["document", "navigator", "location"], &["process"])
+      }
+      TreeShakeTargetEnv::Node => (&["global"], &["process"], &["window", "self", "document"]),
+      TreeShakeTargetEnv::Worker => {
+        (&["self"], &["navigator", "location"], &["window", "document", "process"])
+      }
+    };
+    for name in aliases {
+      self.globals.insert(name, global_object);
+    }
+    for name in host_objects {
+      self.globals.insert(name, factory.alloc(HostObjectValue { is_global: false }).into());
+    }
+    self.host_globals.extend(aliases.iter().chain(host_objects));
+    self.absent_globals.extend(absent);
+  }
+
+  /// Called when a global is written, which now exists and is no longer the one provided by the
+  /// target environment
+  pub fn redefine_global(&mut self, name: &str) {
+    self.absent_globals.remove(name);
+    if self.host_globals.remove(name) {
+      self.globals.remove(name);
+    }
+  }
+
+  /// Called when any global may be written
+  pub fn redefine_all_globals(&mut self) {
+    self.absent_globals.clear();
+    for name in self.host_globals.drain() {
+      self.globals.remove(name);
+    }
+  }
+}
@@ -0,0 +1,145 @@
+use crate::{
+  analyzer::Analyzer,
+  builtins::{
+    Builtins,
+    constants::{INTL_CONSTRUCTOR_OBJECT_ID, INTL_OBJECT_ID},
+  },
+  dep::Dep,
+  entity::Entity,
+  init_namespace,
+  value::{ObjectPropertyValue, ObjectPrototype, ObjectValue},
+};
+
+impl<'a> Builtins<'a> {
+  pub fn init_intl(&mut self) {
+    let factory = self.factory;
+
+    let object = factory.builtin_object(
+      INTL_OBJECT_ID,
+      ObjectPrototype::Builtin(&self.prototypes.object),
+      false,
+    );
+    object.init_rest(factory, ObjectPropertyValue::Field(factory.unknown, true));
+
+    // The services share a prototype, so their instances are not distinguished by `instanceof`
+    init_namespace!(object, factory, {
+      "getCanonicalLocales" => factory.pure_fn_returns_unknown,
+      "supportedValuesOf" => factory.pure_fn_returns_unknown,
+      // Legacy services which can be called without `new`
+      "Collator" => factory.implemented_builtin_class(
+        "Intl.Collator",
+        self.create_intl_statics(),
+        None,
+        Some(construct_intl_service),
+        construct_intl_service,
+      ),
+      "DateTimeFormat" => factory.implemented_builtin_class(
+        "Intl.DateTimeFormat",
+        self.create_intl_statics(),
+        None,
+        Some(construct_intl_service),
+        construct_intl_service,
+      ),
+      "NumberFormat" => factory.implemented_builtin_class(
+        "Intl.NumberFormat",
+        self.create_intl_statics(),
+        None,
+        Some(construct_intl_service),
+        construct_intl_service,
+      ),
+      "DisplayNames" => factory.implemented_builtin_class(
+        "Intl.DisplayNames",
+        self.create_intl_statics(),
+        None,
+        None,
+        construct_intl_service,
+      ),
+      "DurationFormat" => factory.implemented_builtin_class(
+        "Intl.DurationFormat",
+        self.create_intl_statics(),
+        None,
+        None,
+        construct_intl_service,
+      ),
+      "ListFormat" => factory.implemented_builtin_class(
+        "Intl.ListFormat",
+        self.create_intl_statics(),
+        None,
+        None,
+        construct_intl_service,
+      ),
+      "PluralRules" => factory.implemented_builtin_class(
+        "Intl.PluralRules",
+        self.create_intl_statics(),
+        None,
+        None,
+        construct_intl_service,
+      ),
+      "RelativeTimeFormat" => factory.implemented_builtin_class(
+        "Intl.RelativeTimeFormat",
+        self.create_intl_statics(),
+        None,
+        None,
+        construct_intl_service,
+      ),
+      "Segmenter" => factory.implemented_builtin_class(
+        "Intl.Segmenter",
+        self.create_intl_statics(),
+        None,
+        None,
+        construct_intl_service,
+      ),
+      "Locale" => factory.implemented_builtin_class(
+        "Intl.Locale",
+        self.create_intl_statics(),
+        None,
+        None,
+        construct_intl_locale,
+      ),
+    });
+
+    self.globals.insert("Intl", object.into());
+  }
+
+  fn create_intl_statics(&self) -> &'a ObjectValue<'a> {
+    let factory = self.factory;
+
+    let object = factory.builtin_object(
+      INTL_CONSTRUCTOR_OBJECT_ID,
+      ObjectPrototype::Builtin(&self.prototypes.function),
+      false,
+    );
+    object.init_rest(factory, ObjectPropertyValue::Field(factory.unknown, true));
+
+    init_namespace!(object, factory, {
+      "prototype" => factory.unknown,
+      "supportedLocalesOf" => factory.pure_fn_returns_unknown,
+    });
+
+    object
+  }
+}
+
+/// The options are read, but creating a service has no side effects
+fn construct_intl_service<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  args: Entity<'a>,
+) -> Entity<'a> {
+  let dep = analyzer.dep((dep, args));
+  args.unknown_mutate(analyzer, dep);
+  let prototypes = analyzer.builtins.prototypes;
+  let service = analyzer.new_empty_object(ObjectPrototype::Builtin(&prototypes.intl), None);
+  analyzer.factory.computed(service.into(), dep)
+}
+
+/// The properties of a locale, like `language`, are not modeled
+fn construct_intl_locale<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  args: Entity<'a>,
+) -> Entity<'a> {
+  let dep = analyzer.dep((dep, args));
+  args.unknown_mutate(analyzer, dep);
+  analyzer.factory.computed_unknown(dep)
+}
@@ -0,0 +1,254 @@
+use oxc::{allocator, ast::ast::PropertyKind};
+use oxc_syntax::number::ToJsString;
+use serde_json::Value as JsonValue;
+
+use crate::{
+  analyzer::Analyzer,
+  builtins::{Builtins, constants::JSON_OBJECT_ID},
+  dep::Dep,
+  entity::Entity,
+  init_namespace,
+  value::{LiteralValue, ObjectPropertyValue, ObjectPrototype, PropertyKeyValue, TypeofResult},
+};
+
+impl<'a> Builtins<'a> {
+  pub fn init_json(&mut self) {
+    let factory = self.factory;
+
+    let object = factory.builtin_object(
+      JSON_OBJECT_ID,
+      ObjectPrototype::Builtin(&self.prototypes.object),
+      false,
+    );
+    object.init_rest(factory, ObjectPropertyValue::Field(factory.unknown, true));
+
+    init_namespace!(object, factory, {
+      "parse" => self.create_json_parse_impl(),
+      "stringify" => self.create_json_stringify_impl(),
+    });
+
+    self.globals.insert("JSON", object.into());
+  }
+
+  fn create_json_parse_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("JSON.parse", |analyzer, dep, _, args| {
+      let args_arr = args.destruct_as_array(analyzer, dep, 2, false).0;
+      let (text, reviver) = (args_arr[0], args_arr[1]);
+
+      if reviver.test_is_undefined() == Some(true) {
+        let text_str = text.get_to_string(analyzer);
+        if let Some(LiteralValue::String(text_str, _)) = text_str.get_literal(analyzer) {
+          if let Ok(json) = serde_json::from_str::<JsonValue>(text_str) {
+            let value = analyzer.build_json_value(&json);
+            return analyzer.factory.computed(value, (dep, text));
+          }
+        }
+      }
+
+      // May throw, or call the reviver
+      analyzer.consume((dep, args));
+      analyzer.factory.unknown
+    })
+  }
+
+  fn create_json_stringify_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("JSON.stringify", |analyzer, dep, _, args| {
+      let args_arr = args.destruct_as_array(analyzer, dep, 3, false).0;
+      let (value, replacer, space) = (args_arr[0], args_arr[1], args_arr[2]);
+
+      let mut stringifier = JsonStringifier { gap: String::new(), deps: analyzer.factory.vec() };
+      let result = if replacer.test_nullish() == Some(true)
+        && stringifier.init_gap(analyzer, space).is_some()
+      {
+        stringifier.stringify(analyzer, dep, value, "")
+      } else {
+        None
+      };
+
+      match result {
+        Some(Some(result)) => {
+          let result = analyzer.factory.string(analyzer.allocator.alloc_str(&result));
+          analyzer.factory.computed(result, ((dep, args), stringifier.deps))
+        }
+        Some(None) => analyzer.factory.computed(analyzer.factory.undefined, (dep, args)),
+        None => {
+          // May throw, or call `toJSON` and getters
+          analyzer.consume((dep, args));
+          analyzer.factory.unknown_string
+        }
+      }
+    })
+  }
+}
+
+impl<'a> Analyzer<'a> {
+  fn build_json_value(&mut self, json: &JsonValue) -> Entity<'a> {
+    match json {
+      JsonValue::Null => self.factory.null,
+      JsonValue::Bool(value) => self.factory.boolean(*value),
+      JsonValue::Number(value) => self.factory.number(value.as_f64().unwrap(), None),
+      JsonValue::String(value) => self.factory.string(self.allocator.alloc_str(value)),
+      JsonValue::Array(elements) => {
+        let array = self.new_empty_array();
+        for element in elements {
+          array.push_element(self.build_json_value(element));
+        }
+        array.into()
+      }
+      JsonValue::Object(properties) => {
+        let object =
+          self.new_empty_object(ObjectPrototype::Builtin(&self.builtins.prototypes.object), None);
+        for (key, value) in properties {
+          let key = self.factory.string(self.allocator.alloc_str(key));
+          let value = self.build_json_value(value);
+          object.init_property(self, PropertyKind::Init, key, value, true);
+        }
+        object.into()
+      }
+    }
+  }
+}
+
+struct JsonStringifier<'a> {
+  gap: String,
+  deps: allocator::Vec<'a, Dep<'a>>,
+}
+
+impl<'a> JsonStringifier<'a> {
+  fn init_gap(&mut self, analyzer: &Analyzer<'a>, space: Entity<'a>) -> Option<()> {
+    match space.get_literal(analyzer)? {
+      LiteralValue::Number(value, _) => {
+        self.gap = " ".repeat(value.0.clamp(0.0, 10.0) as usize);
+      }
+      LiteralValue::String(value, _) => {
+        self.gap = value.chars().take(10).collect();
+      }
+      LiteralValue::Null | LiteralValue::Undefined => {}
+      _ => return None,
+    }
+    Some(())
+  }
+
+  /// Returns `None` if the result is unknown, and `Some(None)` if the value is skipped
+  fn stringify(
+    &mut self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    value: Entity<'a>,
+    indent: &str,
+  ) -> Option<Option<String>> {
+    if let Some(literal) = value.get_literal(analyzer) {
+      return match literal {
+        LiteralValue::String(value, _) => Some(Some(serde_json::to_string(value).unwrap())),
+        LiteralValue::Number(value, _) if value.0.is_finite() => Some(Some(value.0.to_js_string())),
+        LiteralValue::Boolean(value) => Some(Some(value.to_string())),
+        LiteralValue::Number(_, _)
+        | LiteralValue::Infinity(_)
+        | LiteralValue::NaN
+        | LiteralValue::Null => Some(Some("null".to_string())),
+        LiteralValue::Undefined | LiteralValue::Symbol(_, _) => Some(None),
+        // TypeError: Do not know how to serialize a BigInt
+        LiteralValue::BigInt(_) => None,
+      };
+    }
+
+    let typeof_result = value.test_typeof();
+    if typeof_result == TypeofResult::Function {
+      return Some(None);
+    }
+    if typeof_result != TypeofResult::Object {
+      return None;
+    }
+
+    let inner_indent = format!("{indent}{}", self.gap);
+    match value.test_is_array()? {
+      true => {
+        let (elements, rest, iterate_dep) = value.iterate(analyzer, dep);
+        self.deps.push(iterate_dep);
+        if rest.is_some() {
+          return None;
+        }
+        let mut items = vec![];
+        for element in elements {
+          let item = self.stringify(analyzer, dep, element, &inner_indent)?;
+          items.push(item.unwrap_or_else(|| "null".to_string()));
+        }
+        Some(Some(self.join(items, indent, &inner_indent, '[', ']')))
+      }
+      false => {
+        let to_json_key = analyzer.factory.string("toJSON");
+        let to_json = value.get_property(analyzer, dep, to_json_key);
+        if to_json.test_is_undefined() != Some(true) {
+          return None;
+        }
+
+        let enumerated = value.enumerate_properties(analyzer, dep);
+        self.deps.push(enumerated.dep);
+        if enumerated.unknown.is_some() {
+          return None;
+        }
+
+        // Integer keys are ordered numerically, and others by insertion order
+        let mut index_keyed = vec![];
+        let mut string_keyed = vec![];
+        for (key, (definite, _, value)) in enumerated.known {
+          if !definite {
+            return None;
+          }
+          let PropertyKeyValue::String(key) = key else {
+            continue;
+          };
+          match key.parse::<u32>() {
+            Ok(index) if index.to_string() == key => index_keyed.push((index, key, value)),
+            _ => string_keyed.push((key, value)),
+          }
+        }
+        if string_keyed.len() > 1 {
+          let object = value.as_object()?;
+          let key_order = object.key_order.borrow();
+          let key_order = key_order.as_ref()?;
+          let mut ordered = Vec::with_capacity(string_keyed.len());
+          for (key, value) in string_keyed {
+            let position = key_order.iter().position(|k| *k == PropertyKeyValue::String(key))?;
+            ordered.push((position, key, value));
+          }
+          ordered.sort_by_key(|(position, _, _)| *position);
+          string_keyed = ordered.into_iter().map(|(_, key, value)| (key, value)).collect();
+        }
+        index_keyed.sort_by_key(|(index, _, _)| *index);
+
+        let mut items = vec![];
+        let separator = if self.gap.is_empty() { ":" } else { ": " };
+        let properties = index_keyed
+          .into_iter()
+          .map(|(_, key, value)| (key, value))
+          .chain(string_keyed)
+          .collect::<Vec<_>>();
+        for (key, value) in properties {
+          if let Some(item) = self.stringify(analyzer, dep, value, &inner_indent)? {
+            items.push(format!("{}{separator}{item}", serde_json::to_string(key).unwrap()));
+          }
+        }
+        Some(Some(self.join(items, indent, &inner_indent, '{', '}')))
+      }
+    }
+  }
+
+  fn join(
+    &self,
+    items: Vec<String>,
+    indent: &str,
+    inner_indent: &str,
+    open: char,
+    close: char,
+  ) -> String {
+    if items.is_empty() {
+      format!("{open}{close}")
+    } else if self.gap.is_empty() {
+      format!("{open}{}{close}", items.join(","))
+    } else {
+      let separator = format!(",\n{inner_indent}");
+      format!("{open}\n{inner_indent}{}\n{indent}{close}", items.join(&separator))
+    }
+  }
+}
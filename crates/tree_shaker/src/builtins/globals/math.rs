@@ -0,0 +1,191 @@
+use std::f64::consts;
+
+use oxc_ecmascript::ToInt32;
+
+use crate::{
+  analyzer::{Analyzer, Factory},
+  builtins::{Builtins, constants::MATH_OBJECT_ID},
+  entity::Entity,
+  init_namespace,
+  value::{LiteralValue, ObjectPropertyValue, ObjectPrototype},
+};
+
+impl<'a> Builtins<'a> {
+  pub fn init_math(&mut self) {
+    let factory = self.factory;
+
+    let object = factory.builtin_object(
+      MATH_OBJECT_ID,
+      ObjectPrototype::Builtin(&self.prototypes.object),
+      false,
+    );
+    object.init_rest(factory, ObjectPropertyValue::Field(factory.unknown, true));
+
+    init_namespace!(object, factory, {
+      "E" => factory.number(consts::E, None),
+      "LN10" => factory.number(consts::LN_10, None),
+      "LN2" => factory.number(consts::LN_2, None),
+      "LOG10E" => factory.number(consts::LOG10_E, None),
+      "LOG2E" => factory.number(consts::LOG2_E, None),
+      "PI" => factory.number(consts::PI, None),
+      "SQRT1_2" => factory.number(consts::FRAC_1_SQRT_2, None),
+      "SQRT2" => factory.number(consts::SQRT_2, None),
+      "abs" => self.create_math_unary_impl("Math.abs", f64::abs),
+      "acos" => self.create_math_unary_impl("Math.acos", f64::acos),
+      "acosh" => self.create_math_unary_impl("Math.acosh", f64::acosh),
+      "asin" => self.create_math_unary_impl("Math.asin", f64::asin),
+      "asinh" => self.create_math_unary_impl("Math.asinh", f64::asinh),
+      "atan" => self.create_math_unary_impl("Math.atan", f64::atan),
+      "atan2" => self.create_math_binary_impl("Math.atan2", f64::atan2),
+      "atanh" => self.create_math_unary_impl("Math.atanh", f64::atanh),
+      "cbrt" => self.create_math_unary_impl("Math.cbrt", f64::cbrt),
+      "ceil" => self.create_math_unary_impl("Math.ceil", f64::ceil),
+      "clz32" => self.create_math_unary_impl("Math.clz32", js_clz32),
+      "cos" => self.create_math_unary_impl("Math.cos", f64::cos),
+      "cosh" => self.create_math_unary_impl("Math.cosh", f64::cosh),
+      "exp" => self.create_math_unary_impl("Math.exp", f64::exp),
+      "expm1" => self.create_math_unary_impl("Math.expm1", f64::exp_m1),
+      "floor" => self.create_math_unary_impl("Math.floor", f64::floor),
+      "fround" => self.create_math_unary_impl("Math.fround", |x| x as f32 as f64),
+      "hypot" => self.create_math_variadic_impl("Math.hypot", 0.0, |acc, x| acc.hypot(x)),
+      "imul" => self.create_math_binary_impl("Math.imul", js_imul),
+      "log" => self.create_math_unary_impl("Math.log", f64::ln),
+      "log10" => self.create_math_unary_impl("Math.log10", f64::log10),
+      "log1p" => self.create_math_unary_impl("Math.log1p", f64::ln_1p),
+      "log2" => self.create_math_unary_impl("Math.log2", f64::log2),
+      "max" => self.create_math_variadic_impl("Math.max", f64::NEG_INFINITY, js_max),
+      "min" => self.create_math_variadic_impl("Math.min", f64::INFINITY, js_min),
+      "pow" => self.create_math_binary_impl("Math.pow", js_pow),
+      "random" => factory.pure_fn_returns_number,
+      "round" => self.create_math_unary_impl("Math.round", js_round),
+      "sign" => self.create_math_unary_impl("Math.sign", js_sign),
+      "sin" => self.create_math_unary_impl("Math.sin", f64::sin),
+      "sinh" => self.create_math_unary_impl("Math.sinh", f64::sinh),
+      "sqrt" => self.create_math_unary_impl("Math.sqrt", f64::sqrt),
+      "tan" => self.create_math_unary_impl("Math.tan", f64::tan),
+      "tanh" => self.create_math_unary_impl("Math.tanh", f64::tanh),
+      "trunc" => self.create_math_unary_impl("Math.trunc", f64::trunc),
+    });
+
+    self.globals.insert("Math", object.into());
+  }
+
+  fn create_math_unary_impl(&self, name: &'static str, f: fn(f64) -> f64) -> Entity<'a> {
+    self.factory.implemented_builtin_fn(name, move |analyzer, dep, _, args| {
+      let arg = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+      let result = match get_number_arg(analyzer, arg) {
+        Some(x) => number_to_entity(analyzer.factory, f(x)),
+        None => analyzer.factory.unknown_number,
+      };
+      analyzer.factory.computed(result, (dep, args))
+    })
+  }
+
+  fn create_math_binary_impl(&self, name: &'static str, f: fn(f64, f64) -> f64) -> Entity<'a> {
+    self.factory.implemented_builtin_fn(name, move |analyzer, dep, _, args| {
+      let args_arr = args.destruct_as_array(analyzer, dep, 2, false).0;
+      let result =
+        match (get_number_arg(analyzer, args_arr[0]), get_number_arg(analyzer, args_arr[1])) {
+          (Some(a), Some(b)) => number_to_entity(analyzer.factory, f(a, b)),
+          _ => analyzer.factory.unknown_number,
+        };
+      analyzer.factory.computed(result, (dep, args))
+    })
+  }
+
+  fn create_math_variadic_impl(
+    &self,
+    name: &'static str,
+    init: f64,
+    f: fn(f64, f64) -> f64,
+  ) -> Entity<'a> {
+    self.factory.implemented_builtin_fn(name, move |analyzer, dep, _, args| {
+      let (known, rest, _) = args.iterate(analyzer, dep);
+      let result = if rest.is_some() {
+        None
+      } else {
+        known.iter().try_fold(init, |acc, arg| Some(f(acc, get_number_arg(analyzer, *arg)?)))
+      };
+      let result = match result {
+        Some(result) => number_to_entity(analyzer.factory, result),
+        None => analyzer.factory.unknown_number,
+      };
+      analyzer.factory.computed(result, (dep, args))
+    })
+  }
+}
+
+/// `None` if the argument can't be converted to a known number
+pub fn get_number_arg<'a>(analyzer: &Analyzer<'a>, arg: Entity<'a>) -> Option<f64> {
+  match arg.get_to_numeric(analyzer).get_literal(analyzer)? {
+    LiteralValue::Number(value, _) => Some(value.0),
+    LiteralValue::Infinity(positive) => {
+      Some(if positive { f64::INFINITY } else { f64::NEG_INFINITY })
+    }
+    LiteralValue::NaN => Some(f64::NAN),
+    _ => None,
+  }
+}
+
+pub fn number_to_entity<'a>(factory: &Factory<'a>, value: f64) -> Entity<'a> {
+  if value.is_nan() {
+    factory.nan
+  } else if value.is_infinite() {
+    factory.infinity(value.is_sign_positive())
+  } else {
+    factory.number(value, None)
+  }
+}
+
+fn js_max(a: f64, b: f64) -> f64 {
+  if a.is_nan() || b.is_nan() {
+    f64::NAN
+  } else if a == b {
+    // max(-0, +0) is +0
+    if a.is_sign_positive() { a } else { b }
+  } else {
+    a.max(b)
+  }
+}
+
+fn js_min(a: f64, b: f64) -> f64 {
+  if a.is_nan() || b.is_nan() {
+    f64::NAN
+  } else if a == b {
+    // min(-0, +0) is -0
+    if a.is_sign_negative() { a } else { b }
+  } else {
+    a.min(b)
+  }
+}
+
+fn js_clz32(x: f64) -> f64 {
+  (x.to_int_32() as u32).leading_zeros() as f64
+}
+
+fn js_imul(a: f64, b: f64) -> f64 {
+  a.to_int_32().wrapping_mul(b.to_int_32()) as f64
+}
+
+fn js_pow(base: f64, exponent: f64) -> f64 {
+  if exponent.is_nan() || (base.abs() == 1.0 && exponent.is_infinite()) {
+    f64::NAN
+  } else {
+    base.powf(exponent)
+  }
+}
+
+/// Rounds half up, unlike `f64::round` which rounds half away from zero
+fn js_round(x: f64) -> f64 {
+  if !x.is_finite() || x.fract() == 0.0 {
+    return x;
+  }
+  let floor = x.floor();
+  let result = if x - floor >= 0.5 { floor + 1.0 } else { floor };
+  // Keeps the sign of negative values rounded to zero
+  if result == 0.0 && x.is_sign_negative() { -0.0 } else { result }
+}
+
+fn js_sign(x: f64) -> f64 {
+  if x.is_nan() || x == 0.0 { x } else { x.signum() }
+}
@@ -1,6 +1,22 @@
+mod array_buffer_constructors;
+mod array_constructor;
+mod collections;
+mod console;
 mod constants;
+mod date_constructor;
+mod error_constructors;
+mod host;
+mod intl;
+mod json;
+mod math;
 mod object_constructor;
+mod primitive_constructors;
+mod promise_constructor;
+mod proxy_constructor;
+mod reflect;
+mod regexp_constructor;
 mod symbol_constructor;
+mod text_codec_constructors;
 
 use super::Builtins;
 
@@ -8,6 +24,22 @@ impl Builtins<'_> {
   pub fn init_globals(&mut self) {
     self.init_global_constants();
     self.init_object_constructor();
+    self.init_array_constructor();
+    self.init_primitive_constructors();
+    self.init_math();
+    self.init_json();
+    self.init_collection_constructors();
     self.init_symbol_constructor();
+    self.init_reflect();
+    self.init_proxy_constructor();
+    self.init_promise_constructor();
+    self.init_regexp_constructor();
+    self.init_error_constructors();
+    self.init_console();
+    self.init_date_constructor();
+    self.init_intl();
+    self.init_array_buffer_constructors();
+    self.init_text_codec_constructors();
+    self.init_host_globals();
   }
 }
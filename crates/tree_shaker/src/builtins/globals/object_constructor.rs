@@ -1,10 +1,18 @@
 use std::borrow::BorrowMut;
 
+use oxc::ast::ast::PropertyKind;
+
 use crate::{
+  analyzer::Analyzer,
   builtins::{Builtins, constants::OBJECT_CONSTRUCTOR_OBJECT_ID},
+  dep::Dep,
   entity::Entity,
   init_namespace,
-  value::{LiteralValue, ObjectPropertyValue, ObjectPrototype, TypeofResult},
+  mangling::MangleConstraint,
+  value::{
+    LiteralValue, ObjectIntegrity, ObjectPropertyValue, ObjectPrototype, PropertyKeyValue,
+    TypeofResult,
+  },
 };
 
 impl<'a> Builtins<'a> {
@@ -21,12 +29,31 @@ impl<'a> Builtins<'a> {
     init_namespace!(object, factory, {
       "prototype" => factory.unknown,
       "assign" => self.create_object_assign_impl(),
-      "keys" => self.create_object_keys_impl(),
+      "keys" => self.create_object_keys_impl("Object.keys", false),
+      "getOwnPropertyNames" => self.create_object_keys_impl("Object.getOwnPropertyNames", true),
       "values" => self.create_object_values_impl(),
       "entries" => self.create_object_entries_impl(),
-      "freeze" => self.create_object_freeze_impl(),
+      "fromEntries" => self.create_object_from_entries_impl(),
+      "freeze" => self.create_object_integrity_impl("Object.freeze", ObjectIntegrity::Frozen),
+      "seal" => self.create_object_integrity_impl("Object.seal", ObjectIntegrity::Sealed),
+      "preventExtensions" => self.create_object_integrity_impl(
+        "Object.preventExtensions",
+        ObjectIntegrity::NonExtensible,
+      ),
+      "isFrozen" => self.create_object_test_integrity_impl("Object.isFrozen", test_frozen),
+      "isSealed" => self.create_object_test_integrity_impl("Object.isSealed", test_sealed),
+      "isExtensible" => self.create_object_test_integrity_impl(
+        "Object.isExtensible",
+        test_extensible,
+      ),
       "defineProperty" => self.create_object_define_property_impl(),
+      "defineProperties" => self.create_object_define_properties_impl(),
+      "getOwnPropertyDescriptor" => self.create_object_get_own_property_descriptor_impl(),
+      "getPrototypeOf" => self.create_object_get_prototype_of_impl(),
+      "setPrototypeOf" => self.create_object_set_prototype_of_impl(),
       "create" => self.create_object_create_impl(),
+      "hasOwn" => self.create_object_has_own_impl(),
+      "is" => self.create_object_is_impl(),
     });
 
     self.globals.borrow_mut().insert("Object", object.into());
@@ -70,11 +97,15 @@ impl<'a> Builtins<'a> {
     })
   }
 
-  fn create_object_keys_impl(&self) -> Entity<'a> {
-    self.factory.implemented_builtin_fn("Object.keys", |analyzer, dep, _, args| {
+  /// `Object.keys`, or `Object.getOwnPropertyNames` if `non_enumerable` is true
+  fn create_object_keys_impl(&self, name: &'static str, non_enumerable: bool) -> Entity<'a> {
+    self.factory.implemented_builtin_fn(name, move |analyzer, dep, _, args| {
       let object = args.destruct_as_array(analyzer, dep, 1, false).0[0];
       let array = analyzer.new_empty_array();
-      if let Some(keys) = object.get_own_keys(analyzer) {
+      if non_enumerable && object.test_is_array() != Some(false) {
+        array.init_rest(analyzer.factory.string("length"));
+      }
+      if let Some(keys) = object.get_own_keys(analyzer, analyzer.dep((dep, object))) {
         for (_, key) in keys {
           if key.test_typeof().contains(TypeofResult::String) {
             array.init_rest(key);
@@ -95,8 +126,10 @@ impl<'a> Builtins<'a> {
 
       let array = analyzer.new_empty_array();
 
-      for (_, _, value) in enumerated.known.into_values() {
-        array.init_rest(value);
+      for (key, (_, _, value)) in enumerated.known {
+        if let PropertyKeyValue::String(_) = key {
+          array.init_rest(value);
+        }
       }
 
       if let Some(unknown) = enumerated.unknown {
@@ -114,9 +147,12 @@ impl<'a> Builtins<'a> {
 
       let array = analyzer.new_empty_array();
 
-      for (_, key, value) in enumerated.known.into_values() {
+      for (key, (_, key_entity, value)) in enumerated.known {
+        if let PropertyKeyValue::Symbol(_) = key {
+          continue;
+        }
         let entry = analyzer.new_empty_array();
-        entry.push_element(key.get_to_string(analyzer));
+        entry.push_element(key_entity.get_to_string(analyzer));
         entry.push_element(value);
         array.init_rest(entry.into());
       }
@@ -132,15 +168,43 @@ impl<'a> Builtins<'a> {
     })
   }
 
-  fn create_object_freeze_impl(&self) -> Entity<'a> {
-    self.factory.implemented_builtin_fn("Object.freeze", |analyzer, dep, _, args| {
+  fn create_object_integrity_impl(&self, name: &'static str, level: ObjectIntegrity) -> Entity<'a> {
+    self.factory.implemented_builtin_fn(name, move |analyzer, dep, _, args| {
       let object = args.destruct_as_array(analyzer, dep, 1, false).0[0];
-      if analyzer.config.preserve_writablity {
+      if let Some(value) = object.as_object() {
+        let dep = analyzer.dep((dep, object.get_shallow_dep(analyzer)));
+        value.set_integrity(analyzer, dep, level);
+      } else if analyzer.config.preserve_writablity {
         object.unknown_mutate(analyzer, dep);
-        object
-      } else {
-        // TODO: Actually freeze the object
-        analyzer.factory.computed(object, dep)
+      }
+      analyzer.factory.computed(object, dep)
+    })
+  }
+
+  pub fn create_object_test_integrity_impl(
+    &self,
+    name: &'static str,
+    test: fn(ObjectIntegrity) -> Option<bool>,
+  ) -> Entity<'a> {
+    self.factory.implemented_builtin_fn(name, move |analyzer, dep, _, args| {
+      let object = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+      let result = match object.as_object() {
+        Some(value) if !value.consumed.get() => value
+          .integrity
+          .get()
+          .and_then(test)
+          .map(|result| (result, value.integrity_deps.borrow_mut().collect(analyzer.factory))),
+        // Primitives are treated as frozen objects
+        None if TypeofResult::_Primitive.contains(object.test_typeof()) => {
+          test(ObjectIntegrity::Frozen).map(|result| (result, analyzer.factory.no_dep))
+        }
+        _ => None,
+      };
+      match result {
+        Some((result, integrity_dep)) => {
+          analyzer.factory.computed(analyzer.factory.boolean(result), (dep, args, integrity_dep))
+        }
+        None => analyzer.factory.computed_unknown_boolean((dep, args)),
       }
     })
   }
@@ -150,50 +214,111 @@ impl<'a> Builtins<'a> {
       let [object, key, descriptor] = args.destruct_as_array(analyzer, dep, 3, false).0[..] else {
         unreachable!()
       };
-      let key = key.get_to_property_key(analyzer);
+      define_property(analyzer, dep, object, key, descriptor);
+      object
+    })
+  }
 
-      'trackable: {
-        if analyzer.config.preserve_writablity {
-          break 'trackable;
+  fn create_object_define_properties_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Object.defineProperties", |analyzer, dep, _, args| {
+      let [object, properties] = args.destruct_as_array(analyzer, dep, 2, false).0[..] else {
+        unreachable!()
+      };
+      let enumerated = properties.enumerate_properties(analyzer, dep);
+      for (definite, key, descriptor) in enumerated.known.into_values() {
+        if !definite {
+          analyzer.push_indeterminate_cf_scope();
         }
-        if key.get_literal(analyzer).is_none() {
-          break 'trackable;
+        define_property(analyzer, enumerated.dep, object, key, descriptor);
+        if !definite {
+          analyzer.pop_cf_scope();
         }
-        let enumerated = descriptor.enumerate_properties(analyzer, dep);
-        let mut value = analyzer.factory.undefined;
-        for (definite, key, value2) in enumerated.known.into_values() {
-          if !definite {
-            break 'trackable;
-          }
-          let Some(LiteralValue::String(key_str, _)) = key.get_literal(analyzer) else {
-            break 'trackable;
-          };
-          match key_str {
-            "value" => {
-              value = self.factory.computed(value2, (key, value));
-            }
-            "get" => {
-              // FIXME: This is not safe, but OK for now.
-              value = self.factory.computed_unknown((value2, key, value));
-            }
-            "set" | "enumerable" | "configurable" | "writable" => {
-              // TODO: actually handle these
-              value = self.factory.computed(value, (key, value2));
+      }
+      if let Some(unknown) = enumerated.unknown {
+        analyzer.refer_to_global();
+        analyzer.consume((enumerated.dep, object, unknown));
+      }
+      object
+    })
+  }
+
+  pub fn create_object_get_own_property_descriptor_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn(
+      "Object.getOwnPropertyDescriptor",
+      |analyzer, dep, _, args| {
+        let [object, key] = args.destruct_as_array(analyzer, dep, 2, false).0[..] else {
+          unreachable!()
+        };
+        let key = key.get_to_property_key(analyzer);
+        match get_own_field(analyzer, object, key) {
+          Some((value, field_dep)) => {
+            // Only the attributes implied by the integrity level are tracked, since
+            // `Object.defineProperty` doesn't record the ones it sets
+            let integrity = object.as_object().unwrap().integrity.get();
+            let writable = integrity.filter(|integrity| *integrity == ObjectIntegrity::Frozen);
+            let configurable = integrity.filter(|integrity| *integrity >= ObjectIntegrity::Sealed);
+            let descriptor = analyzer.new_empty_object(
+              ObjectPrototype::Builtin(&analyzer.builtins.prototypes.object),
+              None,
+            );
+            let factory = analyzer.factory;
+            for (name, value) in [
+              ("value", value),
+              ("writable", factory.boolean_maybe_unknown(writable.map(|_| false))),
+              ("enumerable", factory.unknown_boolean),
+              ("configurable", factory.boolean_maybe_unknown(configurable.map(|_| false))),
+            ] {
+              descriptor.init_property(
+                analyzer,
+                PropertyKind::Init,
+                factory.string(name),
+                value,
+                true,
+              );
             }
-            _ => {}
+            analyzer.factory.computed(descriptor.into(), (dep, key, value, field_dep))
           }
+          None => analyzer.factory.computed_unknown((dep, args)),
+        }
+      },
+    )
+  }
+
+  pub fn create_object_get_prototype_of_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Object.getPrototypeOf", |analyzer, dep, _, args| {
+      let object = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+      let Some(value) = object.as_object().filter(|value| !value.consumed.get()) else {
+        return analyzer.factory.computed_unknown((dep, args));
+      };
+      let dep = analyzer.dep((
+        dep,
+        object.get_shallow_dep(analyzer),
+        value.unknown.borrow_mut().non_existent.collect(analyzer.factory),
+      ));
+      match value.prototype.get() {
+        ObjectPrototype::ImplicitOrNull => analyzer.factory.computed(analyzer.factory.null, dep),
+        ObjectPrototype::Custom(prototype) => analyzer.factory.computed(prototype.into(), dep),
+        ObjectPrototype::Builtin(_) => analyzer.factory.computed_unknown(dep),
+        ObjectPrototype::Unknown(prototype_dep) => {
+          analyzer.factory.computed_unknown((dep, prototype_dep))
         }
-        object.set_property(
-          analyzer,
-          analyzer.factory.dep((enumerated.dep, descriptor.get_shallow_dep(analyzer))),
-          key,
-          value,
-        );
-        return object;
       }
+    })
+  }
 
-      object.unknown_mutate(analyzer, (dep, key, descriptor));
-      object
+  fn create_object_set_prototype_of_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Object.setPrototypeOf", |analyzer, dep, _, args| {
+      let [object, prototype] = args.destruct_as_array(analyzer, dep, 2, false).0[..] else {
+        unreachable!()
+      };
+      if let Some(value) = object.as_object() {
+        let dep = analyzer.dep((dep, object.get_shallow_dep(analyzer)));
+        value.mutate_prototype(analyzer, dep, prototype);
+      } else {
+        analyzer.consume(prototype);
+        object.unknown_mutate(analyzer, dep);
+      }
+      analyzer.factory.computed(object, dep)
     })
   }
 
@@ -221,4 +346,216 @@ impl<'a> Builtins<'a> {
       object.into()
     })
   }
+
+  fn create_object_from_entries_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Object.fromEntries", |analyzer, dep, _, args| {
+      let iterable = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+      let (elements, rest, iterate_dep) = iterable.iterate(analyzer, dep);
+      let object = analyzer
+        .new_empty_object(ObjectPrototype::Builtin(&analyzer.builtins.prototypes.object), None);
+      for (entry, definite) in
+        elements.into_iter().map(|e| (e, true)).chain(rest.map(|e| (e, false)))
+      {
+        let entry = entry.destruct_as_array(analyzer, iterate_dep, 2, false).0;
+        let key = entry[0].get_to_property_key(analyzer);
+        object.init_property(analyzer, PropertyKind::Init, key, entry[1], definite);
+      }
+      analyzer.factory.computed(object.into(), iterate_dep)
+    })
+  }
+
+  fn create_object_has_own_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Object.hasOwn", |analyzer, dep, _, args| {
+      let [object, key] = args.destruct_as_array(analyzer, dep, 2, false).0[..] else {
+        unreachable!()
+      };
+      let key = key.get_to_property_key(analyzer);
+      match test_has_own(analyzer, object, key) {
+        Some((result, property_dep)) => analyzer.factory.computed(
+          analyzer.factory.boolean(result),
+          (dep, object.get_shallow_dep(analyzer), key, property_dep),
+        ),
+        None => analyzer.factory.computed_unknown_boolean((dep, args)),
+      }
+    })
+  }
+
+  fn create_object_is_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Object.is", |analyzer, dep, _, args| {
+      let [lhs, rhs] = args.destruct_as_array(analyzer, dep, 2, false).0[..] else {
+        unreachable!()
+      };
+      let (result, constraint) = same_value(analyzer, lhs, rhs);
+      let result = analyzer.factory.boolean_maybe_unknown(result);
+      match constraint {
+        Some(constraint) => analyzer.factory.computed(result, (dep, lhs, rhs, constraint)),
+        None => analyzer.factory.computed(result, (dep, args)),
+      }
+    })
+  }
+}
+
+pub fn define_property<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  object: Entity<'a>,
+  key: Entity<'a>,
+  descriptor: Entity<'a>,
+) {
+  let key = key.get_to_property_key(analyzer);
+
+  'trackable: {
+    if analyzer.config.preserve_writablity {
+      break 'trackable;
+    }
+    if key.get_literal(analyzer).is_none() {
+      break 'trackable;
+    }
+    let enumerated = descriptor.enumerate_properties(analyzer, dep);
+    let mut value = analyzer.factory.undefined;
+    for (definite, key, value2) in enumerated.known.into_values() {
+      if !definite {
+        break 'trackable;
+      }
+      let Some(LiteralValue::String(key_str, _)) = key.get_literal(analyzer) else {
+        break 'trackable;
+      };
+      match key_str {
+        "value" => {
+          value = analyzer.factory.computed(value2, (key, value));
+        }
+        "get" => {
+          // FIXME: This is not safe, but OK for now.
+          value = analyzer.factory.computed_unknown((value2, key, value));
+        }
+        "set" => {
+          // FIXME: Later writes don't call the setter.
+          value = analyzer.factory.computed(value, (key, value2));
+        }
+        "enumerable" | "configurable" | "writable" => {
+          // The attributes are not tracked, so they are unknown to `getOwnPropertyDescriptor`
+          value = analyzer.factory.computed(value, (key, value2));
+        }
+        _ => {}
+      }
+    }
+    object.set_property(
+      analyzer,
+      analyzer.factory.dep((enumerated.dep, descriptor.get_shallow_dep(analyzer))),
+      key,
+      value,
+    );
+    return;
+  }
+
+  // The attributes are not tracked, so later reads of the object can't be known
+  analyzer.refer_to_global();
+  analyzer.consume((dep, object, key, descriptor));
+}
+
+/// Returns `(value, dep)` if the key is known to be an own data property
+fn get_own_field<'a>(
+  analyzer: &mut Analyzer<'a>,
+  object: Entity<'a>,
+  key: Entity<'a>,
+) -> Option<(Entity<'a>, Dep<'a>)> {
+  let value = object.as_object()?;
+  if value.consumed.get() || value.rest.is_some() {
+    return None;
+  }
+  // The key is compared without mangling constraints
+  value.disable_mangling(analyzer);
+  let mut unknown = value.unknown.borrow_mut();
+  if !unknown.possible_values.is_empty() {
+    return None;
+  }
+  let key_literal = key.get_literal(analyzer)?;
+  let mut keyed = value.keyed.borrow_mut();
+  let property = keyed.get_mut(&PropertyKeyValue::from(key_literal))?;
+  let [ObjectPropertyValue::Field(field, _)] = property.possible_values[..] else {
+    return None;
+  };
+  if !property.definite {
+    return None;
+  }
+  let dep = analyzer.dep((
+    object.get_shallow_dep(analyzer),
+    key,
+    property.non_existent.collect(analyzer.factory),
+    unknown.non_existent.collect(analyzer.factory),
+  ));
+  Some((field, dep))
+}
+
+/// Returns `(result, dep)` if it is known whether the object has the own property
+fn test_has_own<'a>(
+  analyzer: &mut Analyzer<'a>,
+  object: Entity<'a>,
+  key: Entity<'a>,
+) -> Option<(bool, Dep<'a>)> {
+  let value = object.as_object()?;
+  if value.consumed.get() || value.rest.is_some() {
+    return None;
+  }
+  // The key is compared without mangling constraints
+  value.disable_mangling(analyzer);
+  let mut unknown = value.unknown.borrow_mut();
+  if !unknown.possible_values.is_empty() {
+    return None;
+  }
+  let key_literal = key.get_literal(analyzer)?;
+  let unknown_dep = unknown.non_existent.collect(analyzer.factory);
+  match value.keyed.borrow_mut().get_mut(&PropertyKeyValue::from(key_literal)) {
+    Some(property) if property.definite => {
+      Some((true, analyzer.dep((unknown_dep, property.non_existent.collect(analyzer.factory)))))
+    }
+    Some(_) => None,
+    None => Some((false, unknown_dep)),
+  }
+}
+
+fn test_frozen(integrity: ObjectIntegrity) -> Option<bool> {
+  match integrity {
+    ObjectIntegrity::Extensible => Some(false),
+    // Depends on whether all properties are readonly
+    ObjectIntegrity::NonExtensible | ObjectIntegrity::Sealed => None,
+    ObjectIntegrity::Frozen => Some(true),
+  }
+}
+
+fn test_sealed(integrity: ObjectIntegrity) -> Option<bool> {
+  match integrity {
+    ObjectIntegrity::Extensible => Some(false),
+    ObjectIntegrity::NonExtensible => None,
+    ObjectIntegrity::Sealed | ObjectIntegrity::Frozen => Some(true),
+  }
+}
+
+pub fn test_extensible(integrity: ObjectIntegrity) -> Option<bool> {
+  Some(integrity == ObjectIntegrity::Extensible)
+}
+
+/// The `SameValue` algorithm, which differs from `===` for `NaN` and signed zeros
+fn same_value<'a>(
+  analyzer: &Analyzer<'a>,
+  lhs: Entity<'a>,
+  rhs: Entity<'a>,
+) -> (Option<bool>, Option<MangleConstraint<'a>>) {
+  if Entity::value_eq(lhs, rhs) {
+    return (Some(true), Some(MangleConstraint::None));
+  }
+  if lhs.test_typeof() & rhs.test_typeof() == TypeofResult::_None {
+    return (Some(false), None);
+  }
+  match (lhs.get_literal(analyzer), rhs.get_literal(analyzer)) {
+    (Some(LiteralValue::NaN), Some(LiteralValue::NaN)) => (Some(true), None),
+    (Some(LiteralValue::Number(l, _)), Some(LiteralValue::Number(r, _))) => {
+      (Some(l.0 == r.0 && l.0.is_sign_negative() == r.0.is_sign_negative()), None)
+    }
+    (Some(l), Some(r)) => {
+      let (eq, constraint) = l.strict_eq(r);
+      (Some(eq), constraint)
+    }
+    _ => (None, None),
+  }
 }
@@ -0,0 +1,502 @@
+use oxc_ecmascript::ToInt32;
+
+use super::math::{get_number_arg, number_to_entity};
+use crate::{
+  analyzer::Analyzer,
+  builtins::{
+    BuiltinPrototype, Builtins,
+    constants::{
+      BIGINT_CONSTRUCTOR_OBJECT_ID, BOOLEAN_CONSTRUCTOR_OBJECT_ID, NUMBER_CONSTRUCTOR_OBJECT_ID,
+      STRING_CONSTRUCTOR_OBJECT_ID,
+    },
+    utils::{get_string_arg, is_js_whitespace},
+  },
+  dep::{Dep, DepCollector},
+  entity::Entity,
+  init_map, init_namespace,
+  value::{
+    LiteralValue, ObjectId, ObjectProperty, ObjectPropertyValue, ObjectPrototype, ObjectValue,
+    PropertyKeyValue, TypeofResult,
+  },
+};
+
+const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+
+impl<'a> Builtins<'a> {
+  pub fn init_primitive_constructors(&mut self) {
+    let parse_int = self.create_parse_int_impl();
+    let parse_float = self.create_parse_float_impl();
+    let is_nan = self.create_global_number_predicate_impl("isNaN", f64::is_nan);
+    let is_finite = self.create_global_number_predicate_impl("isFinite", f64::is_finite);
+
+    self.init_number_constructor(parse_int, parse_float);
+    self.init_string_constructor();
+    self.init_boolean_constructor();
+    self.init_bigint_constructor();
+
+    init_map!(self.globals, {
+      "parseInt" => parse_int,
+      "parseFloat" => parse_float,
+      "isNaN" => is_nan,
+      "isFinite" => is_finite,
+    });
+  }
+
+  fn create_primitive_statics(&self, object_id: ObjectId) -> &'a mut ObjectValue<'a> {
+    let factory = self.factory;
+    let object =
+      factory.builtin_object(object_id, ObjectPrototype::Builtin(&self.prototypes.function), false);
+    object.init_rest(factory, ObjectPropertyValue::Field(factory.unknown, true));
+    object
+  }
+
+  fn init_number_constructor(&mut self, parse_int: Entity<'a>, parse_float: Entity<'a>) {
+    let factory = self.factory;
+    let object = self.create_primitive_statics(NUMBER_CONSTRUCTOR_OBJECT_ID);
+
+    init_namespace!(object, factory, {
+      "prototype" => factory.unknown,
+      "EPSILON" => factory.number(f64::EPSILON, None),
+      "MAX_SAFE_INTEGER" => factory.number(MAX_SAFE_INTEGER, None),
+      "MIN_SAFE_INTEGER" => factory.number(-MAX_SAFE_INTEGER, None),
+      "MAX_VALUE" => factory.number(f64::MAX, None),
+      "MIN_VALUE" => factory.number(5e-324, None),
+      "NaN" => factory.nan,
+      "NEGATIVE_INFINITY" => factory.infinity(false),
+      "POSITIVE_INFINITY" => factory.infinity(true),
+      "isFinite" => self.create_number_predicate_impl("Number.isFinite", f64::is_finite),
+      "isInteger" => self.create_number_predicate_impl("Number.isInteger", is_integer),
+      "isNaN" => self.create_number_predicate_impl("Number.isNaN", f64::is_nan),
+      "isSafeInteger" => self.create_number_predicate_impl("Number.isSafeInteger", is_safe_integer),
+      "parseFloat" => parse_float,
+      "parseInt" => parse_int,
+    });
+
+    let prototype = &self.prototypes.number;
+    let constructor =
+      factory.implemented_builtin_class("Number", object, Some(prototype), Some(call_number), {
+        move |analyzer, dep, args| construct_wrapper(analyzer, dep, args, prototype)
+      });
+    self.globals.insert("Number", constructor);
+  }
+
+  fn init_string_constructor(&mut self) {
+    let factory = self.factory;
+    let object = self.create_primitive_statics(STRING_CONSTRUCTOR_OBJECT_ID);
+
+    let from_char_code =
+      self.create_string_from_numbers_impl("String.fromCharCode", from_char_codes);
+    let from_code_point =
+      self.create_string_from_numbers_impl("String.fromCodePoint", from_code_points);
+    init_namespace!(object, factory, {
+      "prototype" => factory.unknown,
+      "fromCharCode" => from_char_code,
+      "fromCodePoint" => from_code_point,
+      "raw" => factory.pure_fn_returns_string,
+    });
+
+    let prototype = &self.prototypes.string;
+    let constructor =
+      factory.implemented_builtin_class("String", object, Some(prototype), Some(call_string), {
+        move |analyzer, dep, args| construct_string_wrapper(analyzer, dep, args, prototype)
+      });
+    self.globals.insert("String", constructor);
+  }
+
+  fn init_boolean_constructor(&mut self) {
+    let factory = self.factory;
+    let object = self.create_primitive_statics(BOOLEAN_CONSTRUCTOR_OBJECT_ID);
+
+    init_namespace!(object, factory, {
+      "prototype" => factory.unknown,
+    });
+
+    let prototype = &self.prototypes.boolean;
+    let constructor =
+      factory.implemented_builtin_class("Boolean", object, Some(prototype), Some(call_boolean), {
+        move |analyzer, dep, args| construct_wrapper(analyzer, dep, args, prototype)
+      });
+    self.globals.insert("Boolean", constructor);
+  }
+
+  fn init_bigint_constructor(&mut self) {
+    let factory = self.factory;
+    let object = self.create_primitive_statics(BIGINT_CONSTRUCTOR_OBJECT_ID);
+
+    init_namespace!(object, factory, {
+      "prototype" => factory.unknown,
+      "asIntN" => factory.pure_fn_returns_bigint,
+      "asUintN" => factory.pure_fn_returns_bigint,
+    });
+
+    let constructor = factory.implemented_builtin_class(
+      "BigInt",
+      object,
+      Some(&self.prototypes.bigint),
+      Some(call_bigint),
+      |analyzer, dep, args| throw_error(analyzer, dep, args, "BigInt is not a constructor"),
+    );
+    self.globals.insert("BigInt", constructor);
+  }
+
+  /// `Number.isNaN` and the like, which don't convert the argument
+  fn create_number_predicate_impl(&self, name: &'static str, f: fn(f64) -> bool) -> Entity<'a> {
+    self.factory.implemented_builtin_fn(name, move |analyzer, dep, _, args| {
+      let arg = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+      let result = if arg.test_typeof().contains(TypeofResult::Number) {
+        match arg.get_literal(analyzer) {
+          Some(LiteralValue::Number(value, _)) => Some(f(value.0)),
+          Some(LiteralValue::NaN) => Some(f(f64::NAN)),
+          Some(LiteralValue::Infinity(positive)) => {
+            Some(f(if positive { f64::INFINITY } else { f64::NEG_INFINITY }))
+          }
+          _ => None,
+        }
+      } else {
+        Some(false)
+      };
+      analyzer.factory.computed(analyzer.factory.boolean_maybe_unknown(result), (dep, args))
+    })
+  }
+
+  /// The global `isNaN` and `isFinite`, which convert the argument to a number first
+  fn create_global_number_predicate_impl(
+    &self,
+    name: &'static str,
+    f: fn(f64) -> bool,
+  ) -> Entity<'a> {
+    self.factory.implemented_builtin_fn(name, move |analyzer, dep, _, args| {
+      let arg = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+      let result = get_number_arg(analyzer, arg).map(f);
+      analyzer.factory.computed(analyzer.factory.boolean_maybe_unknown(result), (dep, args))
+    })
+  }
+
+  fn create_parse_int_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("parseInt", |analyzer, dep, _, args| {
+      let args_arr = args.destruct_as_array(analyzer, dep, 2, false).0;
+      let result = get_number_arg(analyzer, args_arr[1]).and_then(|radix| {
+        let input = get_string_arg(analyzer, args_arr[0])?;
+        Some(parse_int(input, radix.to_int_32()))
+      });
+      let result = match result {
+        Some(result) => number_to_entity(analyzer.factory, result),
+        None => analyzer.factory.unknown_number,
+      };
+      analyzer.factory.computed(result, (dep, args))
+    })
+  }
+
+  fn create_parse_float_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("parseFloat", |analyzer, dep, _, args| {
+      let arg = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+      let result = match get_string_arg(analyzer, arg) {
+        Some(input) => number_to_entity(analyzer.factory, parse_float(input)),
+        None => analyzer.factory.unknown_number,
+      };
+      analyzer.factory.computed(result, (dep, args))
+    })
+  }
+
+  fn create_string_from_numbers_impl(
+    &self,
+    name: &'static str,
+    f: fn(Vec<f64>) -> Option<String>,
+  ) -> Entity<'a> {
+    self.factory.implemented_builtin_fn(name, move |analyzer, dep, _, args| {
+      let (elements, rest, _) = args.iterate(analyzer, dep);
+      let numbers = if rest.is_some() {
+        None
+      } else {
+        elements.into_iter().map(|element| get_number_arg(analyzer, element)).collect()
+      };
+      let result = match numbers.and_then(f) {
+        Some(result) => analyzer.factory.string(analyzer.allocator.alloc_str(&result)),
+        None => analyzer.factory.unknown_string,
+      };
+      analyzer.factory.computed(result, (dep, args))
+    })
+  }
+}
+
+/// Applies `convert` to the first argument, or returns `default` if there is no argument
+fn convert_first_arg<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  args: Entity<'a>,
+  default: Entity<'a>,
+  convert: fn(&mut Analyzer<'a>, Entity<'a>) -> Entity<'a>,
+) -> Entity<'a> {
+  let (elements, rest, _) = args.iterate(analyzer, dep);
+  let result = match (elements.first(), rest) {
+    (Some(arg), _) => convert(analyzer, *arg),
+    (None, None) => default,
+    (None, Some(rest)) => {
+      let converted = convert(analyzer, rest);
+      analyzer.factory.union((default, converted))
+    }
+  };
+  analyzer.factory.computed(result, (dep, args))
+}
+
+fn call_number<'a>(analyzer: &mut Analyzer<'a>, dep: Dep<'a>, args: Entity<'a>) -> Entity<'a> {
+  let zero = analyzer.factory.number(0.0, Some("0"));
+  convert_first_arg(analyzer, dep, args, zero, |analyzer, value| {
+    let numeric = value.get_to_numeric(analyzer);
+    match numeric.get_literal(analyzer) {
+      Some(LiteralValue::BigInt(value)) => match value.parse::<f64>() {
+        Ok(value) => number_to_entity(analyzer.factory, value),
+        Err(_) => analyzer.factory.unknown_number,
+      },
+      Some(_) => numeric,
+      None if numeric.test_typeof() == TypeofResult::Number => numeric,
+      None => analyzer.factory.unknown_number,
+    }
+  })
+}
+
+fn call_string<'a>(analyzer: &mut Analyzer<'a>, dep: Dep<'a>, args: Entity<'a>) -> Entity<'a> {
+  let empty = analyzer.factory.string("");
+  convert_first_arg(analyzer, dep, args, empty, |analyzer, value| {
+    // `String(symbol)` returns the description, while `get_to_string` would throw
+    if value.test_typeof().contains(TypeofResult::Symbol) {
+      return analyzer.factory.unknown_string;
+    }
+    let string = value.get_to_string(analyzer);
+    if string.test_typeof() == TypeofResult::String {
+      string
+    } else {
+      analyzer.factory.unknown_string
+    }
+  })
+}
+
+fn call_boolean<'a>(analyzer: &mut Analyzer<'a>, dep: Dep<'a>, args: Entity<'a>) -> Entity<'a> {
+  let r#false = analyzer.factory.boolean(false);
+  convert_first_arg(analyzer, dep, args, r#false, |analyzer, value| value.get_to_boolean(analyzer))
+}
+
+fn call_bigint<'a>(analyzer: &mut Analyzer<'a>, dep: Dep<'a>, args: Entity<'a>) -> Entity<'a> {
+  let arg = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+  let result = match arg.get_literal(analyzer) {
+    Some(LiteralValue::BigInt(_)) => arg,
+    Some(LiteralValue::Number(value, _)) => {
+      let value = value.0;
+      if !is_integer(value) {
+        return throw_error(analyzer, dep, args, "The number cannot be converted to a BigInt");
+      }
+      let value = if value == 0.0 { "0".to_string() } else { format!("{value:.0}") };
+      analyzer.factory.big_int(analyzer.allocator.alloc_str(&value))
+    }
+    Some(LiteralValue::Boolean(value)) => analyzer.factory.big_int(if value { "1" } else { "0" }),
+    Some(LiteralValue::String(value, atom)) => match parse_bigint_string(value) {
+      Some(value) => {
+        analyzer.consume(atom);
+        analyzer.factory.big_int(analyzer.allocator.alloc_str(&value))
+      }
+      None => analyzer.factory.unknown_bigint,
+    },
+    Some(LiteralValue::NaN | LiteralValue::Infinity(_)) => {
+      return throw_error(analyzer, dep, args, "The number cannot be converted to a BigInt");
+    }
+    Some(LiteralValue::Null | LiteralValue::Undefined | LiteralValue::Symbol(_, _)) => {
+      return throw_error(analyzer, dep, args, "Cannot convert the value to a BigInt");
+    }
+    None => analyzer.factory.unknown_bigint,
+  };
+  analyzer.factory.computed(result, (dep, args))
+}
+
+/// `new Number(...)` and the like create objects, whose primitive values are not tracked
+fn construct_wrapper<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  args: Entity<'a>,
+  prototype: &'a BuiltinPrototype<'a>,
+) -> Entity<'a> {
+  let object = analyzer.new_empty_object(ObjectPrototype::Builtin(prototype), None);
+  analyzer.factory.computed((&*object).into(), (dep, args))
+}
+
+/// `new String(...)` also has the characters and the length of the string as readonly properties
+fn construct_string_wrapper<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  args: Entity<'a>,
+  prototype: &'a BuiltinPrototype<'a>,
+) -> Entity<'a> {
+  let string = call_string(analyzer, dep, args);
+  let object = analyzer.new_empty_object(ObjectPrototype::Builtin(prototype), None);
+
+  let literal = match string.get_literal(analyzer) {
+    // Only when each character is a single UTF-16 code unit
+    Some(LiteralValue::String(value, atom)) if value.chars().all(|c| c.len_utf16() == 1) => {
+      analyzer.consume(atom);
+      Some(value)
+    }
+    _ => None,
+  };
+  if let Some(value) = literal {
+    let mut keyed = object.keyed.borrow_mut();
+    let mut init = |key: &'a str, value: Entity<'a>, enumerable: bool| {
+      keyed.insert(
+        PropertyKeyValue::String(key),
+        ObjectProperty {
+          definite: true,
+          enumerable,
+          possible_values: analyzer
+            .factory
+            .vec1(ObjectPropertyValue::Field(analyzer.factory.computed(value, string), true)),
+          non_existent: DepCollector::new(analyzer.factory.vec()),
+          key: None,
+          mangling: None,
+        },
+      );
+    };
+    for (index, (offset, c)) in value.char_indices().enumerate() {
+      let key = analyzer.allocator.alloc_str(&index.to_string());
+      init(key, analyzer.factory.string(&value[offset..offset + c.len_utf8()]), true);
+    }
+    init("length", analyzer.factory.number(value.chars().count() as f64, None), false);
+  } else {
+    let property = ObjectPropertyValue::Field(analyzer.factory.computed_unknown(string), true);
+    object.init_rest(analyzer.factory, property);
+  }
+
+  analyzer.factory.computed((&*object).into(), string)
+}
+
+fn throw_error<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  args: Entity<'a>,
+  message: &'static str,
+) -> Entity<'a> {
+  analyzer.throw_builtin_error(message);
+  if analyzer.config.preserve_exceptions {
+    analyzer.consume((dep, args));
+    analyzer.factory.unknown
+  } else {
+    analyzer.factory.never
+  }
+}
+
+fn is_integer(value: f64) -> bool {
+  value.is_finite() && value.trunc() == value
+}
+
+fn is_safe_integer(value: f64) -> bool {
+  is_integer(value) && value.abs() <= MAX_SAFE_INTEGER
+}
+
+fn parse_int(input: &str, radix: i32) -> f64 {
+  let input = input.trim_start_matches(is_js_whitespace);
+  let (negative, mut input) = match input.as_bytes().first() {
+    Some(b'-') => (true, &input[1..]),
+    Some(b'+') => (false, &input[1..]),
+    _ => (false, input),
+  };
+
+  let strip_prefix = radix == 0 || radix == 16;
+  let mut radix = if radix == 0 { 10 } else { radix };
+  if !(2..=36).contains(&radix) {
+    return f64::NAN;
+  }
+  if strip_prefix {
+    if let Some(rest) = input.strip_prefix("0x").or_else(|| input.strip_prefix("0X")) {
+      input = rest;
+      radix = 16;
+    }
+  }
+
+  let radix = radix as u32;
+  let end = input.find(|c: char| !c.is_digit(radix)).unwrap_or(input.len());
+  let digits = &input[..end];
+  if digits.is_empty() {
+    return f64::NAN;
+  }
+  let value = if radix == 10 {
+    digits.parse::<f64>().unwrap_or(f64::NAN)
+  } else {
+    digits.chars().fold(0.0, |acc, c| acc * radix as f64 + c.to_digit(radix).unwrap() as f64)
+  };
+  if negative { -value } else { value }
+}
+
+fn parse_float(input: &str) -> f64 {
+  let input = input.trim_start_matches(is_js_whitespace);
+  let bytes = input.as_bytes();
+  let skip_digits = |mut index: usize| {
+    while bytes.get(index).is_some_and(u8::is_ascii_digit) {
+      index += 1;
+    }
+    index
+  };
+
+  let mut end = if matches!(bytes.first(), Some(b'+' | b'-')) { 1 } else { 0 };
+  if input[end..].starts_with("Infinity") {
+    return if bytes[0] == b'-' { f64::NEG_INFINITY } else { f64::INFINITY };
+  }
+
+  let digits_start = end;
+  end = skip_digits(end);
+  let mut has_digits = end > digits_start;
+  if bytes.get(end) == Some(&b'.') {
+    let fraction_end = skip_digits(end + 1);
+    has_digits |= fraction_end > end + 1;
+    end = fraction_end;
+  }
+  if !has_digits {
+    return f64::NAN;
+  }
+  if matches!(bytes.get(end), Some(b'e' | b'E')) {
+    let exponent_start =
+      if matches!(bytes.get(end + 1), Some(b'+' | b'-')) { end + 2 } else { end + 1 };
+    let exponent_end = skip_digits(exponent_start);
+    if exponent_end > exponent_start {
+      end = exponent_end;
+    }
+  }
+  input[..end].parse().unwrap_or(f64::NAN)
+}
+
+/// Only decimal literals are folded
+fn parse_bigint_string(value: &str) -> Option<String> {
+  let value = value.trim_matches(is_js_whitespace);
+  let (negative, digits) = match value.strip_prefix('-') {
+    Some(digits) => (true, digits),
+    None => (false, value.strip_prefix('+').unwrap_or(value)),
+  };
+  if value.is_empty() {
+    return Some("0".to_string());
+  }
+  if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+    return None;
+  }
+  let digits = digits.trim_start_matches('0');
+  Some(if digits.is_empty() {
+    "0".to_string()
+  } else if negative {
+    format!("-{digits}")
+  } else {
+    digits.to_string()
+  })
+}
+
+fn from_char_codes(codes: Vec<f64>) -> Option<String> {
+  let units: Vec<u16> = codes.into_iter().map(|code| code.to_int_32() as u16).collect();
+  String::from_utf16(&units).ok()
+}
+
+fn from_code_points(code_points: Vec<f64>) -> Option<String> {
+  code_points
+    .into_iter()
+    .map(|code_point| {
+      if is_integer(code_point) && (0.0..=1114111.0).contains(&code_point) {
+        char::from_u32(code_point as u32)
+      } else {
+        None
+      }
+    })
+    .collect()
+}
@@ -0,0 +1,319 @@
+use std::cell::Cell;
+
+use oxc::{allocator, ast::ast::PropertyKind};
+
+use crate::{
+  analyzer::Analyzer,
+  builtins::{Builtins, constants::PROMISE_CONSTRUCTOR_OBJECT_ID},
+  dep::Dep,
+  entity::Entity,
+  init_namespace,
+  value::{ObjectPropertyValue, ObjectPrototype, TypeofResult, promise::PromiseSettlement},
+};
+
+impl<'a> Builtins<'a> {
+  pub fn init_promise_constructor(&mut self) {
+    let factory = self.factory;
+
+    let object = factory.builtin_object(
+      PROMISE_CONSTRUCTOR_OBJECT_ID,
+      ObjectPrototype::Builtin(&self.prototypes.function),
+      false,
+    );
+    object.init_rest(factory, ObjectPropertyValue::Field(factory.unknown, true));
+
+    init_namespace!(object, factory, {
+      "prototype" => factory.unknown,
+      "resolve" => self.create_promise_resolve_impl(),
+      "reject" => self.create_promise_reject_impl(),
+      "all" => self.create_promise_combinator_impl("Promise.all", combine_all),
+      "allSettled" => self.create_promise_combinator_impl(
+        "Promise.allSettled",
+        combine_all_settled,
+      ),
+      "race" => self.create_promise_combinator_impl("Promise.race", combine_race),
+      "any" => self.create_promise_combinator_impl("Promise.any", combine_any),
+    });
+
+    let constructor = factory.implemented_builtin_class(
+      "Promise",
+      object,
+      Some(&self.prototypes.promise),
+      None,
+      construct_promise,
+    );
+
+    self.globals.insert("Promise", constructor);
+  }
+
+  fn create_promise_resolve_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Promise.resolve", |analyzer, dep, _, args| {
+      let value = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+      if value.as_promise().is_some_and(|promise| !promise.consumed.get()) {
+        // Promises are returned as is
+        return analyzer.factory.computed(value, dep);
+      }
+      let settlement = analyzer.resolve_promise_value(dep, value);
+      analyzer.factory.computed(analyzer.new_promise(settlement).into(), dep)
+    })
+  }
+
+  fn create_promise_reject_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Promise.reject", |analyzer, dep, _, args| {
+      let reason = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+      let settlement = PromiseSettlement::rejected(reason);
+      analyzer.factory.computed(analyzer.new_promise(settlement).into(), dep)
+    })
+  }
+
+  fn create_promise_combinator_impl(
+    &self,
+    name: &'static str,
+    combine: Combinator<'a>,
+  ) -> Entity<'a> {
+    self.factory.implemented_builtin_fn(name, move |analyzer, dep, _, args| {
+      let iterable = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+      let (elements, rest, iterate_dep) = iterable.iterate(analyzer, dep);
+      let elements = elements
+        .into_iter()
+        .map(|element| analyzer.resolve_promise_value(iterate_dep, element))
+        .collect::<Vec<_>>();
+      let rest = rest.map(|rest| analyzer.resolve_promise_value(iterate_dep, rest));
+      let settling_deps = allocator::Vec::from_iter_in(
+        elements.iter().chain(rest.iter()).map(|settlement| settlement.dep),
+        analyzer.allocator,
+      );
+      let settlement =
+        combine(analyzer, &elements, rest).with_dep(analyzer.dep(settling_deps), analyzer.factory);
+      analyzer.factory.computed(analyzer.new_promise(settlement).into(), iterate_dep)
+    })
+  }
+}
+
+/// Settles the combined promise from the settlements of the known elements and the rest elements,
+/// which may be zero or more
+type Combinator<'a> = fn(
+  &mut Analyzer<'a>,
+  &[PromiseSettlement<'a>],
+  Option<PromiseSettlement<'a>>,
+) -> PromiseSettlement<'a>;
+
+fn union_settlements<'a>(
+  analyzer: &Analyzer<'a>,
+  settlements: impl IntoIterator<Item = PromiseSettlement<'a>>,
+) -> PromiseSettlement<'a> {
+  settlements
+    .into_iter()
+    .fold(PromiseSettlement::pending(), |acc, settlement| acc.union(settlement, analyzer.factory))
+}
+
+fn combine_all<'a>(
+  analyzer: &mut Analyzer<'a>,
+  elements: &[PromiseSettlement<'a>],
+  rest: Option<PromiseSettlement<'a>>,
+) -> PromiseSettlement<'a> {
+  let rejected = union_settlements(
+    analyzer,
+    elements.iter().chain(rest.iter()).map(|settlement| PromiseSettlement {
+      rejected: settlement.rejected,
+      ..PromiseSettlement::pending()
+    }),
+  )
+  .rejected;
+
+  if elements.iter().any(|settlement| settlement.fulfilled.is_none()) {
+    return PromiseSettlement { rejected, ..PromiseSettlement::pending() };
+  }
+  let array = analyzer.new_empty_array();
+  for settlement in elements {
+    array.push_element(settlement.fulfilled.unwrap());
+  }
+  if let Some(value) = rest.and_then(|rest| rest.fulfilled) {
+    array.init_rest(value);
+  }
+  PromiseSettlement { fulfilled: Some(array.into()), rejected, dep: None }
+}
+
+fn combine_all_settled<'a>(
+  analyzer: &mut Analyzer<'a>,
+  elements: &[PromiseSettlement<'a>],
+  rest: Option<PromiseSettlement<'a>>,
+) -> PromiseSettlement<'a> {
+  let mut results = vec![];
+  for settlement in elements {
+    match create_settled_result(analyzer, *settlement) {
+      Some(result) => results.push(result),
+      None => return PromiseSettlement::pending(),
+    }
+  }
+  let array = analyzer.new_empty_array();
+  for result in results {
+    array.push_element(result);
+  }
+  if let Some(result) = rest.and_then(|rest| create_settled_result(analyzer, rest)) {
+    array.init_rest(result);
+  }
+  PromiseSettlement::fulfilled(array.into())
+}
+
+/// The `{ status, value }` or `{ status, reason }` objects of `Promise.allSettled`
+fn create_settled_result<'a>(
+  analyzer: &mut Analyzer<'a>,
+  settlement: PromiseSettlement<'a>,
+) -> Option<Entity<'a>> {
+  let mut results = analyzer.factory.vec();
+  for (status, key, value) in
+    [("fulfilled", "value", settlement.fulfilled), ("rejected", "reason", settlement.rejected)]
+  {
+    let Some(value) = value else {
+      continue;
+    };
+    let object = analyzer
+      .new_empty_object(ObjectPrototype::Builtin(&analyzer.builtins.prototypes.object), None);
+    let factory = analyzer.factory;
+    object.init_property(
+      analyzer,
+      PropertyKind::Init,
+      factory.string("status"),
+      factory.string(status),
+      true,
+    );
+    object.init_property(analyzer, PropertyKind::Init, factory.string(key), value, true);
+    results.push(object.into());
+  }
+  analyzer.factory.try_union(results)
+}
+
+fn combine_race<'a>(
+  analyzer: &mut Analyzer<'a>,
+  elements: &[PromiseSettlement<'a>],
+  rest: Option<PromiseSettlement<'a>>,
+) -> PromiseSettlement<'a> {
+  union_settlements(analyzer, elements.iter().chain(rest.iter()).copied())
+}
+
+fn combine_any<'a>(
+  analyzer: &mut Analyzer<'a>,
+  elements: &[PromiseSettlement<'a>],
+  rest: Option<PromiseSettlement<'a>>,
+) -> PromiseSettlement<'a> {
+  let fulfilled = union_settlements(
+    analyzer,
+    elements.iter().chain(rest.iter()).map(|settlement| PromiseSettlement {
+      fulfilled: settlement.fulfilled,
+      ..PromiseSettlement::pending()
+    }),
+  )
+  .fulfilled;
+  // Rejected with an `AggregateError` if all of them are rejected
+  let may_all_reject =
+    elements.iter().chain(rest.iter()).all(|settlement| settlement.rejected.is_some());
+  PromiseSettlement {
+    fulfilled,
+    rejected: may_all_reject.then_some(analyzer.factory.unknown),
+    dep: None,
+  }
+}
+
+/// Tracks the calls to the resolving functions passed to the executor
+struct PromiseResolvingState<'a> {
+  /// The depth of the scope catching the exceptions from the executor
+  cf_depth: usize,
+  settlement: Cell<PromiseSettlement<'a>>,
+  /// Whether it must have been settled. Later calls are ignored
+  settled: Cell<bool>,
+  /// Whether the executor has returned
+  finished: Cell<bool>,
+}
+
+fn construct_promise<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  args: Entity<'a>,
+) -> Entity<'a> {
+  let executor = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+  let typeof_executor = executor.test_typeof();
+  if !typeof_executor.contains(TypeofResult::Function) {
+    analyzer.throw_builtin_error("Promise resolver is not a function");
+    return if analyzer.config.preserve_exceptions {
+      analyzer.consume((dep, args));
+      analyzer.factory.unknown
+    } else {
+      analyzer.factory.never
+    };
+  }
+  if typeof_executor != TypeofResult::Function {
+    analyzer.consume((dep, executor));
+    let settlement = PromiseSettlement::unknown(analyzer.factory);
+    return analyzer.new_promise(settlement).into();
+  }
+
+  let state: &'a PromiseResolvingState<'a> = analyzer.allocator.alloc(PromiseResolvingState {
+    cf_depth: analyzer.scoping.cf.current_depth() + 1,
+    settlement: Cell::new(PromiseSettlement::pending()),
+    settled: Cell::new(false),
+    finished: Cell::new(false),
+  });
+  let mut resolving_functions = analyzer.factory.vec();
+  resolving_functions.push((false, create_resolving_function(analyzer, state, false)));
+  resolving_functions.push((false, create_resolving_function(analyzer, state, true)));
+  let (_, thrown, thrown_dep) = analyzer.call_catching(
+    dep,
+    executor,
+    analyzer.factory.undefined,
+    analyzer.factory.arguments(resolving_functions),
+  );
+  state.finished.set(true);
+
+  let collected = state.settlement.get();
+  let settlement = if state.settled.get() {
+    collected
+  } else if thrown == Some(true) {
+    let rejected =
+      PromiseSettlement::rejected(analyzer.factory.unknown).with_dep(thrown_dep, analyzer.factory);
+    collected.union(rejected, analyzer.factory)
+  } else {
+    // May be settled later
+    let unknown =
+      PromiseSettlement::unknown(analyzer.factory).with_dep(thrown_dep, analyzer.factory);
+    collected.union(unknown, analyzer.factory)
+  };
+  // The executor must be kept as a function
+  let dep = analyzer.dep((dep, executor.get_shallow_dep(analyzer)));
+  analyzer.factory.computed(analyzer.new_promise(settlement).into(), dep)
+}
+
+fn create_resolving_function<'a>(
+  analyzer: &Analyzer<'a>,
+  state: &'a PromiseResolvingState<'a>,
+  reject: bool,
+) -> Entity<'a> {
+  let name = if reject { "Promise reject function" } else { "Promise resolve function" };
+  analyzer.factory.implemented_consumable_fn(name, move |analyzer, dep, _, args| {
+    if state.settled.get() {
+      // No effect
+      return analyzer.factory.undefined;
+    }
+    let value = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+    if state.finished.get() {
+      // The settlement has been treated as unknown
+      analyzer.consume(value);
+      return analyzer.factory.undefined;
+    }
+
+    let settlement = if reject {
+      PromiseSettlement::rejected(analyzer.factory.computed(value, dep))
+    } else {
+      analyzer.resolve_promise_value(dep, value)
+    };
+    // Also keeps the executor which calls it
+    let exec_dep = analyzer.get_exec_dep(state.cf_depth);
+    let settlement = settlement.with_dep(analyzer.dep((dep, exec_dep)), analyzer.factory);
+    state.settlement.set(state.settlement.get().union(settlement, analyzer.factory));
+    if !analyzer.scoping.cf.iter_stack().skip(state.cf_depth).any(|scope| scope.is_indeterminate())
+    {
+      state.settled.set(true);
+    }
+    analyzer.factory.undefined
+  })
+}
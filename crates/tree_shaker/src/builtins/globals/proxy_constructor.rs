@@ -0,0 +1,48 @@
+use crate::{
+  builtins::{Builtins, constants::PROXY_CONSTRUCTOR_OBJECT_ID},
+  entity::Entity,
+  init_namespace,
+  value::{ObjectPropertyValue, ObjectPrototype, TypeofResult},
+};
+
+impl<'a> Builtins<'a> {
+  pub fn init_proxy_constructor(&mut self) {
+    let constructor = self.create_proxy_constructor();
+    self.globals.insert("Proxy", constructor);
+  }
+
+  fn create_proxy_constructor(&self) -> Entity<'a> {
+    let factory = self.factory;
+
+    let object = factory.builtin_object(
+      PROXY_CONSTRUCTOR_OBJECT_ID,
+      ObjectPrototype::Builtin(&self.prototypes.function),
+      false,
+    );
+    object.init_rest(factory, ObjectPropertyValue::Field(factory.unknown, true));
+
+    init_namespace!(object, factory, {
+      "revocable" => factory.unknown,
+    });
+
+    // `Proxy` has no `prototype`
+    factory.implemented_builtin_class("Proxy", object, None, None, |analyzer, dep, args| {
+      let [target, handler] = args.destruct_as_array(analyzer, dep, 2, false).0[..] else {
+        unreachable!()
+      };
+      if [target, handler]
+        .iter()
+        .any(|value| TypeofResult::_Primitive.contains(value.test_typeof()))
+      {
+        analyzer.throw_builtin_error("Cannot create proxy with a non-object as target or handler");
+        return if analyzer.config.preserve_exceptions {
+          analyzer.consume((dep, args));
+          analyzer.factory.unknown
+        } else {
+          analyzer.factory.never
+        };
+      }
+      analyzer.factory.computed(analyzer.new_proxy(target, handler).into(), dep)
+    })
+  }
+}
@@ -0,0 +1,222 @@
+use super::object_constructor::{define_property, test_extensible};
+use crate::{
+  analyzer::Analyzer,
+  builtins::{Builtins, constants::REFLECT_OBJECT_ID},
+  dep::Dep,
+  entity::Entity,
+  init_namespace,
+  value::{ObjectIntegrity, ObjectPropertyValue, ObjectPrototype},
+};
+
+impl<'a> Builtins<'a> {
+  pub fn init_reflect(&mut self) {
+    let factory = self.factory;
+
+    let object = factory.builtin_object(
+      REFLECT_OBJECT_ID,
+      ObjectPrototype::Builtin(&self.prototypes.object),
+      false,
+    );
+    object.init_rest(factory, ObjectPropertyValue::Field(factory.unknown, true));
+
+    init_namespace!(object, factory, {
+      "apply" => self.create_reflect_apply_impl(),
+      "construct" => self.create_reflect_construct_impl(),
+      "defineProperty" => self.create_reflect_define_property_impl(),
+      "deleteProperty" => self.create_reflect_delete_property_impl(),
+      "get" => self.create_reflect_get_impl(),
+      "getOwnPropertyDescriptor" => self.create_object_get_own_property_descriptor_impl(),
+      "getPrototypeOf" => self.create_object_get_prototype_of_impl(),
+      "has" => factory.pure_fn_returns_boolean,
+      "isExtensible" => self.create_object_test_integrity_impl(
+        "Reflect.isExtensible",
+        test_extensible,
+      ),
+      "ownKeys" => self.create_reflect_own_keys_impl(),
+      "preventExtensions" => self.create_reflect_prevent_extensions_impl(),
+      "set" => self.create_reflect_set_impl(),
+      "setPrototypeOf" => self.create_reflect_set_prototype_of_impl(),
+    });
+
+    self.globals.insert("Reflect", object.into());
+  }
+
+  fn create_reflect_apply_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Reflect.apply", |analyzer, dep, _, args| {
+      let [target, this, args_list] = args.destruct_as_array(analyzer, dep, 3, false).0[..] else {
+        unreachable!()
+      };
+      let args = create_arguments_from_list(analyzer, dep, args_list);
+      target.call(analyzer, dep, this, args)
+    })
+  }
+
+  fn create_reflect_construct_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Reflect.construct", |analyzer, dep, _, args| {
+      let [target, args_list, new_target] = args.destruct_as_array(analyzer, dep, 3, false).0[..]
+      else {
+        unreachable!()
+      };
+      if new_target.test_is_undefined() != Some(true) {
+        // `new.target` and the prototype of the result are not tracked
+        return call_unknown(analyzer, dep, args);
+      }
+      let args = create_arguments_from_list(analyzer, dep, args_list);
+      target.construct(analyzer, dep, args)
+    })
+  }
+
+  fn create_reflect_define_property_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Reflect.defineProperty", |analyzer, dep, _, args| {
+      let [object, key, descriptor] = args.destruct_as_array(analyzer, dep, 3, false).0[..] else {
+        unreachable!()
+      };
+      let may_fail = !is_extensible(object);
+      define_property(analyzer, dep, object, key, descriptor);
+      mutation_result(analyzer, dep, may_fail, args)
+    })
+  }
+
+  fn create_reflect_delete_property_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Reflect.deleteProperty", |analyzer, dep, _, args| {
+      let [object, key] = args.destruct_as_array(analyzer, dep, 2, false).0[..] else {
+        unreachable!()
+      };
+      let key = key.get_to_property_key(analyzer);
+      let may_fail = !is_extensible(object);
+      object.delete_property(analyzer, dep, key);
+      mutation_result(analyzer, dep, may_fail, args)
+    })
+  }
+
+  fn create_reflect_get_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Reflect.get", |analyzer, dep, _, args| {
+      let [object, key, receiver] = args.destruct_as_array(analyzer, dep, 3, false).0[..] else {
+        unreachable!()
+      };
+      if receiver.test_is_undefined() != Some(true) {
+        // Getters would be called with another `this`
+        return call_unknown(analyzer, dep, args);
+      }
+      let key = key.get_to_property_key(analyzer);
+      object.get_property(analyzer, dep, key)
+    })
+  }
+
+  fn create_reflect_own_keys_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Reflect.ownKeys", |analyzer, dep, _, args| {
+      let object = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+      let array = analyzer.new_empty_array();
+      if object.test_is_array() != Some(false) {
+        array.init_rest(analyzer.factory.string("length"));
+      }
+      if let Some(keys) = object.get_own_keys(analyzer, analyzer.dep((dep, object))) {
+        for (_, key) in keys {
+          array.init_rest(key);
+        }
+      } else {
+        array.init_rest(analyzer.factory.unknown);
+      }
+
+      analyzer.factory.computed(array.into(), (dep, object.get_shallow_dep(analyzer)))
+    })
+  }
+
+  fn create_reflect_prevent_extensions_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Reflect.preventExtensions", |analyzer, dep, _, args| {
+      let object = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+      if let Some(value) = object.as_object() {
+        let dep = analyzer.dep((dep, object.get_shallow_dep(analyzer)));
+        value.set_integrity(analyzer, dep, ObjectIntegrity::NonExtensible);
+        mutation_result(analyzer, dep, false, args)
+      } else {
+        if analyzer.config.preserve_writablity {
+          object.unknown_mutate(analyzer, dep);
+        }
+        mutation_result(analyzer, dep, true, args)
+      }
+    })
+  }
+
+  fn create_reflect_set_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Reflect.set", |analyzer, dep, _, args| {
+      let [object, key, value, receiver] = args.destruct_as_array(analyzer, dep, 4, false).0[..]
+      else {
+        unreachable!()
+      };
+      if receiver.test_is_undefined() != Some(true) {
+        // Setters would be called with another `this`
+        return call_unknown(analyzer, dep, args);
+      }
+      let key = key.get_to_property_key(analyzer);
+      let may_fail = !is_extensible(object);
+      object.set_property(analyzer, dep, key, value);
+      mutation_result(analyzer, dep, may_fail, args)
+    })
+  }
+
+  fn create_reflect_set_prototype_of_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Reflect.setPrototypeOf", |analyzer, dep, _, args| {
+      let [object, prototype] = args.destruct_as_array(analyzer, dep, 2, false).0[..] else {
+        unreachable!()
+      };
+      if let Some(value) = object.as_object() {
+        let dep = analyzer.dep((dep, object.get_shallow_dep(analyzer)));
+        let may_fail = !is_extensible(object);
+        value.mutate_prototype(analyzer, dep, prototype);
+        mutation_result(analyzer, dep, may_fail, args)
+      } else {
+        analyzer.consume(prototype);
+        object.unknown_mutate(analyzer, dep);
+        mutation_result(analyzer, dep, true, args)
+      }
+    })
+  }
+}
+
+/// The boolean returned by `Reflect` methods which mutate the target. It is only known to be
+/// `true` if the mutation can't fail and the writablity of the target is not tracked.
+fn mutation_result<'a>(
+  analyzer: &Analyzer<'a>,
+  dep: Dep<'a>,
+  may_fail: bool,
+  args: Entity<'a>,
+) -> Entity<'a> {
+  if may_fail || analyzer.config.preserve_writablity {
+    analyzer.factory.computed_unknown_boolean((dep, args))
+  } else {
+    analyzer.factory.computed(analyzer.factory.boolean(true), dep)
+  }
+}
+
+/// Whether the target is a tracked object which is known to be extensible
+fn is_extensible(object: Entity) -> bool {
+  object.as_object().is_some_and(|value| {
+    !value.consumed.get() && value.integrity.get() == Some(ObjectIntegrity::Extensible)
+  })
+}
+
+/// Keeps the positions of the known elements, which would be lost by spreading the list
+fn create_arguments_from_list<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  args_list: Entity<'a>,
+) -> Entity<'a> {
+  let (elements, rest, iterate_dep) = args_list.iterate(analyzer, dep);
+  let mut arguments = analyzer.factory.vec();
+  for element in elements {
+    arguments.push((false, analyzer.factory.computed(element, iterate_dep)));
+  }
+  if let Some(rest) = rest {
+    let rest_array = analyzer.new_empty_array();
+    rest_array.init_rest(rest);
+    arguments.push((true, analyzer.factory.computed(rest_array.into(), iterate_dep)));
+  }
+  analyzer.factory.arguments(arguments)
+}
+
+fn call_unknown<'a>(analyzer: &mut Analyzer<'a>, dep: Dep<'a>, args: Entity<'a>) -> Entity<'a> {
+  analyzer.consume((dep, args));
+  analyzer.refer_to_global();
+  analyzer.factory.unknown
+}
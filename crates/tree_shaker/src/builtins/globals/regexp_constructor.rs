@@ -0,0 +1,106 @@
+use crate::{
+  analyzer::Analyzer,
+  builtins::{Builtins, constants::REGEXP_CONSTRUCTOR_OBJECT_ID, utils::get_string_arg},
+  dep::Dep,
+  entity::Entity,
+  init_namespace,
+  utils::JsRegex,
+  value::{ObjectPropertyValue, ObjectPrototype},
+};
+
+impl Builtins<'_> {
+  pub fn init_regexp_constructor(&mut self) {
+    let factory = self.factory;
+
+    let object = factory.builtin_object(
+      REGEXP_CONSTRUCTOR_OBJECT_ID,
+      ObjectPrototype::Builtin(&self.prototypes.function),
+      false,
+    );
+    object.init_rest(factory, ObjectPropertyValue::Field(factory.unknown, true));
+
+    init_namespace!(object, factory, {
+      "prototype" => factory.unknown,
+    });
+
+    let constructor = factory.implemented_builtin_class(
+      "RegExp",
+      object,
+      Some(&self.prototypes.regexp),
+      Some(call_regexp),
+      |analyzer, dep, args| {
+        let [pattern, flags] = args.destruct_as_array(analyzer, dep, 2, false).0[..] else {
+          unreachable!()
+        };
+        create_regexp(analyzer, dep, pattern, flags)
+      },
+    );
+    self.globals.insert("RegExp", constructor);
+  }
+}
+
+/// `RegExp(regexp)` returns the argument itself
+fn call_regexp<'a>(analyzer: &mut Analyzer<'a>, dep: Dep<'a>, args: Entity<'a>) -> Entity<'a> {
+  let [pattern, flags] = args.destruct_as_array(analyzer, dep, 2, false).0[..] else {
+    unreachable!()
+  };
+  if pattern.as_regexp().is_some() && flags.test_is_undefined() == Some(true) {
+    return analyzer.factory.computed(pattern, dep);
+  }
+  create_regexp(analyzer, dep, pattern, flags)
+}
+
+fn create_regexp<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  pattern: Entity<'a>,
+  flags: Entity<'a>,
+) -> Entity<'a> {
+  let regexp = (|| {
+    let pattern_regexp = pattern.as_regexp().filter(|regexp| !regexp.consumed.get());
+    let source = if let Some(regexp) = pattern_regexp {
+      regexp.source
+    } else if pattern.test_is_undefined() == Some(true) {
+      ""
+    } else {
+      pattern.get_literal(analyzer)?;
+      get_string_arg(analyzer, pattern)?
+    };
+    let flags = if flags.test_is_undefined() == Some(true) {
+      pattern_regexp.map_or("", |regexp| regexp.flags)
+    } else {
+      flags.get_literal(analyzer)?;
+      get_string_arg(analyzer, flags)?
+    };
+    let source = if source.is_empty() { "(?:)" } else { source };
+    if source.contains(['/', '\n', '\r', '\u{2028}', '\u{2029}']) {
+      // These are escaped in `source`
+      return None;
+    }
+    let flags = canonicalize_flags(analyzer, flags)?;
+    // Invalid patterns and flags throw
+    JsRegex::new(source, flags)?;
+    Some(analyzer.new_regexp(source, flags))
+  })();
+
+  match regexp {
+    Some(regexp) => analyzer.factory.computed(regexp, (dep, pattern, flags)),
+    None => {
+      analyzer.consume((dep, pattern, flags));
+      analyzer.factory.unknown
+    }
+  }
+}
+
+/// Sorts the flags like `RegExp.prototype.flags`, `None` if they are invalid
+fn canonicalize_flags<'a>(analyzer: &Analyzer<'a>, flags: &str) -> Option<&'a str> {
+  let mut canonical = String::new();
+  for flag in "dgimsuvy".chars() {
+    match flags.matches(flag).count() {
+      0 => {}
+      1 => canonical.push(flag),
+      _ => return None,
+    }
+  }
+  (canonical.len() == flags.len()).then(|| analyzer.allocator.alloc_str(&canonical) as &str)
+}
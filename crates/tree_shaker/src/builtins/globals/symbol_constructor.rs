@@ -1,10 +1,16 @@
 use crate::{
+  analyzer::Analyzer,
   builtins::{Builtins, constants::SYMBOL_CONSTRUCTOR_OBJECT_ID},
+  dep::Dep,
+  entity::Entity,
   init_namespace,
-  value::{ObjectPropertyValue, ObjectPrototype},
+  value::{
+    LiteralValue, ObjectPropertyValue, ObjectPrototype, TypeofResult,
+    symbol::{new_symbol_id, registered_symbol_id},
+  },
 };
 
-impl Builtins<'_> {
+impl<'a> Builtins<'a> {
   pub fn init_symbol_constructor(&mut self) {
     let factory = self.factory;
 
@@ -17,22 +23,73 @@ impl Builtins<'_> {
 
     init_namespace!(object, factory, {
       "prototype" => factory.unknown,
-      // "asyncIterator" => factory.string("__#asyncIterator__"),
-      // "hasInstance" => factory.string("__#hasInstance__"),
-      // "isConcatSpreadable" => factory.string("__#isConcatSpreadable__"),
-      // "iterator" => factory.string("__#iterator__"),
-      // "match" => factory.string("__#match__"),
-      // "matchAll" => factory.string("__#matchAll__"),
-      // "replace" => factory.string("__#replace__"),
-      // "search" => factory.string("__#search__"),
-      // "species" => factory.string("__#species__"),
-      // "split" => factory.string("__#split__"),
-      // "toPrimitive" => factory.string("__#toPrimitive__"),
-      // "toStringTag" => factory.string("__#toStringTag__"),
-      // "unscopables" => factory.string("__#unscopables__"),
-      // "toString" => factory.string("__#toString__"),
+      "for" => self.create_symbol_for_impl(),
+      "asyncIterator" => factory.well_known_symbol("asyncIterator"),
+      "hasInstance" => factory.well_known_symbol("hasInstance"),
+      "isConcatSpreadable" => factory.well_known_symbol("isConcatSpreadable"),
+      "iterator" => factory.well_known_symbol("iterator"),
+      "match" => factory.well_known_symbol("match"),
+      "matchAll" => factory.well_known_symbol("matchAll"),
+      "replace" => factory.well_known_symbol("replace"),
+      "search" => factory.well_known_symbol("search"),
+      "species" => factory.well_known_symbol("species"),
+      "split" => factory.well_known_symbol("split"),
+      "toPrimitive" => factory.well_known_symbol("toPrimitive"),
+      "toStringTag" => factory.well_known_symbol("toStringTag"),
+      "unscopables" => factory.well_known_symbol("unscopables"),
     });
 
-    self.globals.insert("Symbol", object.into());
+    let prototype = &self.prototypes.symbol;
+    let constructor =
+      factory.implemented_builtin_class("Symbol", object, Some(prototype), Some(call_symbol), {
+        |analyzer, dep, args| {
+          analyzer.throw_builtin_error("Symbol is not a constructor");
+          if analyzer.config.preserve_exceptions {
+            analyzer.consume((dep, args));
+            analyzer.factory.unknown
+          } else {
+            analyzer.factory.never
+          }
+        }
+      });
+    self.globals.insert("Symbol", constructor);
+  }
+
+  fn create_symbol_for_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Symbol.for", |analyzer, dep, _, args| {
+      let key_arg = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+      let Some(key) = get_description(analyzer, key_arg) else {
+        return analyzer.factory.computed_unknown_symbol((dep, key_arg));
+      };
+      let str_rep = analyzer.allocator.alloc_str(&format!("Symbol({key})"));
+      let symbol = analyzer.factory.symbol(registered_symbol_id(key), str_rep);
+      analyzer.factory.computed(symbol, (dep, key_arg))
+    })
+  }
+}
+
+/// `Symbol()` creates a new symbol each time it is called
+fn call_symbol<'a>(analyzer: &mut Analyzer<'a>, dep: Dep<'a>, args: Entity<'a>) -> Entity<'a> {
+  let arg = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+  let description =
+    if arg.test_is_undefined() == Some(true) { Some("") } else { get_description(analyzer, arg) };
+  let Some(description) = description else {
+    return analyzer.factory.computed_unknown_symbol((dep, arg));
+  };
+  let str_rep = analyzer.allocator.alloc_str(&format!("Symbol({description})"));
+  analyzer.factory.computed(analyzer.factory.symbol(new_symbol_id(), str_rep), (dep, arg))
+}
+
+/// The description is converted to a string, which throws for symbols
+fn get_description<'a>(analyzer: &mut Analyzer<'a>, value: Entity<'a>) -> Option<&'a str> {
+  if value.test_typeof().contains(TypeofResult::Symbol) {
+    return None;
+  }
+  match value.get_to_string(analyzer).get_literal(analyzer)? {
+    LiteralValue::String(value, atom) => {
+      analyzer.consume(atom);
+      Some(value)
+    }
+    _ => None,
   }
 }
@@ -0,0 +1,71 @@
+use crate::{
+  analyzer::Analyzer,
+  builtins::{BuiltinPrototype, Builtins, constants::TEXT_CODEC_CONSTRUCTOR_OBJECT_ID},
+  dep::Dep,
+  entity::Entity,
+  init_namespace,
+  value::{ObjectPropertyValue, ObjectPrototype, builtin_fn::BuiltinConstructorCall},
+};
+
+impl<'a> Builtins<'a> {
+  pub fn init_text_codec_constructors(&mut self) {
+    let prototypes = self.prototypes;
+    self.init_text_codec_constructor(
+      "TextEncoder",
+      &prototypes.text_encoder,
+      construct_text_encoder,
+    );
+    self.init_text_codec_constructor(
+      "TextDecoder",
+      &prototypes.text_decoder,
+      construct_text_decoder,
+    );
+  }
+
+  fn init_text_codec_constructor(
+    &mut self,
+    name: &'static str,
+    prototype: &'a BuiltinPrototype<'a>,
+    construct: BuiltinConstructorCall<'a>,
+  ) {
+    let factory = self.factory;
+
+    let object = factory.builtin_object(
+      TEXT_CODEC_CONSTRUCTOR_OBJECT_ID,
+      ObjectPrototype::Builtin(&self.prototypes.function),
+      false,
+    );
+    object.init_rest(factory, ObjectPropertyValue::Field(factory.unknown, true));
+
+    init_namespace!(object, factory, {
+      "prototype" => factory.unknown,
+    });
+
+    let constructor =
+      factory.implemented_builtin_class(name, object, Some(prototype), None, construct);
+    self.globals.insert(name, constructor);
+  }
+}
+
+fn construct_text_encoder<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  _args: Entity<'a>,
+) -> Entity<'a> {
+  let prototypes = analyzer.builtins.prototypes;
+  let encoder = analyzer.new_empty_object(ObjectPrototype::Builtin(&prototypes.text_encoder), None);
+  analyzer.factory.computed(encoder.into(), dep)
+}
+
+/// The label and the options are read, but an unsupported label is not detected
+fn construct_text_decoder<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  args: Entity<'a>,
+) -> Entity<'a> {
+  let dep = analyzer.dep((dep, args));
+  args.unknown_mutate(analyzer, dep);
+  let prototypes = analyzer.builtins.prototypes;
+  let decoder = analyzer.new_empty_object(ObjectPrototype::Builtin(&prototypes.text_decoder), None);
+  analyzer.factory.computed(decoder.into(), dep)
+}
@@ -1,4 +1,6 @@
-use super::{Builtins, constants::IMPORT_META_OBJECT_ID, prototypes::BuiltinPrototypes};
+use super::{
+  Builtins, constants::IMPORT_META_OBJECT_ID, defines::DefineTree, prototypes::BuiltinPrototypes,
+};
 use crate::{
   analyzer::Factory,
   dep::DepCollector,
@@ -9,7 +11,8 @@ use crate::{
 impl<'a> Builtins<'a> {
   pub fn create_import_meta(
     factory: &'a Factory<'a>,
-    _prototypes: &'a BuiltinPrototypes<'a>,
+    prototypes: &'a BuiltinPrototypes<'a>,
+    defines: Option<&DefineTree<'a>>,
   ) -> Entity<'a> {
     let object =
       factory.builtin_object(IMPORT_META_OBJECT_ID, ObjectPrototype::ImplicitOrNull, true);
@@ -36,6 +39,11 @@ impl<'a> Builtins<'a> {
       },
     );
 
+    // Properties like `import.meta.env.MODE` from the defines
+    if let Some(defines) = defines {
+      Self::init_defined_properties(factory, prototypes, object, defines);
+    }
+
     object.into()
   }
 }
@@ -1,16 +1,18 @@
 mod constants;
+mod defines;
 mod globals;
 mod import_meta;
 mod known_modules;
 mod prototypes;
 mod react;
+mod require;
 mod utils;
 
+use defines::DefineTree;
 use known_modules::KnownModule;
-pub use prototypes::BuiltinPrototype;
-use prototypes::BuiltinPrototypes;
+pub use prototypes::{BuiltinPrototype, BuiltinPrototypes};
 use react::AnalyzerDataForReact;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{TreeShakeConfig, analyzer::Factory, entity::Entity};
 
@@ -19,8 +21,13 @@ pub struct Builtins<'a> {
   pub factory: &'a Factory<'a>,
 
   pub prototypes: &'a BuiltinPrototypes<'a>,
-  pub globals: FxHashMap<&'static str, Entity<'a>>,
+  pub globals: FxHashMap<&'a str, Entity<'a>>,
+  /// Globals which don't exist in the target environment, until they are written
+  pub absent_globals: FxHashSet<&'a str>,
+  /// Globals provided by the target environment, which are unknown once they are written
+  pub host_globals: FxHashSet<&'a str>,
   pub import_meta: Entity<'a>,
+  pub require: Entity<'a>,
   pub known_modules: FxHashMap<&'static str, KnownModule<'a>>,
 
   pub react_data: AnalyzerDataForReact<'a>,
@@ -29,18 +36,23 @@ pub struct Builtins<'a> {
 impl<'a> Builtins<'a> {
   pub fn new(config: &'a TreeShakeConfig, factory: &'a Factory<'a>) -> Self {
     let prototypes = Self::create_builtin_prototypes(factory);
+    let defines = DefineTree::new(config);
     let mut builtins = Self {
       config,
       factory,
 
       prototypes,
-      import_meta: Self::create_import_meta(factory, prototypes),
-      globals: Default::default(),       // Initialize later
-      known_modules: Default::default(), // Initialize later
+      import_meta: Self::create_import_meta(factory, prototypes, defines.import_meta()),
+      require: Self::create_require(factory, prototypes),
+      globals: Default::default(),        // Initialize later
+      absent_globals: Default::default(), // Initialize later
+      host_globals: Default::default(),   // Initialize later
+      known_modules: Default::default(),  // Initialize later
 
       react_data: Default::default(),
     };
     builtins.init_globals();
+    builtins.init_defines(&defines);
     builtins.init_known_modules();
     builtins
   }
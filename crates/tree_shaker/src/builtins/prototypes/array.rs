@@ -1,43 +1,1178 @@
-use super::{BuiltinPrototype, object::create_object_prototype};
-use crate::{analyzer::Factory, init_prototype};
+use std::{cmp::Ordering, ops::Range};
+
+use oxc::allocator;
+
+use super::{
+  BuiltinPrototype,
+  object::create_object_prototype,
+  utils::{call_unknown, resolve_relative_index, to_integer_or_infinity},
+};
+use crate::{
+  analyzer::{Analyzer, Factory},
+  dep::Dep,
+  entity::Entity,
+  init_prototype,
+  value::{LiteralValue, TypeofResult, array::ArrayValue, symbol::well_known_symbol_id},
+};
 
 pub fn create_array_prototype<'a>(factory: &Factory<'a>) -> BuiltinPrototype<'a> {
   init_prototype!("Array", create_object_prototype(factory), {
-    "at" => factory.unknown,
-    "concat" => factory.unknown /*pure_fn_returns_array*/,
-    "copyWithin" => factory.pure_fn_returns_unknown /* mutates_self */,
-    "entries" => factory.unknown /*pure_fn_returns_array*/,
-    "every" => factory.pure_fn_returns_boolean,
-    "fill" => factory.pure_fn_returns_unknown /* mutates_self */,
-    "filter" => factory.unknown /*pure_fn_returns_array*/,
-    "find" => factory.pure_fn_returns_unknown,
-    "findIndex" => factory.pure_fn_returns_number,
-    "findLast" => factory.pure_fn_returns_unknown,
-    "findLastIndex" => factory.pure_fn_returns_number,
-    "flat" => factory.unknown /*pure_fn_returns_array*/,
-    "flatMap" => factory.unknown /*pure_fn_returns_array*/,
-    "forEach" => factory.pure_fn_returns_unknown,
-    "includes" => factory.pure_fn_returns_boolean,
-    "indexOf" => factory.pure_fn_returns_number,
-    "join" => factory.pure_fn_returns_string,
-    "keys" => factory.pure_fn_returns_unknown,
-    "lastIndexOf" => factory.pure_fn_returns_number,
-    "map" => factory.unknown /*pure_fn_returns_array*/,
-    "pop" => factory.pure_fn_returns_unknown /* mutates_self */,
-    "push" => factory.pure_fn_returns_number /* mutates_self */,
-    "reduce" => factory.pure_fn_returns_unknown,
-    "reduceRight" => factory.pure_fn_returns_unknown,
-    "reverse" => factory.pure_fn_returns_unknown /* mutates_self */,
-    "shift" => factory.pure_fn_returns_unknown /* mutates_self */,
-    "slice" => factory.unknown /*pure_fn_returns_array*/,
-    "some" => factory.pure_fn_returns_boolean,
-    "sort" => factory.pure_fn_returns_unknown /* mutates_self */,
-    "splice" => factory.unknown /*pure_fn_returns_array*/ /* mutates_self */,
-    "toReversed" => factory.unknown /*pure_fn_returns_array*/,
-    "toSorted" => factory.unknown /*pure_fn_returns_array*/,
-    "toSpliced" => factory.unknown /*pure_fn_returns_array*/,
-    "unshift" => factory.pure_fn_returns_number /* mutates_self */,
-    "values" => factory.pure_fn_returns_unknown,
-    "with" => factory.unknown,
+    "at" => create_array_at_impl(factory),
+    "concat" => create_array_concat_impl(factory),
+    "copyWithin" => create_array_copy_within_impl(factory),
+    "entries" => create_array_iterator_impl(factory, "Array::entries"),
+    "every" => create_array_search_impl(factory, "Array::every", SearchKind::Every),
+    "fill" => create_array_fill_impl(factory),
+    "filter" => create_array_filter_impl(factory),
+    "find" => create_array_search_impl(factory, "Array::find", SearchKind::Find),
+    "findIndex" => create_array_search_impl(factory, "Array::findIndex", SearchKind::FindIndex),
+    "findLast" => create_array_search_impl(factory, "Array::findLast", SearchKind::FindLast),
+    "findLastIndex" => create_array_search_impl(factory, "Array::findLastIndex", SearchKind::FindLastIndex),
+    "flat" => create_array_flat_impl(factory),
+    "flatMap" => create_array_flat_map_impl(factory),
+    "forEach" => create_array_for_each_impl(factory),
+    "includes" => create_array_index_of_impl(factory, "Array::includes", true, false),
+    "indexOf" => create_array_index_of_impl(factory, "Array::indexOf", false, false),
+    "join" => create_array_join_impl(factory),
+    "keys" => create_array_iterator_impl(factory, "Array::keys"),
+    "lastIndexOf" => create_array_index_of_impl(factory, "Array::lastIndexOf", false, true),
+    "map" => create_array_map_impl(factory),
+    "pop" => create_array_pop_impl(factory),
+    "push" => create_array_push_impl(factory),
+    "reduce" => create_array_reduce_impl(factory, "Array::reduce", false),
+    "reduceRight" => create_array_reduce_impl(factory, "Array::reduceRight", true),
+    "reverse" => create_array_reverse_impl(factory),
+    "shift" => create_array_shift_impl(factory),
+    "slice" => create_array_slice_impl(factory),
+    "some" => create_array_search_impl(factory, "Array::some", SearchKind::Some),
+    "sort" => create_array_sort_impl(factory),
+    "splice" => create_array_splice_impl(factory),
+    "toReversed" => create_array_to_reversed_impl(factory),
+    "toSorted" => create_array_to_sorted_impl(factory),
+    "toSpliced" => create_array_to_spliced_impl(factory),
+    "unshift" => create_array_unshift_impl(factory),
+    "values" => create_array_iterator_impl(factory, "Array::values"),
+    "with" => create_array_with_impl(factory),
+    well_known_symbol_id("iterator") => create_array_iterator_impl(factory, "Array::values"),
+  })
+}
+
+fn create_array_for_each_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::forEach", |analyzer, dep, this, args| {
+    if this.test_is_array() != Some(true) {
+      return call_unknown(analyzer, dep, this, args);
+    }
+
+    let args_arr = args.destruct_as_array(analyzer, dep, 2, false).0;
+    let (callback, this_arg) = (args_arr[0], args_arr[1]);
+
+    visit_elements(analyzer, dep, this, false, false, |analyzer, dep, element, index| {
+      let index = index_entity(analyzer, index);
+      call_callback(analyzer, dep, callback, this_arg, &[element, index, this]);
+      Some(true)
+    });
+
+    analyzer.factory.undefined
+  })
+}
+
+fn create_array_map_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::map", |analyzer, dep, this, args| {
+    if this.test_is_array() != Some(true) {
+      return call_unknown(analyzer, dep, this, args);
+    }
+
+    let args_arr = args.destruct_as_array(analyzer, dep, 2, false).0;
+    let (callback, this_arg) = (args_arr[0], args_arr[1]);
+
+    let array = analyzer.new_empty_array();
+    let mut pushed = 0;
+    let mut has_rest = false;
+    let (length, iterate_dep) =
+      visit_elements(analyzer, dep, this, false, false, |analyzer, dep, element, index| {
+        let index_entity = index_entity(analyzer, index);
+        let mapped =
+          call_callback(analyzer, dep, callback, this_arg, &[element, index_entity, this]);
+        match index {
+          Some(index) if !has_rest => {
+            // Missing elements are left as holes
+            while pushed < index {
+              array.push_element(analyzer.factory.undefined);
+              pushed += 1;
+            }
+            array.push_element(mapped);
+            pushed += 1;
+          }
+          _ => {
+            array.init_rest(mapped);
+            has_rest = true;
+          }
+        }
+        Some(true)
+      });
+    if !has_rest {
+      while pushed < length {
+        array.push_element(analyzer.factory.undefined);
+        pushed += 1;
+      }
+    }
+
+    analyzer.factory.computed(array.into(), iterate_dep)
+  })
+}
+
+fn create_array_filter_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::filter", |analyzer, dep, this, args| {
+    if this.test_is_array() != Some(true) {
+      return call_unknown(analyzer, dep, this, args);
+    }
+
+    let args_arr = args.destruct_as_array(analyzer, dep, 2, false).0;
+    let (callback, this_arg) = (args_arr[0], args_arr[1]);
+
+    let array = analyzer.new_empty_array();
+    let mut deps = analyzer.factory.vec();
+    let (_, iterate_dep) =
+      visit_elements(analyzer, dep, this, false, false, |analyzer, dep, element, index| {
+        let is_rest = index.is_none();
+        let index = index_entity(analyzer, index);
+        let result = call_callback(analyzer, dep, callback, this_arg, &[element, index, this]);
+        let result = result.get_to_boolean(analyzer);
+        match result.test_truthy() {
+          Some(false) => {}
+          Some(true) if !is_rest => array.push_element(element),
+          _ => array.init_rest(element),
+        }
+        deps.push(result);
+        Some(true)
+      });
+
+    analyzer.factory.computed(array.into(), (iterate_dep, deps))
+  })
+}
+
+#[derive(Clone, Copy)]
+enum SearchKind {
+  Find,
+  FindIndex,
+  FindLast,
+  FindLastIndex,
+  Some,
+  Every,
+}
+
+fn create_array_search_impl<'a>(
+  factory: &Factory<'a>,
+  name: &'static str,
+  kind: SearchKind,
+) -> Entity<'a> {
+  factory.implemented_builtin_fn(name, move |analyzer, dep, this, args| {
+    if this.test_is_array() != Some(true) {
+      return call_unknown(analyzer, dep, this, args);
+    }
+
+    let args_arr = args.destruct_as_array(analyzer, dep, 2, false).0;
+    let (callback, this_arg) = (args_arr[0], args_arr[1]);
+
+    // `every` searches for the first falsy result
+    let expected = !matches!(kind, SearchKind::Every);
+    let reverse = matches!(kind, SearchKind::FindLast | SearchKind::FindLastIndex);
+    // `find` and its variants visit the missing elements as `undefined`
+    let visit_missing = !matches!(kind, SearchKind::Some | SearchKind::Every);
+
+    let mut matched = vec![];
+    let mut found = false;
+    let mut deps = analyzer.factory.vec();
+    let (_, iterate_dep) = visit_elements(
+      analyzer,
+      dep,
+      this,
+      reverse,
+      visit_missing,
+      |analyzer, dep, element, index| {
+        let is_rest = index.is_none();
+        let index = index_entity(analyzer, index);
+        let result = call_callback(analyzer, dep, callback, this_arg, &[element, index, this]);
+        let result = result.get_to_boolean(analyzer);
+        deps.push(result);
+        match result.test_truthy().map(|truthy| truthy == expected) {
+          Some(false) => Some(true),
+          Some(true) if !is_rest => {
+            matched.push((element, index));
+            found = true;
+            Some(false)
+          }
+          _ => {
+            matched.push((element, index));
+            None
+          }
+        }
+      },
+    );
+
+    let factory = analyzer.factory;
+    let result = match kind {
+      SearchKind::Find | SearchKind::FindLast => {
+        let mut values = factory.vec();
+        values.extend(matched.iter().map(|(element, _)| *element));
+        if !found {
+          values.push(factory.undefined);
+        }
+        factory.union(values)
+      }
+      SearchKind::FindIndex | SearchKind::FindLastIndex => {
+        let mut values = factory.vec();
+        values.extend(matched.iter().map(|(_, index)| *index));
+        if !found {
+          values.push(factory.number(-1.0, None));
+        }
+        factory.union(values)
+      }
+      SearchKind::Some | SearchKind::Every => factory.boolean_maybe_unknown(if found {
+        Some(expected)
+      } else if matched.is_empty() {
+        Some(!expected)
+      } else {
+        None
+      }),
+    };
+
+    factory.computed(result, (iterate_dep, deps))
+  })
+}
+
+fn create_array_reduce_impl<'a>(
+  factory: &Factory<'a>,
+  name: &'static str,
+  reverse: bool,
+) -> Entity<'a> {
+  factory.implemented_builtin_fn(name, move |analyzer, dep, this, args| {
+    if this.test_is_array() != Some(true) {
+      return call_unknown(analyzer, dep, this, args);
+    }
+
+    let (args_arr, args_rest, _) = args.iterate(analyzer, dep);
+    if args_rest.is_some() {
+      return call_unknown(analyzer, dep, this, args);
+    }
+    let callback = args_arr.first().copied().unwrap_or(analyzer.factory.undefined);
+    let mut accumulator = args_arr.get(1).copied();
+
+    let (_, iterate_dep) =
+      visit_elements(analyzer, dep, this, reverse, false, |analyzer, dep, element, index| {
+        let is_rest = index.is_none();
+        let previous = match accumulator {
+          Some(previous) => previous,
+          // The rest may be empty, so the callback may be called with it as the initial value
+          None if is_rest => element,
+          None => {
+            accumulator = Some(element);
+            return Some(true);
+          }
+        };
+        let this_arg = analyzer.factory.undefined;
+        let index = index_entity(analyzer, index);
+        let result =
+          call_callback(analyzer, dep, callback, this_arg, &[previous, element, index, this]);
+        accumulator =
+          Some(if is_rest { analyzer.factory.union((previous, result)) } else { result });
+        Some(true)
+      });
+
+    if let Some(accumulator) = accumulator {
+      analyzer.factory.computed(accumulator, iterate_dep)
+    } else {
+      analyzer.throw_builtin_error("Reduce of empty array with no initial value");
+      if analyzer.config.preserve_exceptions {
+        analyzer.consume((iterate_dep, args));
+        analyzer.factory.unknown
+      } else {
+        analyzer.factory.never
+      }
+    }
+  })
+}
+
+fn create_array_index_of_impl<'a>(
+  factory: &Factory<'a>,
+  name: &'static str,
+  includes: bool,
+  reverse: bool,
+) -> Entity<'a> {
+  factory.implemented_builtin_fn(name, move |analyzer, dep, this, args| {
+    if this.test_is_array() != Some(true) {
+      return call_unknown(analyzer, dep, this, args);
+    }
+
+    let args_arr = args.destruct_as_array(analyzer, dep, 2, false).0;
+    let (search, from_index) = (args_arr[0], args_arr[1]);
+    let (elements, rest, iterate_dep) = this.iterate(analyzer, dep);
+    let deps = (iterate_dep, args, collect_elements(analyzer, &elements, rest));
+
+    // The searched range of the known elements
+    let range = if reverse {
+      // The rest is searched first
+      if rest.is_none() { resolve_last_index(analyzer, from_index, elements.len()) } else { None }
+    } else if rest.is_none() {
+      resolve_relative_index(analyzer, from_index, elements.len(), 0)
+        .map(|start| start..elements.len())
+    } else {
+      (from_index.test_is_undefined() == Some(true)).then_some(0..elements.len())
+    };
+    let Some(range) = range else {
+      let result =
+        if includes { analyzer.factory.unknown_boolean } else { analyzer.factory.unknown_number };
+      return analyzer.factory.computed(result, deps);
+    };
+
+    let mut found = None;
+    let mut maybe = false;
+    let mut indexes = range.collect::<Vec<_>>();
+    if reverse {
+      indexes.reverse();
+    }
+    for index in indexes {
+      match test_eq(analyzer, elements[index], search, includes) {
+        Some(true) => {
+          found = Some(index);
+          break;
+        }
+        Some(false) => {}
+        None => maybe = true,
+      }
+    }
+    if found.is_none() {
+      if let Some(rest) = rest {
+        maybe |= test_eq(analyzer, rest, search, includes) != Some(false);
+      }
+    }
+
+    let factory = analyzer.factory;
+    let result = if includes {
+      factory.boolean_maybe_unknown(if found.is_some() {
+        Some(true)
+      } else if maybe {
+        None
+      } else {
+        Some(false)
+      })
+    } else if maybe {
+      factory.unknown_number
+    } else {
+      factory.number(found.map_or(-1.0, |index| index as f64), None)
+    };
+    factory.computed(result, deps)
+  })
+}
+
+/// Resolves the `fromIndex` argument of `lastIndexOf` to the searched range
+fn resolve_last_index<'a>(
+  analyzer: &Analyzer<'a>,
+  from_index: Entity<'a>,
+  length: usize,
+) -> Option<Range<usize>> {
+  if from_index.test_is_undefined() == Some(true) {
+    return Some(0..length);
+  }
+  let from_index = to_integer_or_infinity(analyzer, from_index)?;
+  let length = length as f64;
+  let end =
+    if from_index < 0.0 { length + from_index + 1.0 } else { (from_index + 1.0).min(length) };
+  Some(0..end.max(0.0) as usize)
+}
+
+/// Resolves an index argument like `at` does, `Some(None)` if it is out of range
+fn resolve_index<'a>(
+  analyzer: &Analyzer<'a>,
+  index: Entity<'a>,
+  length: usize,
+) -> Option<Option<usize>> {
+  let index = to_integer_or_infinity(analyzer, index)?;
+  let index = if index < 0.0 { length as f64 + index } else { index };
+  Some((index >= 0.0 && index < length as f64).then_some(index as usize))
+}
+
+fn create_array_at_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::at", |analyzer, dep, this, args| {
+    if this.test_is_array() != Some(true) {
+      return call_unknown(analyzer, dep, this, args);
+    }
+
+    let index = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+    let (elements, rest, iterate_dep) = this.iterate(analyzer, dep);
+
+    let factory = analyzer.factory;
+    let result = match rest.is_none().then(|| resolve_index(analyzer, index, elements.len())) {
+      Some(Some(Some(index))) => elements[index],
+      Some(Some(None)) => factory.undefined,
+      _ => {
+        let mut values = collect_elements(analyzer, &elements, rest);
+        values.push(factory.undefined);
+        factory.union(values)
+      }
+    };
+
+    factory.computed(result, (iterate_dep, args))
+  })
+}
+
+/// The elements are not read until the iterator is used
+fn create_array_iterator_impl<'a>(factory: &Factory<'a>, name: &'static str) -> Entity<'a> {
+  factory.implemented_builtin_fn(name, |analyzer, dep, this, args| {
+    if this.test_is_array() != Some(true) {
+      return call_unknown(analyzer, dep, this, args);
+    }
+
+    analyzer.factory.computed_unknown((dep, this, args))
+  })
+}
+
+fn create_array_join_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::join", |analyzer, dep, this, args| {
+    if this.test_is_array() != Some(true) {
+      return call_unknown(analyzer, dep, this, args);
+    }
+
+    let separator = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+    let (elements, rest, iterate_dep) = this.iterate(analyzer, dep);
+    let deps = (iterate_dep, args, collect_elements(analyzer, &elements, rest));
+
+    if rest.is_none() {
+      if let Some(joined) = join_elements(analyzer, &elements, separator) {
+        let joined = analyzer.factory.string(analyzer.allocator.alloc_str(&joined));
+        return analyzer.factory.computed(joined, deps);
+      }
+    }
+
+    let primitive_only = elements.iter().chain(rest.iter()).all(|element| {
+      !element.test_typeof().intersects(TypeofResult::Object | TypeofResult::Function)
+    });
+    if primitive_only {
+      analyzer.factory.computed_unknown_string(deps)
+    } else {
+      // May call `toString` of the elements
+      analyzer.consume((deps, this));
+      analyzer.factory.unknown_string
+    }
+  })
+}
+
+fn join_elements<'a>(
+  analyzer: &mut Analyzer<'a>,
+  elements: &[Entity<'a>],
+  separator: Entity<'a>,
+) -> Option<String> {
+  let separator = if separator.test_is_undefined() == Some(true) {
+    ","
+  } else {
+    literal_to_string(analyzer, separator.get_to_string(analyzer).get_literal(analyzer)?)?
+  };
+
+  let mut joined = String::new();
+  for (index, element) in elements.iter().enumerate() {
+    if index > 0 {
+      joined.push_str(separator);
+    }
+    match element.get_literal(analyzer)? {
+      LiteralValue::Undefined | LiteralValue::Null => {}
+      literal => joined.push_str(literal_to_string(analyzer, literal)?),
+    }
+  }
+  Some(joined)
+}
+
+fn create_array_slice_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::slice", |analyzer, dep, this, args| {
+    if this.test_is_array() != Some(true) {
+      return call_unknown(analyzer, dep, this, args);
+    }
+
+    let args_arr = args.destruct_as_array(analyzer, dep, 2, false).0;
+    let (start, end) = (args_arr[0], args_arr[1]);
+    let (elements, rest, iterate_dep) = this.iterate(analyzer, dep);
+
+    let range = if rest.is_none() {
+      let length = elements.len();
+      resolve_relative_index(analyzer, start, length, 0)
+        .zip(resolve_relative_index(analyzer, end, length, length))
+    } else {
+      None
+    };
+
+    let array = analyzer.new_empty_array();
+    if let Some((start, end)) = range {
+      for element in elements.iter().take(end).skip(start) {
+        array.push_element(*element);
+      }
+    } else {
+      for element in elements {
+        array.init_rest(element);
+      }
+      if let Some(rest) = rest {
+        array.init_rest(rest);
+      }
+    }
+
+    analyzer.factory.computed(array.into(), (iterate_dep, args))
+  })
+}
+
+fn create_array_to_reversed_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::toReversed", |analyzer, dep, this, args| {
+    if this.test_is_array() != Some(true) {
+      return call_unknown(analyzer, dep, this, args);
+    }
+
+    let (elements, rest, iterate_dep) = this.iterate(analyzer, dep);
+
+    let array = analyzer.new_empty_array();
+    if rest.is_none() {
+      for element in elements.into_iter().rev() {
+        array.push_element(element);
+      }
+    } else {
+      init_rest_elements(array, elements, rest);
+    }
+
+    analyzer.factory.computed(array.into(), (iterate_dep, args))
+  })
+}
+
+fn create_array_to_sorted_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::toSorted", |analyzer, dep, this, args| {
+    if this.test_is_array() != Some(true) {
+      return call_unknown(analyzer, dep, this, args);
+    }
+
+    let compare = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+    let (elements, rest, iterate_dep) = this.iterate(analyzer, dep);
+
+    let sorted = if compare.test_is_undefined() == Some(true) {
+      if rest.is_none() { sort_elements(analyzer, &elements) } else { None }
+    } else {
+      if let Some(value) = this.iterate_result_union(analyzer, dep, false) {
+        analyzer.push_indeterminate_cf_scope();
+        let this_arg = analyzer.factory.undefined;
+        call_callback(analyzer, dep, compare, this_arg, &[value, value]);
+        analyzer.pop_cf_scope();
+      }
+      // The order depends on the comparator
+      None
+    };
+
+    let array = analyzer.new_empty_array();
+    if let Some(sorted) = sorted {
+      for element in sorted {
+        array.push_element(element);
+      }
+    } else {
+      init_rest_elements(array, elements, rest);
+    }
+
+    analyzer.factory.computed(array.into(), (iterate_dep, args))
+  })
+}
+
+fn create_array_to_spliced_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::toSpliced", |analyzer, dep, this, args| {
+    if this.test_is_array() != Some(true) {
+      return call_unknown(analyzer, dep, this, args);
+    }
+
+    let (args_arr, args_rest, args_dep) = args.iterate(analyzer, dep);
+    let (elements, rest, iterate_dep) = this.iterate(analyzer, dep);
+
+    let range = if rest.is_none() && args_rest.is_none() {
+      resolve_splice_range(analyzer, &args_arr, elements.len())
+    } else {
+      None
+    };
+
+    let array = analyzer.new_empty_array();
+    if let Some((start, delete_count)) = range {
+      let items = args_arr.iter().skip(2).copied();
+      let mut elements = elements;
+      elements.splice(start..start + delete_count, items);
+      for element in elements {
+        array.push_element(element);
+      }
+    } else {
+      init_rest_elements(array, elements, rest);
+      init_rest_elements(array, args_arr.into_iter().skip(2).collect(), args_rest);
+    }
+
+    analyzer.factory.computed(array.into(), (iterate_dep, args_dep, args))
+  })
+}
+
+fn create_array_with_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::with", |analyzer, dep, this, args| {
+    if this.test_is_array() != Some(true) {
+      return call_unknown(analyzer, dep, this, args);
+    }
+
+    let args_arr = args.destruct_as_array(analyzer, dep, 2, false).0;
+    let (index, value) = (args_arr[0], args_arr[1]);
+    let (elements, rest, iterate_dep) = this.iterate(analyzer, dep);
+
+    let array = analyzer.new_empty_array();
+    match rest.is_none().then(|| resolve_index(analyzer, index, elements.len())) {
+      Some(Some(Some(index))) => {
+        for (i, element) in elements.into_iter().enumerate() {
+          array.push_element(if i == index { value } else { element });
+        }
+      }
+      Some(Some(None)) => {
+        analyzer.throw_builtin_error("Invalid index");
+        return if analyzer.config.preserve_exceptions {
+          analyzer.consume((iterate_dep, args));
+          analyzer.factory.unknown
+        } else {
+          analyzer.factory.never
+        };
+      }
+      _ => {
+        // May throw if the index is out of range
+        init_rest_elements(array, elements, rest);
+        array.init_rest(value);
+      }
+    }
+
+    analyzer.factory.computed(array.into(), (iterate_dep, args))
+  })
+}
+
+/// Appends the elements at unknown positions
+fn init_rest_elements<'a>(
+  array: &ArrayValue<'a>,
+  elements: Vec<Entity<'a>>,
+  rest: Option<Entity<'a>>,
+) {
+  for element in elements {
+    array.init_rest(element);
+  }
+  if let Some(rest) = rest {
+    array.init_rest(rest);
+  }
+}
+
+fn create_array_concat_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::concat", |analyzer, dep, this, args| {
+    if this.test_is_array() != Some(true) {
+      return call_unknown(analyzer, dep, this, args);
+    }
+
+    let (items, items_rest, items_dep) = args.iterate(analyzer, dep);
+    let mut deps = analyzer.factory.vec1(items_dep);
+
+    let array = analyzer.new_empty_array();
+    for item in [this].into_iter().chain(items) {
+      match item.test_is_array() {
+        Some(true) => {
+          let (elements, rest, iterate_dep) = item.iterate(analyzer, dep);
+          for element in elements {
+            array.push_element(element);
+          }
+          if let Some(rest) = rest {
+            array.init_rest(rest);
+          }
+          deps.push(iterate_dep);
+        }
+        Some(false) => array.push_element(item),
+        None => {
+          analyzer.consume(item);
+          array.init_rest(analyzer.factory.unknown);
+        }
+      }
+    }
+    if let Some(items_rest) = items_rest {
+      analyzer.consume(items_rest);
+      array.init_rest(analyzer.factory.unknown);
+    }
+
+    analyzer.factory.computed(array.into(), deps)
+  })
+}
+
+fn create_array_flat_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::flat", |analyzer, dep, this, args| {
+    if this.test_is_array() != Some(true) {
+      return call_unknown(analyzer, dep, this, args);
+    }
+
+    let depth = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+    let depth = if depth.test_is_undefined() == Some(true) {
+      1.0
+    } else if let Some(depth) = to_integer_or_infinity(analyzer, depth) {
+      depth
+    } else {
+      return call_unknown(analyzer, dep, this, args);
+    };
+
+    let array = analyzer.new_empty_array();
+    let mut deps = analyzer.factory.vec1(analyzer.dep(args));
+    flatten_element(analyzer, dep, array, this, depth + 1.0, false, &mut deps);
+
+    analyzer.factory.computed(array.into(), deps)
+  })
+}
+
+fn create_array_flat_map_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::flatMap", |analyzer, dep, this, args| {
+    if this.test_is_array() != Some(true) {
+      return call_unknown(analyzer, dep, this, args);
+    }
+
+    let args_arr = args.destruct_as_array(analyzer, dep, 2, false).0;
+    let (callback, this_arg) = (args_arr[0], args_arr[1]);
+
+    let array = analyzer.new_empty_array();
+    let mut deps = analyzer.factory.vec();
+    let (_, iterate_dep) =
+      visit_elements(analyzer, dep, this, false, false, |analyzer, dep, element, index| {
+        let is_rest = index.is_none();
+        let index = index_entity(analyzer, index);
+        let mapped = call_callback(analyzer, dep, callback, this_arg, &[element, index, this]);
+        flatten_element(analyzer, dep, array, mapped, 1.0, is_rest, &mut deps);
+        Some(true)
+      });
+    deps.push(iterate_dep);
+
+    analyzer.factory.computed(array.into(), deps)
+  })
+}
+
+/// Appends `element` to `array`, flattening nested arrays until `depth` reaches zero
+fn flatten_element<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  array: &ArrayValue<'a>,
+  element: Entity<'a>,
+  depth: f64,
+  is_rest: bool,
+  deps: &mut allocator::Vec<'a, Dep<'a>>,
+) {
+  if depth >= 1.0 {
+    match element.test_is_array() {
+      Some(true) => {
+        let (elements, rest, iterate_dep) = element.iterate(analyzer, dep);
+        deps.push(iterate_dep);
+        for element in elements {
+          flatten_element(analyzer, dep, array, element, depth - 1.0, is_rest, deps);
+        }
+        if let Some(rest) = rest {
+          flatten_element(analyzer, dep, array, rest, depth - 1.0, true, deps);
+        }
+        return;
+      }
+      Some(false) => {}
+      None => {
+        analyzer.consume(element);
+        array.init_rest(analyzer.factory.unknown);
+        return;
+      }
+    }
+  }
+
+  if is_rest {
+    array.init_rest(element);
+  } else {
+    array.push_element(element);
+  }
+}
+
+fn create_array_push_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::push", |analyzer, dep, this, args| {
+    let Some(array) = this.as_array() else {
+      return call_unknown(analyzer, dep, this, args);
+    };
+    let dep = analyzer.dep((dep, this.get_shallow_dep(analyzer)));
+
+    let (items, items_rest, _) = args.iterate(analyzer, dep);
+    let length = array.mutate(analyzer, dep, args, |analyzer, elements, rest| {
+      Some(if rest.is_empty() && items_rest.is_none() {
+        elements.extend(items);
+        analyzer.factory.number(elements.len() as f64, None)
+      } else {
+        rest.extend(items);
+        rest.extend(items_rest);
+        analyzer.factory.unknown_number
+      })
+    });
+
+    analyzer.factory.computed(length.unwrap_or(analyzer.factory.unknown_number), dep)
+  })
+}
+
+fn create_array_pop_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::pop", |analyzer, dep, this, args| {
+    let Some(array) = this.as_array() else {
+      return call_unknown(analyzer, dep, this, args);
+    };
+    let dep = analyzer.dep((dep, this.get_shallow_dep(analyzer)));
+
+    let element = array.mutate(analyzer, dep, args, |analyzer, elements, rest| {
+      rest.is_empty().then(|| elements.pop().unwrap_or(analyzer.factory.undefined))
+    });
+
+    analyzer.factory.computed(element.unwrap_or(analyzer.factory.unknown), dep)
+  })
+}
+
+fn create_array_shift_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::shift", |analyzer, dep, this, args| {
+    let Some(array) = this.as_array() else {
+      return call_unknown(analyzer, dep, this, args);
+    };
+    let dep = analyzer.dep((dep, this.get_shallow_dep(analyzer)));
+
+    let element = array.mutate(analyzer, dep, args, |analyzer, elements, rest| {
+      if elements.is_empty() {
+        rest.is_empty().then_some(analyzer.factory.undefined)
+      } else {
+        Some(elements.remove(0))
+      }
+    });
+
+    analyzer.factory.computed(element.unwrap_or(analyzer.factory.unknown), dep)
+  })
+}
+
+fn create_array_unshift_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::unshift", |analyzer, dep, this, args| {
+    let Some(array) = this.as_array() else {
+      return call_unknown(analyzer, dep, this, args);
+    };
+    let dep = analyzer.dep((dep, this.get_shallow_dep(analyzer)));
+
+    let (items, items_rest, _) = args.iterate(analyzer, dep);
+    let length = array.mutate(analyzer, dep, args, |analyzer, elements, rest| {
+      if items_rest.is_some() {
+        return None;
+      }
+      elements.splice(0..0, items);
+      Some(if rest.is_empty() {
+        analyzer.factory.number(elements.len() as f64, None)
+      } else {
+        analyzer.factory.unknown_number
+      })
+    });
+
+    analyzer.factory.computed(length.unwrap_or(analyzer.factory.unknown_number), dep)
+  })
+}
+
+fn create_array_splice_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::splice", |analyzer, dep, this, args| {
+    let Some(array) = this.as_array() else {
+      return call_unknown(analyzer, dep, this, args);
+    };
+    let dep = analyzer.dep((dep, this.get_shallow_dep(analyzer)));
+
+    let (args_arr, args_rest, _) = args.iterate(analyzer, dep);
+    let removed = analyzer.new_empty_array();
+    let result = array.mutate(analyzer, dep, args, |analyzer, elements, rest| {
+      if !rest.is_empty() || args_rest.is_some() {
+        return None;
+      }
+      let (start, delete_count) = resolve_splice_range(analyzer, &args_arr, elements.len())?;
+      let items = args_arr.iter().skip(2).copied();
+      for element in elements.splice(start..start + delete_count, items) {
+        removed.push_element(element);
+      }
+      Some(())
+    });
+
+    match result {
+      Some(()) => analyzer.factory.computed(removed.into(), dep),
+      None => analyzer.factory.computed_unknown(dep),
+    }
+  })
+}
+
+fn create_array_reverse_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::reverse", |analyzer, dep, this, args| {
+    let Some(array) = this.as_array() else {
+      return call_unknown(analyzer, dep, this, args);
+    };
+    let dep = analyzer.dep((dep, this.get_shallow_dep(analyzer)));
+
+    array
+      .mutate(analyzer, dep, args, |_, elements, rest| rest.is_empty().then(|| elements.reverse()));
+
+    analyzer.factory.computed(this, dep)
+  })
+}
+
+fn create_array_sort_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::sort", |analyzer, dep, this, args| {
+    let Some(array) = this.as_array() else {
+      return call_unknown(analyzer, dep, this, args);
+    };
+    let dep = analyzer.dep((dep, this.get_shallow_dep(analyzer)));
+
+    let compare = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+    if compare.test_is_undefined() == Some(true) {
+      array.mutate(analyzer, dep, args, |analyzer, elements, rest| {
+        if !rest.is_empty() {
+          return None;
+        }
+        let sorted = sort_elements(analyzer, elements)?;
+        elements.clear();
+        elements.extend(sorted);
+        Some(())
+      });
+    } else {
+      if let Some(value) = this.iterate_result_union(analyzer, dep, false) {
+        analyzer.push_indeterminate_cf_scope();
+        let this_arg = analyzer.factory.undefined;
+        call_callback(analyzer, dep, compare, this_arg, &[value, value]);
+        analyzer.pop_cf_scope();
+      }
+      // The order depends on the comparator
+      array.mutate(analyzer, dep, args, |_, _, _| None::<()>);
+    }
+
+    analyzer.factory.computed(this, dep)
   })
 }
+
+/// Sorts like the default comparator of `sort` does, `None` if the order is unknown
+fn sort_elements<'a>(
+  analyzer: &mut Analyzer<'a>,
+  elements: &[Entity<'a>],
+) -> Option<Vec<Entity<'a>>> {
+  // `undefined` elements are sorted to the end, others are compared as strings
+  let mut keyed = vec![];
+  for element in elements {
+    let key = match element.get_literal(analyzer)? {
+      LiteralValue::Undefined => None,
+      LiteralValue::Symbol(_, _) => return None,
+      literal => Some(literal_to_string(analyzer, literal)?.encode_utf16().collect::<Vec<_>>()),
+    };
+    keyed.push((key, *element));
+  }
+  keyed.sort_by(|(a, _), (b, _)| match (a, b) {
+    (None, None) => Ordering::Equal,
+    (None, Some(_)) => Ordering::Greater,
+    (Some(_), None) => Ordering::Less,
+    (Some(a), Some(b)) => a.cmp(b),
+  });
+  Some(keyed.into_iter().map(|(_, element)| element).collect())
+}
+
+/// Resolves the `start` and `deleteCount` arguments of `splice`
+fn resolve_splice_range<'a>(
+  analyzer: &Analyzer<'a>,
+  args: &[Entity<'a>],
+  length: usize,
+) -> Option<(usize, usize)> {
+  let start = match args.first() {
+    Some(start) => resolve_relative_index(analyzer, *start, length, 0)?,
+    None => 0,
+  };
+  let delete_count = match args.get(1) {
+    Some(count) => {
+      to_integer_or_infinity(analyzer, *count)?.clamp(0.0, (length - start) as f64) as usize
+    }
+    None if args.is_empty() => 0,
+    None => length - start,
+  };
+  Some((start, delete_count))
+}
+
+fn create_array_fill_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::fill", |analyzer, dep, this, args| {
+    let Some(array) = this.as_array() else {
+      return call_unknown(analyzer, dep, this, args);
+    };
+    let dep = analyzer.dep((dep, this.get_shallow_dep(analyzer)));
+
+    let args_arr = args.destruct_as_array(analyzer, dep, 3, false).0;
+    let (value, start, end) = (args_arr[0], args_arr[1], args_arr[2]);
+    array.mutate(analyzer, dep, args, |analyzer, elements, rest| {
+      if !rest.is_empty() {
+        return None;
+      }
+      let length = elements.len();
+      let start = resolve_relative_index(analyzer, start, length, 0)?;
+      let end = resolve_relative_index(analyzer, end, length, length)?;
+      for element in elements.iter_mut().take(end).skip(start) {
+        *element = value;
+      }
+      Some(())
+    });
+
+    analyzer.factory.computed(this, dep)
+  })
+}
+
+fn create_array_copy_within_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Array::copyWithin", |analyzer, dep, this, args| {
+    let Some(array) = this.as_array() else {
+      return call_unknown(analyzer, dep, this, args);
+    };
+    let dep = analyzer.dep((dep, this.get_shallow_dep(analyzer)));
+
+    array.mutate(analyzer, dep, args, |_, _, _| None::<()>);
+
+    analyzer.factory.computed(this, dep)
+  })
+}
+
+/// Calls the callback passed to an array method with the given arguments
+fn call_callback<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  callback: Entity<'a>,
+  this_arg: Entity<'a>,
+  args: &[Entity<'a>],
+) -> Entity<'a> {
+  let args = analyzer.factory.arguments(allocator::Vec::from_iter_in(
+    args.iter().map(|arg| (false, *arg)),
+    analyzer.allocator,
+  ));
+  callback.call(analyzer, dep, this_arg, args)
+}
+
+/// Visits the elements of `this` below its initial length, like the callback methods. They are
+/// re-read before each visit, since the callbacks may mutate the array. Missing elements are
+/// skipped, or visited as `undefined` if `visit_missing` is set. Elements that are not precisely
+/// known are visited once with an unknown index (`None`) in an indeterminate scope.
+///
+/// `visit` is called with the dep of reading the element, and returns `Some(true)` to continue,
+/// `Some(false)` to stop, or `None` if it may stop, after which the elements are visited in an
+/// indeterminate scope. Returns the initial length, and the deps of reading the elements.
+fn visit_elements<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  this: Entity<'a>,
+  reverse: bool,
+  visit_missing: bool,
+  mut visit: impl FnMut(&mut Analyzer<'a>, Dep<'a>, Entity<'a>, Option<usize>) -> Option<bool>,
+) -> (usize, Dep<'a>) {
+  let (elements, rest, iterate_dep) = this.iterate(analyzer, dep);
+  let length = elements.len();
+  let mut deps = analyzer.factory.vec1(iterate_dep);
+
+  let mut order = (0..length).map(Some).chain(rest.map(|_| None)).collect::<Vec<_>>();
+  if reverse {
+    order.reverse();
+  }
+
+  let mut current = Some((elements, rest, iterate_dep));
+  let mut rest_visited = false;
+  let mut indeterminate = false;
+  for index in order {
+    let (elements, rest, iterate_dep) = current.take().unwrap_or_else(|| {
+      let current = this.iterate(analyzer, dep);
+      deps.push(current.2);
+      current
+    });
+
+    let (element, is_rest) = match index {
+      Some(index) if index < elements.len() => (elements[index], false),
+      Some(_) if rest.is_none() => {
+        if !visit_missing {
+          continue;
+        }
+        (analyzer.factory.undefined, false)
+      }
+      Some(_) => (rest.unwrap(), true),
+      // Elements after the initial ones may have been added by the callbacks
+      None => {
+        let mut remaining = analyzer.factory.vec();
+        remaining.extend(elements.iter().skip(length).copied());
+        remaining.extend(rest);
+        match analyzer.factory.try_union(remaining) {
+          Some(remaining) => (remaining, true),
+          None => continue,
+        }
+      }
+    };
+
+    let result = if is_rest {
+      if rest_visited {
+        continue;
+      }
+      rest_visited = true;
+      analyzer.push_indeterminate_cf_scope();
+      let result = visit(analyzer, iterate_dep, element, None);
+      analyzer.pop_cf_scope();
+      result
+    } else {
+      visit(analyzer, iterate_dep, element, index)
+    };
+    match result {
+      Some(true) => {}
+      Some(false) => break,
+      None => {
+        if !indeterminate {
+          analyzer.push_indeterminate_cf_scope();
+          indeterminate = true;
+        }
+      }
+    }
+  }
+  if indeterminate {
+    analyzer.pop_cf_scope();
+  }
+
+  (length, analyzer.dep(deps))
+}
+
+/// The index passed to the callbacks
+fn index_entity<'a>(analyzer: &Analyzer<'a>, index: Option<usize>) -> Entity<'a> {
+  match index {
+    Some(index) => analyzer.factory.number(index as f64, None),
+    None => analyzer.factory.unknown_number,
+  }
+}
+
+fn collect_elements<'a>(
+  analyzer: &Analyzer<'a>,
+  elements: &[Entity<'a>],
+  rest: Option<Entity<'a>>,
+) -> allocator::Vec<'a, Entity<'a>> {
+  allocator::Vec::from_iter_in(elements.iter().copied().chain(rest), analyzer.allocator)
+}
+
+/// Compares with `===`, or `SameValueZero` if `same_value_zero` is set
+fn test_eq<'a>(
+  analyzer: &mut Analyzer<'a>,
+  a: Entity<'a>,
+  b: Entity<'a>,
+  same_value_zero: bool,
+) -> Option<bool> {
+  if Entity::value_eq(a, b) {
+    return Some(true);
+  }
+  if a.test_typeof() & b.test_typeof() == TypeofResult::_None {
+    return Some(false);
+  }
+  match (a.get_literal(analyzer)?, b.get_literal(analyzer)?) {
+    (LiteralValue::String(a, atom_a), LiteralValue::String(b, atom_b)) => {
+      // The result is not tracked by mangling constraints
+      analyzer.consume((atom_a, atom_b));
+      Some(a == b)
+    }
+    (LiteralValue::NaN, LiteralValue::NaN) => Some(same_value_zero),
+    (a, b) => Some(a.strict_eq(b).0),
+  }
+}
+
+fn literal_to_string<'a>(
+  analyzer: &mut Analyzer<'a>,
+  literal: LiteralValue<'a>,
+) -> Option<&'a str> {
+  match literal {
+    LiteralValue::String(value, atom) => {
+      // The string becomes part of the result, so it can't be mangled
+      analyzer.consume(atom);
+      Some(value)
+    }
+    // TypeError: Cannot convert a Symbol value to a string
+    LiteralValue::Symbol(_, _) => None,
+    literal => Some(literal.to_string(analyzer.allocator)),
+  }
+}
@@ -0,0 +1,86 @@
+use super::{BuiltinPrototype, object::create_object_prototype};
+use crate::{
+  analyzer::Factory,
+  init_prototype,
+  value::{array_buffer::ArrayBufferViewKind, symbol::well_known_symbol_id},
+};
+
+/// `byteLength` and the other accessors are handled by the instances
+pub fn create_array_buffer_prototype<'a>(factory: &Factory<'a>) -> BuiltinPrototype<'a> {
+  init_prototype!("ArrayBuffer", create_object_prototype(factory), {
+    "resize" => factory.pure_fn_returns_undefined,
+    "slice" => factory.pure_fn_returns_unknown,
+    "transfer" => factory.pure_fn_returns_unknown,
+    "transferToFixedLength" => factory.pure_fn_returns_unknown,
+  })
+}
+
+/// The methods which take a callback are not modeled, so calling them is never removed
+pub fn create_typed_array_prototype<'a>(
+  factory: &Factory<'a>,
+  kind: ArrayBufferViewKind,
+) -> BuiltinPrototype<'a> {
+  init_prototype!(kind.name(), create_object_prototype(factory), {
+    "BYTES_PER_ELEMENT" => factory.number(kind.bytes_per_element() as f64, None),
+    "at" => factory.pure_fn_returns_unknown,
+    "copyWithin" => factory.pure_fn_returns_unknown,
+    "entries" => factory.pure_fn_returns_unknown,
+    "every" => factory.unknown,
+    "fill" => factory.pure_fn_returns_unknown,
+    "filter" => factory.unknown,
+    "find" => factory.unknown,
+    "findIndex" => factory.unknown,
+    "findLast" => factory.unknown,
+    "findLastIndex" => factory.unknown,
+    "forEach" => factory.unknown,
+    "includes" => factory.pure_fn_returns_boolean,
+    "indexOf" => factory.pure_fn_returns_number,
+    "join" => factory.pure_fn_returns_string,
+    "keys" => factory.pure_fn_returns_unknown,
+    "lastIndexOf" => factory.pure_fn_returns_number,
+    "map" => factory.unknown,
+    "reduce" => factory.unknown,
+    "reduceRight" => factory.unknown,
+    "reverse" => factory.pure_fn_returns_unknown,
+    "set" => factory.pure_fn_returns_undefined,
+    "slice" => factory.pure_fn_returns_unknown,
+    "some" => factory.unknown,
+    "sort" => factory.unknown,
+    "subarray" => factory.pure_fn_returns_unknown,
+    "toLocaleString" => factory.pure_fn_returns_string,
+    "toReversed" => factory.pure_fn_returns_unknown,
+    "toSorted" => factory.unknown,
+    "toString" => factory.pure_fn_returns_string,
+    "values" => factory.pure_fn_returns_unknown,
+    "with" => factory.pure_fn_returns_unknown,
+    well_known_symbol_id("iterator") => factory.pure_fn_returns_unknown,
+  })
+}
+
+/// Getters only read the buffer, and setters write to it through `this`
+pub fn create_data_view_prototype<'a>(factory: &Factory<'a>) -> BuiltinPrototype<'a> {
+  init_prototype!("DataView", create_object_prototype(factory), {
+    "getBigInt64" => factory.pure_fn_returns_bigint,
+    "getBigUint64" => factory.pure_fn_returns_bigint,
+    "getFloat16" => factory.pure_fn_returns_number,
+    "getFloat32" => factory.pure_fn_returns_number,
+    "getFloat64" => factory.pure_fn_returns_number,
+    "getInt16" => factory.pure_fn_returns_number,
+    "getInt32" => factory.pure_fn_returns_number,
+    "getInt8" => factory.pure_fn_returns_number,
+    "getUint16" => factory.pure_fn_returns_number,
+    "getUint32" => factory.pure_fn_returns_number,
+    "getUint8" => factory.pure_fn_returns_number,
+    "setBigInt64" => factory.pure_fn_returns_undefined,
+    "setBigUint64" => factory.pure_fn_returns_undefined,
+    "setFloat16" => factory.pure_fn_returns_undefined,
+    "setFloat32" => factory.pure_fn_returns_undefined,
+    "setFloat64" => factory.pure_fn_returns_undefined,
+    "setInt16" => factory.pure_fn_returns_undefined,
+    "setInt32" => factory.pure_fn_returns_undefined,
+    "setInt8" => factory.pure_fn_returns_undefined,
+    "setUint16" => factory.pure_fn_returns_undefined,
+    "setUint32" => factory.pure_fn_returns_undefined,
+    "setUint8" => factory.pure_fn_returns_undefined,
+  })
+}
@@ -0,0 +1,11 @@
+use super::{BuiltinPrototype, object::create_object_prototype};
+use crate::analyzer::Factory;
+
+/// The members of `Map`, `Set`, `WeakMap` and `WeakSet` instances are provided by
+/// `CollectionValue` itself, so this prototype only identifies the instances.
+pub fn create_collection_prototype<'a>(
+  factory: &Factory<'a>,
+  name: &'static str,
+) -> BuiltinPrototype<'a> {
+  create_object_prototype(factory).with_name(name)
+}
@@ -0,0 +1,54 @@
+use super::{BuiltinPrototype, object::create_object_prototype};
+use crate::{analyzer::Factory, init_prototype, value::symbol::well_known_symbol_id};
+
+/// The time of a date is never known, and setters only mutate `this`
+pub fn create_date_prototype<'a>(factory: &Factory<'a>) -> BuiltinPrototype<'a> {
+  init_prototype!("Date", create_object_prototype(factory), {
+    "getDate" => factory.pure_fn_returns_number,
+    "getDay" => factory.pure_fn_returns_number,
+    "getFullYear" => factory.pure_fn_returns_number,
+    "getHours" => factory.pure_fn_returns_number,
+    "getMilliseconds" => factory.pure_fn_returns_number,
+    "getMinutes" => factory.pure_fn_returns_number,
+    "getMonth" => factory.pure_fn_returns_number,
+    "getSeconds" => factory.pure_fn_returns_number,
+    "getTime" => factory.pure_fn_returns_number,
+    "getTimezoneOffset" => factory.pure_fn_returns_number,
+    "getUTCDate" => factory.pure_fn_returns_number,
+    "getUTCDay" => factory.pure_fn_returns_number,
+    "getUTCFullYear" => factory.pure_fn_returns_number,
+    "getUTCHours" => factory.pure_fn_returns_number,
+    "getUTCMilliseconds" => factory.pure_fn_returns_number,
+    "getUTCMinutes" => factory.pure_fn_returns_number,
+    "getUTCMonth" => factory.pure_fn_returns_number,
+    "getUTCSeconds" => factory.pure_fn_returns_number,
+    "getYear" => factory.pure_fn_returns_number,
+    "setDate" => factory.pure_fn_returns_number,
+    "setFullYear" => factory.pure_fn_returns_number,
+    "setHours" => factory.pure_fn_returns_number,
+    "setMilliseconds" => factory.pure_fn_returns_number,
+    "setMinutes" => factory.pure_fn_returns_number,
+    "setMonth" => factory.pure_fn_returns_number,
+    "setSeconds" => factory.pure_fn_returns_number,
+    "setTime" => factory.pure_fn_returns_number,
+    "setUTCDate" => factory.pure_fn_returns_number,
+    "setUTCFullYear" => factory.pure_fn_returns_number,
+    "setUTCHours" => factory.pure_fn_returns_number,
+    "setUTCMilliseconds" => factory.pure_fn_returns_number,
+    "setUTCMinutes" => factory.pure_fn_returns_number,
+    "setUTCMonth" => factory.pure_fn_returns_number,
+    "setUTCSeconds" => factory.pure_fn_returns_number,
+    "setYear" => factory.pure_fn_returns_number,
+    "toDateString" => factory.pure_fn_returns_string,
+    "toISOString" => factory.pure_fn_returns_string,
+    "toJSON" => factory.pure_fn_returns_string,
+    "toLocaleDateString" => factory.pure_fn_returns_string,
+    "toLocaleString" => factory.pure_fn_returns_string,
+    "toLocaleTimeString" => factory.pure_fn_returns_string,
+    "toString" => factory.pure_fn_returns_string,
+    "toTimeString" => factory.pure_fn_returns_string,
+    "toUTCString" => factory.pure_fn_returns_string,
+    "valueOf" => factory.pure_fn_returns_number,
+    well_known_symbol_id("toPrimitive") => factory.pure_fn_returns_unknown,
+  })
+}
@@ -0,0 +1,54 @@
+use super::{BuiltinPrototype, object::create_object_prototype};
+use crate::{
+  analyzer::{Analyzer, Factory},
+  builtins::utils::get_string_arg,
+  dep::Dep,
+  entity::Entity,
+  init_prototype,
+};
+
+/// `Error.prototype` and the prototypes of its subclasses, which only differ in `name`
+pub fn create_error_prototype<'a>(
+  factory: &Factory<'a>,
+  name: &'static str,
+) -> BuiltinPrototype<'a> {
+  init_prototype!(name, create_object_prototype(factory), {
+    "message" => factory.string(""),
+    "name" => factory.string(name),
+    "toString" => factory.implemented_builtin_fn("Error::toString", error_to_string),
+  })
+}
+
+fn error_to_string<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  this: Entity<'a>,
+  args: Entity<'a>,
+) -> Entity<'a> {
+  let name = this.get_property(analyzer, dep, analyzer.factory.string("name"));
+  let message = this.get_property(analyzer, dep, analyzer.factory.string("message"));
+  let name_str = get_error_part(analyzer, name, "Error");
+  let message_str = get_error_part(analyzer, message, "");
+  let result = match (name_str, message_str) {
+    (Some(name), Some("")) => analyzer.factory.string(name),
+    (Some(""), Some(message)) => analyzer.factory.string(message),
+    (Some(name), Some(message)) => {
+      analyzer.factory.string(analyzer.allocator.alloc_str(&format!("{name}: {message}")))
+    }
+    _ => analyzer.factory.unknown_string,
+  };
+  analyzer.factory.computed(result, (dep, (this, args), (name, message)))
+}
+
+/// `name` and `message` default to `fallback` when they are `undefined`
+fn get_error_part<'a>(
+  analyzer: &mut Analyzer<'a>,
+  value: Entity<'a>,
+  fallback: &'a str,
+) -> Option<&'a str> {
+  if value.test_is_undefined() == Some(true) {
+    return Some(fallback);
+  }
+  value.get_literal(analyzer)?;
+  get_string_arg(analyzer, value)
+}
@@ -1,8 +1,12 @@
+use std::cell::Cell;
+
 use super::{BuiltinPrototype, object::create_object_prototype};
 use crate::{
-  analyzer::Factory,
-  init_prototype,
-  value::{ObjectId, arguments::ArgumentsValue},
+  analyzer::{Analyzer, Factory},
+  dep::Dep,
+  entity::Entity,
+  init_namespace, init_prototype,
+  value::{LiteralValue, ObjectId, ObjectPrototype, bound_function::BoundFunctionValue},
 };
 
 pub fn create_function_prototype<'a>(factory: &Factory<'a>) -> BuiltinPrototype<'a> {
@@ -11,16 +15,18 @@ pub fn create_function_prototype<'a>(factory: &Factory<'a>) -> BuiltinPrototype<
       let mut args = args.destruct_as_array(analyzer, dep, 2, false).0;
       let args_arg = {
         let arg = args.pop().unwrap();
-        let cf_scope = analyzer.scoping.cf.current_id();
-        // This can be any value
-        let arguments_object_id = ObjectId::from_usize(0);
-        match arg.test_is_undefined() {
-          Some(true) => analyzer.factory.array(cf_scope, arguments_object_id).into(),
-          Some(false) => arg,
-          None => analyzer.factory.union((
-            arg,
-            analyzer.factory.array(cf_scope, arguments_object_id).into(),
-          )),
+        if arg.test_nullish() == Some(true) {
+          // `null` and `undefined` mean no arguments
+          let cf_scope = analyzer.scoping.cf.current_id();
+          // This can be any value
+          let arguments_object_id = ObjectId::from_usize(0);
+          analyzer.factory.array(cf_scope, arguments_object_id).into()
+        } else if arg.test_is_array() == Some(true) {
+          arg
+        } else {
+          // The elements of array-like objects are not tracked
+          analyzer.consume(arg);
+          analyzer.factory.unknown
         }
       };
       let this_arg = args.pop().unwrap();
@@ -30,15 +36,27 @@ pub fn create_function_prototype<'a>(factory: &Factory<'a>) -> BuiltinPrototype<
       let (this_arg, args_arg, _deps) = args.destruct_as_array(analyzer, dep, 1, true);
       this.call(analyzer, dep, this_arg[0], args_arg.unwrap())
     }),
-    "bind" => factory.implemented_builtin_fn("Function::bind", |analyzer, dep, func, args| {
+    "bind" => factory.implemented_builtin_fn("Function::bind", |analyzer, dep, target, args| {
       let (bound_this, bound_args, _deps) = args.destruct_as_array(analyzer, dep, 1, true);
-      let bound_this = bound_this[0];
-      let bound_fn = analyzer.factory.implemented_consumable_fn("Function::bound_fn", move |analyzer, dep, this, args| {
-        let this = analyzer.op_undefined_or(bound_this, this);
-        let (args, dep) = ArgumentsValue::from_concatenate(analyzer, bound_args.unwrap(), args, dep);
-        func.call(analyzer, dep, this, args)
+      let bound_args = bound_args.unwrap();
+      let name = get_bound_name(analyzer, dep, target);
+      let length = get_bound_length(analyzer, dep, target, bound_args);
+      let statics = analyzer.new_empty_object(
+        ObjectPrototype::Builtin(&analyzer.builtins.prototypes.function),
+        None,
+      );
+      init_namespace!(statics, analyzer.factory, {
+        "name" => name,
+        "length" => length,
+      });
+      let bound_fn = analyzer.factory.alloc(BoundFunctionValue {
+        consumed: Cell::new(false),
+        target,
+        bound_this: bound_this[0],
+        bound_args,
+        statics,
       });
-      analyzer.factory.computed(bound_fn, dep)
+      analyzer.factory.computed(bound_fn.into(), dep)
     }),
     "length" => factory.unknown_number,
     "arguments" => factory.unknown,
@@ -47,3 +65,38 @@ pub fn create_function_prototype<'a>(factory: &Factory<'a>) -> BuiltinPrototype<
     "prototype" => factory.unknown,
   })
 }
+
+/// `"bound " + target.name`
+fn get_bound_name<'a>(analyzer: &mut Analyzer<'a>, dep: Dep<'a>, target: Entity<'a>) -> Entity<'a> {
+  if !analyzer.config.preserve_function_name {
+    return analyzer.factory.unknown_string;
+  }
+  let name = target.get_property(analyzer, dep, analyzer.factory.string("name"));
+  if let Some(LiteralValue::String(name_str, _)) = name.get_literal(analyzer) {
+    let bound_name = analyzer.allocator.alloc_str(&format!("bound {name_str}"));
+    analyzer.factory.computed(analyzer.factory.string(bound_name), name)
+  } else {
+    analyzer.factory.computed_unknown_string(name)
+  }
+}
+
+/// `target.length` minus the number of bound arguments
+fn get_bound_length<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  target: Entity<'a>,
+  bound_args: Entity<'a>,
+) -> Entity<'a> {
+  let length = target.get_property(analyzer, dep, analyzer.factory.string("length"));
+  if !analyzer.config.preserve_function_length {
+    return analyzer.factory.computed_unknown_number((length, bound_args));
+  }
+  let (known, rest, _) = bound_args.iterate(analyzer, dep);
+  if let (Some(LiteralValue::Number(length_num, _)), None) = (length.get_literal(analyzer), rest) {
+    let bound_length = (length_num.0 - known.len() as f64).max(0.0);
+    analyzer.factory.computed(analyzer.factory.number(bound_length, None), (length, bound_args))
+  } else {
+    // The length of the target depends on its parameters
+    analyzer.factory.computed_unknown_number((length, bound_args, target))
+  }
+}
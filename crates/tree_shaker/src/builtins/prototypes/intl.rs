@@ -0,0 +1,19 @@
+use super::{BuiltinPrototype, object::create_object_prototype};
+use crate::{analyzer::Factory, init_prototype};
+
+/// The members of the `Intl` services like `NumberFormat` and `Collator`, which share this
+/// prototype. Their results depend on the locale data of the host.
+pub fn create_intl_prototype<'a>(factory: &Factory<'a>) -> BuiltinPrototype<'a> {
+  init_prototype!("Intl", create_object_prototype(factory), {
+    "compare" => factory.pure_fn_returns_number,
+    "format" => factory.pure_fn_returns_string,
+    "formatRange" => factory.pure_fn_returns_string,
+    "formatRangeToParts" => factory.pure_fn_returns_unknown,
+    "formatToParts" => factory.pure_fn_returns_unknown,
+    "of" => factory.pure_fn_returns_unknown,
+    "resolvedOptions" => factory.pure_fn_returns_unknown,
+    "segment" => factory.pure_fn_returns_unknown,
+    "select" => factory.pure_fn_returns_string,
+    "selectRange" => factory.pure_fn_returns_string,
+  })
+}
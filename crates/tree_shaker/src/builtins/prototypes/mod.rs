@@ -1,7 +1,12 @@
 mod array;
+mod array_buffer;
 mod bigint;
 mod boolean;
+mod collection;
+mod date;
+mod error;
 mod function;
+mod intl;
 mod null;
 mod number;
 mod object;
@@ -9,9 +14,10 @@ mod promise;
 mod regexp;
 mod string;
 mod symbol;
+mod text_codec;
 mod utils;
 
-use std::fmt;
+use std::{fmt, ptr};
 
 use oxc::{allocator, semantic::SymbolId};
 
@@ -20,7 +26,7 @@ use crate::{
   analyzer::{Analyzer, Factory},
   dep::Dep,
   entity::Entity,
-  value::{LiteralValue, PropertyKeyValue},
+  value::{LiteralValue, PropertyKeyValue, array_buffer::ArrayBufferViewKind},
 };
 
 pub struct BuiltinPrototype<'a> {
@@ -60,7 +66,7 @@ impl<'a> BuiltinPrototype<'a> {
   pub fn get_keyed(&self, key: PropertyKeyValue) -> Option<Entity<'a>> {
     match key {
       PropertyKeyValue::String(s) => self.string_keyed.get(&s).copied(),
-      PropertyKeyValue::Symbol(_) => todo!(),
+      PropertyKeyValue::Symbol(s) => self.symbol_keyed.get(&s).copied(),
     }
   }
 
@@ -95,32 +101,98 @@ impl<'a> BuiltinPrototype<'a> {
 
 pub struct BuiltinPrototypes<'a> {
   pub array: BuiltinPrototype<'a>,
+  pub array_buffer: BuiltinPrototype<'a>,
   pub bigint: BuiltinPrototype<'a>,
   pub boolean: BuiltinPrototype<'a>,
+  pub data_view: BuiltinPrototype<'a>,
+  pub date: BuiltinPrototype<'a>,
+  pub error: BuiltinPrototype<'a>,
+  pub aggregate_error: BuiltinPrototype<'a>,
+  pub eval_error: BuiltinPrototype<'a>,
+  pub range_error: BuiltinPrototype<'a>,
+  pub reference_error: BuiltinPrototype<'a>,
+  pub syntax_error: BuiltinPrototype<'a>,
+  pub type_error: BuiltinPrototype<'a>,
+  pub uri_error: BuiltinPrototype<'a>,
   pub function: BuiltinPrototype<'a>,
+  pub intl: BuiltinPrototype<'a>,
+  pub map: BuiltinPrototype<'a>,
   pub null: BuiltinPrototype<'a>,
   pub number: BuiltinPrototype<'a>,
   pub object: BuiltinPrototype<'a>,
   pub promise: BuiltinPrototype<'a>,
   pub regexp: BuiltinPrototype<'a>,
+  pub set: BuiltinPrototype<'a>,
   pub string: BuiltinPrototype<'a>,
   pub symbol: BuiltinPrototype<'a>,
+  pub text_decoder: BuiltinPrototype<'a>,
+  pub text_encoder: BuiltinPrototype<'a>,
+  /// Indexed by `ArrayBufferViewKind`
+  pub typed_arrays: [BuiltinPrototype<'a>; 11],
+  pub weak_map: BuiltinPrototype<'a>,
+  pub weak_set: BuiltinPrototype<'a>,
 }
 
 impl<'a> Builtins<'a> {
   pub fn create_builtin_prototypes(factory: &Factory<'a>) -> &'a BuiltinPrototypes<'a> {
     factory.alloc(BuiltinPrototypes {
       array: array::create_array_prototype(factory),
+      array_buffer: array_buffer::create_array_buffer_prototype(factory),
       bigint: bigint::create_bigint_prototype(factory),
       boolean: boolean::create_boolean_prototype(factory),
+      data_view: array_buffer::create_data_view_prototype(factory),
+      date: date::create_date_prototype(factory),
+      error: error::create_error_prototype(factory, "Error"),
+      aggregate_error: error::create_error_prototype(factory, "AggregateError"),
+      eval_error: error::create_error_prototype(factory, "EvalError"),
+      range_error: error::create_error_prototype(factory, "RangeError"),
+      reference_error: error::create_error_prototype(factory, "ReferenceError"),
+      syntax_error: error::create_error_prototype(factory, "SyntaxError"),
+      type_error: error::create_error_prototype(factory, "TypeError"),
+      uri_error: error::create_error_prototype(factory, "URIError"),
       function: function::create_function_prototype(factory),
+      intl: intl::create_intl_prototype(factory),
+      map: collection::create_collection_prototype(factory, "Map"),
       null: null::create_null_prototype(factory),
       number: number::create_number_prototype(factory),
       object: object::create_object_prototype(factory),
       promise: promise::create_promise_prototype(factory),
       regexp: regexp::create_regexp_prototype(factory),
+      set: collection::create_collection_prototype(factory, "Set"),
       string: string::create_string_prototype(factory),
       symbol: symbol::create_symbol_prototype(factory),
+      text_decoder: text_codec::create_text_decoder_prototype(factory),
+      text_encoder: text_codec::create_text_encoder_prototype(factory),
+      typed_arrays: ArrayBufferViewKind::TYPED_ARRAYS
+        .map(|kind| array_buffer::create_typed_array_prototype(factory, kind)),
+      weak_map: collection::create_collection_prototype(factory, "WeakMap"),
+      weak_set: collection::create_collection_prototype(factory, "WeakSet"),
     })
   }
 }
+
+impl<'a> BuiltinPrototypes<'a> {
+  /// The `[[Prototype]]` of a builtin prototype, `None` for `Object.prototype`
+  pub fn get_parent(
+    &'a self,
+    prototype: &BuiltinPrototype<'a>,
+  ) -> Option<&'a BuiltinPrototype<'a>> {
+    if ptr::eq(prototype, &self.object) || ptr::eq(prototype, &self.null) {
+      return None;
+    }
+    let error_subclasses = [
+      &self.aggregate_error,
+      &self.eval_error,
+      &self.range_error,
+      &self.reference_error,
+      &self.syntax_error,
+      &self.type_error,
+      &self.uri_error,
+    ];
+    if error_subclasses.into_iter().any(|subclass| ptr::eq(prototype, subclass)) {
+      Some(&self.error)
+    } else {
+      Some(&self.object)
+    }
+  }
+}
@@ -1,10 +1,115 @@
-use super::{BuiltinPrototype, object::create_object_prototype};
-use crate::{analyzer::Factory, init_prototype};
+use super::{BuiltinPrototype, object::create_object_prototype, utils::call_unknown};
+use crate::{
+  analyzer::{Analyzer, Factory},
+  dep::Dep,
+  entity::Entity,
+  init_prototype,
+  value::{
+    TypeofResult,
+    promise::{PromiseSettlement, PromiseValue},
+  },
+};
 
 pub fn create_promise_prototype<'a>(factory: &Factory<'a>) -> BuiltinPrototype<'a> {
   init_prototype!("Promise", create_object_prototype(factory), {
-    "finally" => factory.unknown,
-    "then" => factory.unknown,
-    "catch" => factory.unknown,
+    "finally" => create_promise_finally_impl(factory),
+    "then" => create_promise_then_impl(factory),
+    "catch" => create_promise_catch_impl(factory),
   })
 }
+
+fn create_promise_then_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Promise::then", |analyzer, dep, this, args| {
+    let Some(promise) = as_tracked_promise(this) else {
+      return call_unknown(analyzer, dep, this, args);
+    };
+    let [on_fulfilled, on_rejected] = args.destruct_as_array(analyzer, dep, 2, false).0[..] else {
+      unreachable!()
+    };
+    let settlement = then(analyzer, dep, this, promise, on_fulfilled, on_rejected);
+    analyzer.factory.computed(analyzer.new_promise(settlement).into(), dep)
+  })
+}
+
+fn create_promise_catch_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Promise::catch", |analyzer, dep, this, args| {
+    let Some(promise) = as_tracked_promise(this) else {
+      return call_unknown(analyzer, dep, this, args);
+    };
+    let on_rejected = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+    let settlement = then(analyzer, dep, this, promise, analyzer.factory.undefined, on_rejected);
+    analyzer.factory.computed(analyzer.new_promise(settlement).into(), dep)
+  })
+}
+
+fn create_promise_finally_impl<'a>(factory: &Factory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Promise::finally", |analyzer, dep, this, args| {
+    let Some(promise) = as_tracked_promise(this) else {
+      return call_unknown(analyzer, dep, this, args);
+    };
+    let on_finally = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+    let settled = promise.settlement.computed(this.get_shallow_dep(analyzer), analyzer.factory);
+    let is_pending = settled.fulfilled.is_none() && settled.rejected.is_none();
+
+    let typeof_on_finally = on_finally.test_typeof();
+    let settlement = if is_pending || !typeof_on_finally.contains(TypeofResult::Function) {
+      settled
+    } else if typeof_on_finally == TypeofResult::Function {
+      let reaction_dep = settled.reaction_dep(dep, analyzer.factory);
+      let reaction =
+        analyzer.call_promise_reaction(reaction_dep, on_finally, analyzer.factory.empty_arguments);
+      // The original settlement is kept unless the callback throws or returns a rejected promise
+      let kept = if reaction.fulfilled.is_some() { settled } else { PromiseSettlement::pending() };
+      kept.union(PromiseSettlement { fulfilled: None, ..reaction }, analyzer.factory)
+    } else {
+      analyzer.consume(on_finally);
+      settled.consume(analyzer);
+      PromiseSettlement::unknown(analyzer.factory)
+    };
+    analyzer.factory.computed(analyzer.new_promise(settlement).into(), dep)
+  })
+}
+
+fn as_tracked_promise(this: Entity<'_>) -> Option<&PromiseValue<'_>> {
+  this.as_promise().filter(|promise| !promise.consumed.get())
+}
+
+fn then<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  this: Entity<'a>,
+  promise: &'a PromiseValue<'a>,
+  on_fulfilled: Entity<'a>,
+  on_rejected: Entity<'a>,
+) -> PromiseSettlement<'a> {
+  let settled = promise.settlement.computed(this.get_shallow_dep(analyzer), analyzer.factory);
+  let dep = settled.reaction_dep(dep, analyzer.factory);
+  let fulfilled =
+    react(analyzer, dep, on_fulfilled, settled.fulfilled, PromiseSettlement::fulfilled);
+  let rejected = react(analyzer, dep, on_rejected, settled.rejected, PromiseSettlement::rejected);
+  fulfilled.union(rejected, analyzer.factory)
+}
+
+/// Calls `handler` with `argument` if the promise may be settled in this way, and falls back to
+/// `pass_through` if `handler` is not callable
+fn react<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  handler: Entity<'a>,
+  argument: Option<Entity<'a>>,
+  pass_through: fn(Entity<'a>) -> PromiseSettlement<'a>,
+) -> PromiseSettlement<'a> {
+  let Some(argument) = argument else {
+    return PromiseSettlement::pending();
+  };
+  let typeof_handler = handler.test_typeof();
+  if typeof_handler == TypeofResult::Function {
+    let args = analyzer.factory.arguments(analyzer.factory.vec1((false, argument)));
+    analyzer.call_promise_reaction(dep, handler, args)
+  } else if !typeof_handler.contains(TypeofResult::Function) {
+    pass_through(argument)
+  } else {
+    analyzer.consume((handler, argument));
+    PromiseSettlement::unknown(analyzer.factory)
+  }
+}
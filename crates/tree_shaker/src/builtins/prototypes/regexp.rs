@@ -1,10 +1,296 @@
+use regex::Captures;
+
 use super::{BuiltinPrototype, object::create_object_prototype};
-use crate::{analyzer::Factory, init_prototype};
+use crate::{
+  analyzer::{Analyzer, Factory},
+  builtins::utils::get_string_arg,
+  entity::Entity,
+  init_object, init_prototype,
+  utils::{JsRegex, next_char_boundary, utf16_index},
+  value::{ObjectPrototype, array::ArrayValue, regexp::RegExpValue},
+};
+
+type RegExpMethod<'a> = fn(&mut Analyzer<'a>, &JsRegex, &'a str) -> Entity<'a>;
 
 pub fn create_regexp_prototype<'a>(factory: &Factory<'a>) -> BuiltinPrototype<'a> {
   init_prototype!("RegExp", create_object_prototype(factory), {
-    "exec" => factory.pure_fn_returns_unknown,
-    "test" => factory.pure_fn_returns_boolean,
-    "toString" => factory.pure_fn_returns_string,
+    "dotAll" => factory.unknown_boolean,
+    "exec" => method(factory, "RegExp::exec", factory.pure_fn_returns_unknown, regexp_exec),
+    "flags" => factory.unknown_string,
+    "global" => factory.unknown_boolean,
+    "hasIndices" => factory.unknown_boolean,
+    "ignoreCase" => factory.unknown_boolean,
+    "multiline" => factory.unknown_boolean,
+    "source" => factory.unknown_string,
+    "sticky" => factory.unknown_boolean,
+    "test" => method(factory, "RegExp::test", factory.pure_fn_returns_boolean, |a, regex, input| {
+      a.factory.boolean(regex.exec_at(input, 0).is_some())
+    }),
+    "toString" => factory.implemented_builtin_fn("RegExp::toString", |analyzer, dep, this, args| {
+      if this.as_regexp().is_some() {
+        let str = this.get_to_string(analyzer);
+        return analyzer.factory.computed(str, (dep, this, args));
+      }
+      analyzer.factory.pure_fn_returns_string.call(analyzer, dep, this, args)
+    }),
+    "unicode" => factory.unknown_boolean,
+    "unicodeSets" => factory.unknown_boolean,
+  })
+}
+
+/// Folds the method if `this` is a known regular expression which is neither global nor sticky
+/// (which would read and write `lastIndex`) and the input is a supported literal string, otherwise
+/// calls `fallback`
+fn method<'a>(
+  factory: &Factory<'a>,
+  name: &'static str,
+  fallback: Entity<'a>,
+  implementation: RegExpMethod<'a>,
+) -> Entity<'a> {
+  factory.implemented_builtin_fn(name, move |analyzer, dep, this, args| {
+    if let Some(regex) = this.as_regexp().and_then(RegExpValue::compile) {
+      if !regex.global {
+        let input = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+        if let Some(input) = get_string_arg(analyzer, input) {
+          if regex.supports_input(input) {
+            let result = implementation(analyzer, &regex, input);
+            return analyzer.factory.computed(result, (dep, this, args));
+          }
+        }
+      }
+    }
+    fallback.call(analyzer, dep, this, args)
   })
 }
+
+fn regexp_exec<'a>(analyzer: &mut Analyzer<'a>, regex: &JsRegex, input: &'a str) -> Entity<'a> {
+  match regex.exec_at(input, 0) {
+    Some(captures) => create_match_result(analyzer, regex, input, &captures),
+    None => analyzer.factory.null,
+  }
+}
+
+/// The result of `exec`, which is an array of the captures with `index`, `input` and `groups`
+fn create_match_result<'a>(
+  analyzer: &mut Analyzer<'a>,
+  regex: &JsRegex,
+  input: &'a str,
+  captures: &Captures<'a>,
+) -> Entity<'a> {
+  let array = analyzer.new_empty_array();
+  for capture in captures.iter() {
+    array.push_element(capture_to_entity(analyzer, capture.map(|m| m.as_str())));
+  }
+  let index = utf16_index(input, captures.get(0).unwrap().start());
+  array.init_named("index", analyzer.factory.number(index as f64, None));
+  array.init_named("input", analyzer.factory.string(input));
+  let groups = if regex.has_named_groups() {
+    let groups = analyzer.new_empty_object(ObjectPrototype::ImplicitOrNull, None);
+    for name in regex.capture_names().flatten() {
+      let name = analyzer.allocator.alloc_str(name);
+      let value = capture_to_entity(analyzer, captures.name(name).map(|m| m.as_str()));
+      init_object!(groups, analyzer.factory, {
+        name => value,
+      });
+    }
+    groups.into()
+  } else {
+    analyzer.factory.undefined
+  };
+  array.init_named("groups", groups);
+  array.into()
+}
+
+fn capture_to_entity<'a>(analyzer: &Analyzer<'a>, capture: Option<&'a str>) -> Entity<'a> {
+  capture.map_or(analyzer.factory.undefined, |capture| analyzer.factory.string(capture))
+}
+
+/// The regular expression used by `String.prototype.match` and `search`, which creates one from
+/// other values
+pub fn get_matcher_arg<'a>(analyzer: &mut Analyzer<'a>, arg: Entity<'a>) -> Option<JsRegex> {
+  if let Some(regexp) = arg.as_regexp() {
+    return regexp.compile();
+  }
+  if arg.test_is_undefined() == Some(true) {
+    return JsRegex::new("(?:)", "");
+  }
+  arg.get_literal(analyzer)?;
+  JsRegex::new(get_string_arg(analyzer, arg)?, "")
+}
+
+pub fn string_match<'a>(
+  analyzer: &mut Analyzer<'a>,
+  regex: &JsRegex,
+  input: &'a str,
+) -> Option<Entity<'a>> {
+  if !regex.supports_input(input) {
+    return None;
+  }
+  if !regex.global {
+    return Some(regexp_exec(analyzer, regex, input));
+  }
+  let matches = regex.exec_all(input);
+  if matches.is_empty() {
+    return Some(analyzer.factory.null);
+  }
+  let array = analyzer.new_empty_array();
+  for captures in matches {
+    array.push_element(analyzer.factory.string(captures.get(0).unwrap().as_str()));
+  }
+  Some(array.into())
+}
+
+pub fn string_search<'a>(
+  analyzer: &mut Analyzer<'a>,
+  regex: &JsRegex,
+  input: &'a str,
+) -> Option<Entity<'a>> {
+  if !regex.supports_input(input) {
+    return None;
+  }
+  let index = match regex.exec_at(input, 0) {
+    Some(captures) => utf16_index(input, captures.get(0).unwrap().start()) as f64,
+    None => -1.0,
+  };
+  Some(analyzer.factory.number(index, None))
+}
+
+/// `replaceAll` requires the regular expression to be global
+pub fn string_replace<'a>(
+  analyzer: &mut Analyzer<'a>,
+  regex: &JsRegex,
+  input: &'a str,
+  replacement: &str,
+  all: bool,
+) -> Option<Entity<'a>> {
+  if (all && !regex.global) || !regex.supports_input(input) {
+    return None;
+  }
+  let matches = if regex.global {
+    regex.exec_all(input)
+  } else {
+    regex.exec_at(input, 0).into_iter().collect()
+  };
+  let mut result = String::new();
+  let mut last_end = 0;
+  for captures in matches {
+    let whole = captures.get(0).unwrap();
+    result.push_str(&input[last_end..whole.start()]);
+    expand_replacement(&mut result, regex, replacement, input, &captures);
+    last_end = whole.end();
+  }
+  result.push_str(&input[last_end..]);
+  Some(analyzer.factory.string(analyzer.allocator.alloc_str(&result)))
+}
+
+/// The `$` patterns in the replacement string, like `GetSubstitution` in the spec
+fn expand_replacement(
+  result: &mut String,
+  regex: &JsRegex,
+  replacement: &str,
+  input: &str,
+  captures: &Captures,
+) {
+  let whole = captures.get(0).unwrap();
+  let group_count = captures.len() - 1;
+  let get_group = |index: usize| captures.get(index).map_or("", |m| m.as_str());
+  let mut rest = replacement;
+  while let Some(dollar) = rest.find('$') {
+    result.push_str(&rest[..dollar]);
+    rest = &rest[dollar + 1..];
+    let digits: Vec<usize> =
+      rest.chars().take(2).map_while(|c| c.to_digit(10)).map(|d| d as usize).collect();
+    match rest.chars().next() {
+      Some('$') => {
+        result.push('$');
+        rest = &rest[1..];
+      }
+      Some('&') => {
+        result.push_str(whole.as_str());
+        rest = &rest[1..];
+      }
+      Some('`') => {
+        result.push_str(&input[..whole.start()]);
+        rest = &rest[1..];
+      }
+      Some('\'') => {
+        result.push_str(&input[whole.end()..]);
+        rest = &rest[1..];
+      }
+      Some('<') if regex.has_named_groups() => match rest.find('>') {
+        Some(end) => {
+          result.push_str(captures.name(&rest[1..end]).map_or("", |m| m.as_str()));
+          rest = &rest[end + 1..];
+        }
+        None => result.push('$'),
+      },
+      _ if digits.len() == 2 && (1..=group_count).contains(&(digits[0] * 10 + digits[1])) => {
+        result.push_str(get_group(digits[0] * 10 + digits[1]));
+        rest = &rest[2..];
+      }
+      _ if !digits.is_empty() && (1..=group_count).contains(&digits[0]) => {
+        result.push_str(get_group(digits[0]));
+        rest = &rest[1..];
+      }
+      _ => result.push('$'),
+    }
+  }
+  result.push_str(rest);
+}
+
+/// Splits like `RegExp.prototype[Symbol.split]`, where the captures are spliced into the result
+pub fn string_split<'a>(
+  analyzer: &mut Analyzer<'a>,
+  regex: &JsRegex,
+  input: &'a str,
+  limit: usize,
+) -> Option<Entity<'a>> {
+  if !regex.supports_input(input) {
+    return None;
+  }
+  let array: &ArrayValue = analyzer.new_empty_array();
+  if limit == 0 {
+    return Some(array.into());
+  }
+  if input.is_empty() {
+    if regex.exec_at(input, 0).is_none() {
+      array.push_element(analyzer.factory.string(""));
+    }
+    return Some(array.into());
+  }
+
+  let mut count = 0;
+  let mut push = |analyzer: &Analyzer<'a>, part: Option<&'a str>| {
+    array.push_element(capture_to_entity(analyzer, part));
+    count += 1;
+    count == limit
+  };
+  let mut p = 0;
+  let mut q = 0;
+  while q < input.len() {
+    let Some(captures) = regex.exec_at(input, q) else {
+      break;
+    };
+    let whole = captures.get(0).unwrap();
+    if whole.start() >= input.len() {
+      break;
+    }
+    q = whole.start();
+    if whole.end() == p {
+      q = next_char_boundary(input, q);
+      continue;
+    }
+    if push(analyzer, Some(&input[p..q])) {
+      return Some(array.into());
+    }
+    for capture in captures.iter().skip(1) {
+      if push(analyzer, capture.map(|m| m.as_str())) {
+        return Some(array.into());
+      }
+    }
+    p = whole.end();
+    q = p;
+  }
+  push(analyzer, Some(&input[p..]));
+  Some(array.into())
+}
@@ -1,55 +1,502 @@
-use super::{BuiltinPrototype, object::create_object_prototype};
-use crate::{analyzer::Factory, init_prototype};
+use super::{
+  BuiltinPrototype,
+  object::create_object_prototype,
+  regexp,
+  utils::{resolve_relative_index, to_integer_or_infinity},
+};
+use crate::{
+  analyzer::{Analyzer, Factory},
+  builtins::utils::{get_string_arg, is_js_whitespace},
+  entity::Entity,
+  init_prototype,
+  value::{LiteralValue, symbol::well_known_symbol_id},
+};
+
+/// Strings generated by `repeat`, `padStart` and `padEnd` longer than this are not folded
+const MAX_GENERATED_STRING_LENGTH: usize = 1024;
+
+type StringMethod<'a> = fn(&mut Analyzer<'a>, &'a str, &[Entity<'a>]) -> Option<Entity<'a>>;
 
 pub fn create_string_prototype<'a>(factory: &Factory<'a>) -> BuiltinPrototype<'a> {
+  let string = factory.pure_fn_returns_string;
+  let number = factory.pure_fn_returns_number;
+  let boolean = factory.pure_fn_returns_boolean;
+  let unknown = factory.pure_fn_returns_unknown;
+  let method = |name, fallback, implementation: StringMethod<'a>| {
+    create_string_method_impl(factory, name, fallback, implementation)
+  };
+  let html = |name, tag, attribute| create_string_html_method_impl(factory, name, tag, attribute);
+
   init_prototype!("String", create_object_prototype(factory), {
-    "anchor" => factory.pure_fn_returns_string,
-    "at" => factory.pure_fn_returns_unknown,
-    "big" => factory.pure_fn_returns_string,
-    "blink" => factory.pure_fn_returns_string,
-    "bold" => factory.pure_fn_returns_string,
-    "charAt" => factory.pure_fn_returns_string,
-    "charCodeAt" => factory.pure_fn_returns_unknown,
-    "codePointAt" => factory.pure_fn_returns_unknown,
-    "concat" => factory.pure_fn_returns_string,
-    "endsWith" => factory.pure_fn_returns_unknown,
-    "fixed" => factory.pure_fn_returns_string,
-    "fontcolor" => factory.pure_fn_returns_string,
-    "fontsize" => factory.pure_fn_returns_string,
-    "includes" => factory.pure_fn_returns_unknown,
-    "indexOf" => factory.pure_fn_returns_unknown,
-    "italics" => factory.pure_fn_returns_string,
-    "lastIndexOf" => factory.pure_fn_returns_unknown,
-    "link" => factory.pure_fn_returns_string,
-    "localeCompare" => factory.pure_fn_returns_unknown,
-    "match" => factory.pure_fn_returns_unknown,
-    "matchAll" => factory.pure_fn_returns_unknown,
-    "normalize" => factory.pure_fn_returns_string,
-    "padEnd" => factory.pure_fn_returns_string,
-    "padStart" => factory.pure_fn_returns_string,
-    "repeat" => factory.pure_fn_returns_string,
-    "replace" => factory.pure_fn_returns_unknown,
-    "replaceAll" => factory.pure_fn_returns_unknown,
-    "search" => factory.pure_fn_returns_unknown,
-    "slice" => factory.pure_fn_returns_string,
-    "small" => factory.pure_fn_returns_string,
-    "split" => factory.pure_fn_returns_unknown,
-    "startsWith" => factory.pure_fn_returns_unknown,
-    "strike" => factory.pure_fn_returns_string,
-    "sub" => factory.pure_fn_returns_string,
-    "substr" => factory.pure_fn_returns_string,
-    "substring" => factory.pure_fn_returns_string,
-    "sup" => factory.pure_fn_returns_string,
-    "toLocaleLowerCase" => factory.pure_fn_returns_string,
-    "toLocaleUpperCase" => factory.pure_fn_returns_string,
-    "toLowerCase" => factory.pure_fn_returns_string,
-    "toString" => factory.pure_fn_returns_string,
-    "toUpperCase" => factory.pure_fn_returns_string,
-    "trim" => factory.pure_fn_returns_string,
-    "trimEnd" => factory.pure_fn_returns_string,
-    "trimLeft" => factory.pure_fn_returns_string,
-    "trimRight" => factory.pure_fn_returns_string,
-    "trimStart" => factory.pure_fn_returns_string,
-    "valueOf" => factory.pure_fn_returns_string,
+    "anchor" => html("String::anchor", "a", Some("name")),
+    "at" => method("String::at", unknown, string_at),
+    "big" => html("String::big", "big", None),
+    "blink" => html("String::blink", "blink", None),
+    "bold" => html("String::bold", "b", None),
+    "charAt" => method("String::charAt", string, string_char_at),
+    "charCodeAt" => method("String::charCodeAt", number, string_char_code_at),
+    "codePointAt" => method("String::codePointAt", unknown, string_code_point_at),
+    "concat" => method("String::concat", string, string_concat),
+    "endsWith" => method("String::endsWith", boolean, string_ends_with),
+    "fixed" => html("String::fixed", "tt", None),
+    "fontcolor" => html("String::fontcolor", "font", Some("color")),
+    "fontsize" => html("String::fontsize", "font", Some("size")),
+    "includes" => method("String::includes", boolean, string_includes),
+    "indexOf" => method("String::indexOf", number, string_index_of),
+    "italics" => html("String::italics", "i", None),
+    "lastIndexOf" => method("String::lastIndexOf", number, string_last_index_of),
+    "link" => html("String::link", "a", Some("href")),
+    "localeCompare" => number,
+    "match" => method("String::match", unknown, |a, v, args| {
+      let regex = regexp::get_matcher_arg(a, get_arg(a, args, 0))?;
+      regexp::string_match(a, &regex, v)
+    }),
+    "matchAll" => unknown,
+    "normalize" => string,
+    "padEnd" => method("String::padEnd", string, |a, v, args| string_pad(a, v, args, false)),
+    "padStart" => method("String::padStart", string, |a, v, args| string_pad(a, v, args, true)),
+    "repeat" => method("String::repeat", string, string_repeat),
+    "replace" => method("String::replace", string, |a, v, args| string_replace(a, v, args, false)),
+    "replaceAll" => method("String::replaceAll", string, |a, v, args| {
+      string_replace(a, v, args, true)
+    }),
+    "search" => method("String::search", number, |a, v, args| {
+      let regex = regexp::get_matcher_arg(a, get_arg(a, args, 0))?;
+      regexp::string_search(a, &regex, v)
+    }),
+    "slice" => method("String::slice", string, string_slice),
+    "small" => html("String::small", "small", None),
+    "split" => method("String::split", unknown, string_split),
+    "startsWith" => method("String::startsWith", boolean, string_starts_with),
+    "strike" => html("String::strike", "strike", None),
+    "sub" => html("String::sub", "sub", None),
+    "substr" => method("String::substr", string, string_substr),
+    "substring" => method("String::substring", string, string_substring),
+    "sup" => html("String::sup", "sup", None),
+    "toLocaleLowerCase" => string,
+    "toLocaleUpperCase" => string,
+    "toLowerCase" => method("String::toLowerCase", string, |a, v, _| {
+      Some(to_entity(a, &v.to_lowercase()))
+    }),
+    "toString" => method("String::toString", string, |a, v, _| Some(a.factory.string(v))),
+    "toUpperCase" => method("String::toUpperCase", string, |a, v, _| {
+      Some(to_entity(a, &v.to_uppercase()))
+    }),
+    "trim" => method("String::trim", string, |a, v, _| string_trim(a, v, true, true)),
+    "trimEnd" => method("String::trimEnd", string, |a, v, _| string_trim(a, v, false, true)),
+    "trimLeft" => method("String::trimLeft", string, |a, v, _| string_trim(a, v, true, false)),
+    "trimRight" => method("String::trimRight", string, |a, v, _| string_trim(a, v, false, true)),
+    "trimStart" => method("String::trimStart", string, |a, v, _| string_trim(a, v, true, false)),
+    "valueOf" => method("String::valueOf", string, |a, v, _| Some(a.factory.string(v))),
+    well_known_symbol_id("iterator") => unknown,
+  })
+}
+
+/// Folds the method if the receiver is a literal string and `implementation` can handle the
+/// arguments, otherwise calls `fallback`
+fn create_string_method_impl<'a>(
+  factory: &Factory<'a>,
+  name: &'static str,
+  fallback: Entity<'a>,
+  implementation: StringMethod<'a>,
+) -> Entity<'a> {
+  factory.implemented_builtin_fn(name, move |analyzer, dep, this, args| {
+    if let Some(LiteralValue::String(value, atom)) = this.get_literal(analyzer) {
+      let (args_arr, args_rest, _) = args.iterate(analyzer, dep);
+      if args_rest.is_none() {
+        if let Some(result) = implementation(analyzer, value, &args_arr) {
+          // The content of the string is observed, so it can't be mangled
+          analyzer.consume(atom);
+          return analyzer.factory.computed(result, (dep, this, args));
+        }
+      }
+    }
+    fallback.call(analyzer, dep, this, args)
+  })
+}
+
+fn create_string_html_method_impl<'a>(
+  factory: &Factory<'a>,
+  name: &'static str,
+  tag: &'static str,
+  attribute: Option<&'static str>,
+) -> Entity<'a> {
+  factory.implemented_builtin_fn(name, move |analyzer, dep, this, args| {
+    let html = (|| {
+      let LiteralValue::String(value, atom) = this.get_literal(analyzer)? else {
+        return None;
+      };
+      analyzer.consume(atom);
+      let attribute = match attribute {
+        Some(attribute) => {
+          let arg = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+          let arg = get_string_arg(analyzer, arg)?.replace('"', "&quot;");
+          format!(" {attribute}=\"{arg}\"")
+        }
+        None => String::new(),
+      };
+      Some(format!("<{tag}{attribute}>{value}</{tag}>"))
+    })();
+
+    match html {
+      Some(html) => analyzer.factory.computed(to_entity(analyzer, &html), (dep, this, args)),
+      None => analyzer.factory.pure_fn_returns_string.call(analyzer, dep, this, args),
+    }
+  })
+}
+
+fn string_at<'a>(
+  analyzer: &mut Analyzer<'a>,
+  value: &'a str,
+  args: &[Entity<'a>],
+) -> Option<Entity<'a>> {
+  let units = to_utf16(value);
+  let index = to_integer_or_infinity(analyzer, get_arg(analyzer, args, 0))?;
+  let index = if index < 0.0 { units.len() as f64 + index } else { index };
+  if index < 0.0 || index >= units.len() as f64 {
+    return Some(analyzer.factory.undefined);
+  }
+  utf16_to_entity(analyzer, &units[index as usize..index as usize + 1])
+}
+
+fn string_char_at<'a>(
+  analyzer: &mut Analyzer<'a>,
+  value: &'a str,
+  args: &[Entity<'a>],
+) -> Option<Entity<'a>> {
+  let units = to_utf16(value);
+  let index = to_integer_or_infinity(analyzer, get_arg(analyzer, args, 0))?;
+  if index < 0.0 || index >= units.len() as f64 {
+    return Some(analyzer.factory.string(""));
+  }
+  utf16_to_entity(analyzer, &units[index as usize..index as usize + 1])
+}
+
+fn string_char_code_at<'a>(
+  analyzer: &mut Analyzer<'a>,
+  value: &'a str,
+  args: &[Entity<'a>],
+) -> Option<Entity<'a>> {
+  let units = to_utf16(value);
+  let index = to_integer_or_infinity(analyzer, get_arg(analyzer, args, 0))?;
+  Some(if index < 0.0 || index >= units.len() as f64 {
+    analyzer.factory.nan
+  } else {
+    analyzer.factory.number(units[index as usize] as f64, None)
   })
 }
+
+fn string_code_point_at<'a>(
+  analyzer: &mut Analyzer<'a>,
+  value: &'a str,
+  args: &[Entity<'a>],
+) -> Option<Entity<'a>> {
+  let units = to_utf16(value);
+  let index = to_integer_or_infinity(analyzer, get_arg(analyzer, args, 0))?;
+  Some(if index < 0.0 || index >= units.len() as f64 {
+    analyzer.factory.undefined
+  } else {
+    let code_point = match char::decode_utf16(units[index as usize..].iter().copied()).next()? {
+      Ok(c) => c as u32,
+      Err(err) => err.unpaired_surrogate() as u32,
+    };
+    analyzer.factory.number(code_point as f64, None)
+  })
+}
+
+fn string_concat<'a>(
+  analyzer: &mut Analyzer<'a>,
+  value: &'a str,
+  args: &[Entity<'a>],
+) -> Option<Entity<'a>> {
+  let mut result = value.to_string();
+  for arg in args {
+    result.push_str(get_string_arg(analyzer, *arg)?);
+  }
+  Some(to_entity(analyzer, &result))
+}
+
+fn string_starts_with<'a>(
+  analyzer: &mut Analyzer<'a>,
+  value: &'a str,
+  args: &[Entity<'a>],
+) -> Option<Entity<'a>> {
+  let units = to_utf16(value);
+  let search = to_utf16(get_search_string_arg(analyzer, get_arg(analyzer, args, 0))?);
+  let start = resolve_position(analyzer, get_arg(analyzer, args, 1), units.len(), 0)?;
+  Some(analyzer.factory.boolean(units[start..].starts_with(&search)))
+}
+
+fn string_ends_with<'a>(
+  analyzer: &mut Analyzer<'a>,
+  value: &'a str,
+  args: &[Entity<'a>],
+) -> Option<Entity<'a>> {
+  let units = to_utf16(value);
+  let search = to_utf16(get_search_string_arg(analyzer, get_arg(analyzer, args, 0))?);
+  let end = resolve_position(analyzer, get_arg(analyzer, args, 1), units.len(), units.len())?;
+  Some(analyzer.factory.boolean(units[..end].ends_with(&search)))
+}
+
+fn string_includes<'a>(
+  analyzer: &mut Analyzer<'a>,
+  value: &'a str,
+  args: &[Entity<'a>],
+) -> Option<Entity<'a>> {
+  let units = to_utf16(value);
+  let search = to_utf16(get_search_string_arg(analyzer, get_arg(analyzer, args, 0))?);
+  let start = resolve_position(analyzer, get_arg(analyzer, args, 1), units.len(), 0)?;
+  Some(analyzer.factory.boolean(find_utf16(&units, &search, start).is_some()))
+}
+
+fn string_index_of<'a>(
+  analyzer: &mut Analyzer<'a>,
+  value: &'a str,
+  args: &[Entity<'a>],
+) -> Option<Entity<'a>> {
+  let units = to_utf16(value);
+  let search = to_utf16(get_string_arg(analyzer, get_arg(analyzer, args, 0))?);
+  let start = resolve_position(analyzer, get_arg(analyzer, args, 1), units.len(), 0)?;
+  let index = find_utf16(&units, &search, start).map_or(-1.0, |index| index as f64);
+  Some(analyzer.factory.number(index, None))
+}
+
+fn string_last_index_of<'a>(
+  analyzer: &mut Analyzer<'a>,
+  value: &'a str,
+  args: &[Entity<'a>],
+) -> Option<Entity<'a>> {
+  let units = to_utf16(value);
+  let search = to_utf16(get_string_arg(analyzer, get_arg(analyzer, args, 0))?);
+  // `NaN` means searching from the end
+  let position = get_arg(analyzer, args, 1).get_to_numeric(analyzer).get_literal(analyzer);
+  let start = match position {
+    Some(LiteralValue::NaN) => units.len(),
+    _ => resolve_position(analyzer, get_arg(analyzer, args, 1), units.len(), units.len())?,
+  };
+  let index = units
+    .len()
+    .checked_sub(search.len())
+    .and_then(|last| (0..=start.min(last)).rev().find(|index| units[*index..].starts_with(&search)))
+    .map_or(-1.0, |index| index as f64);
+  Some(analyzer.factory.number(index, None))
+}
+
+fn string_pad<'a>(
+  analyzer: &mut Analyzer<'a>,
+  value: &'a str,
+  args: &[Entity<'a>],
+  at_start: bool,
+) -> Option<Entity<'a>> {
+  let units = to_utf16(value);
+  let max_length = to_integer_or_infinity(analyzer, get_arg(analyzer, args, 0))?;
+  if max_length <= units.len() as f64 {
+    return Some(analyzer.factory.string(value));
+  }
+  if max_length > MAX_GENERATED_STRING_LENGTH as f64 {
+    return None;
+  }
+  let filler = get_arg(analyzer, args, 1);
+  let filler = if filler.test_is_undefined() == Some(true) {
+    to_utf16(" ")
+  } else {
+    to_utf16(get_string_arg(analyzer, filler)?)
+  };
+  if filler.is_empty() {
+    return Some(analyzer.factory.string(value));
+  }
+
+  let padding =
+    filler.iter().copied().cycle().take(max_length as usize - units.len()).collect::<Vec<_>>();
+  let result = if at_start { [padding, units].concat() } else { [units, padding].concat() };
+  utf16_to_entity(analyzer, &result)
+}
+
+fn string_repeat<'a>(
+  analyzer: &mut Analyzer<'a>,
+  value: &'a str,
+  args: &[Entity<'a>],
+) -> Option<Entity<'a>> {
+  let count = to_integer_or_infinity(analyzer, get_arg(analyzer, args, 0))?;
+  // RangeError: Invalid count value
+  if count < 0.0 || count.is_infinite() {
+    return None;
+  }
+  if value.len() as f64 * count > MAX_GENERATED_STRING_LENGTH as f64 {
+    return None;
+  }
+  Some(to_entity(analyzer, &value.repeat(count as usize)))
+}
+
+fn string_replace<'a>(
+  analyzer: &mut Analyzer<'a>,
+  value: &'a str,
+  args: &[Entity<'a>],
+  all: bool,
+) -> Option<Entity<'a>> {
+  // Replacer functions are not handled here
+  let replacement = get_search_string_arg(analyzer, get_arg(analyzer, args, 1))?;
+  if let Some(regexp) = get_arg(analyzer, args, 0).as_regexp() {
+    return regexp::string_replace(analyzer, &regexp.compile()?, value, replacement, all);
+  }
+  let pattern = get_search_string_arg(analyzer, get_arg(analyzer, args, 0))?;
+  if replacement.contains('$') {
+    return None;
+  }
+  let result = if all {
+    if pattern.is_empty() {
+      // An empty pattern matches between every code unit
+      let units = to_utf16(value);
+      let replacement = to_utf16(replacement);
+      let mut result = replacement.clone();
+      for unit in units {
+        result.push(unit);
+        result.extend(&replacement);
+      }
+      return utf16_to_entity(analyzer, &result);
+    }
+    value.replace(pattern, replacement)
+  } else {
+    value.replacen(pattern, replacement, 1)
+  };
+  Some(to_entity(analyzer, &result))
+}
+
+fn string_slice<'a>(
+  analyzer: &mut Analyzer<'a>,
+  value: &'a str,
+  args: &[Entity<'a>],
+) -> Option<Entity<'a>> {
+  let units = to_utf16(value);
+  let start = resolve_relative_index(analyzer, get_arg(analyzer, args, 0), units.len(), 0)?;
+  let end = resolve_relative_index(analyzer, get_arg(analyzer, args, 1), units.len(), units.len())?;
+  utf16_to_entity(analyzer, if start < end { &units[start..end] } else { &[] })
+}
+
+fn string_substring<'a>(
+  analyzer: &mut Analyzer<'a>,
+  value: &'a str,
+  args: &[Entity<'a>],
+) -> Option<Entity<'a>> {
+  let units = to_utf16(value);
+  let start = resolve_position(analyzer, get_arg(analyzer, args, 0), units.len(), 0)?;
+  let end = resolve_position(analyzer, get_arg(analyzer, args, 1), units.len(), units.len())?;
+  utf16_to_entity(analyzer, &units[start.min(end)..start.max(end)])
+}
+
+fn string_substr<'a>(
+  analyzer: &mut Analyzer<'a>,
+  value: &'a str,
+  args: &[Entity<'a>],
+) -> Option<Entity<'a>> {
+  let units = to_utf16(value);
+  let start = resolve_relative_index(analyzer, get_arg(analyzer, args, 0), units.len(), 0)?;
+  let length = get_arg(analyzer, args, 1);
+  let end = if length.test_is_undefined() == Some(true) {
+    units.len()
+  } else {
+    let length = to_integer_or_infinity(analyzer, length)?;
+    (start as f64 + length.max(0.0)).min(units.len() as f64) as usize
+  };
+  utf16_to_entity(analyzer, if start < end { &units[start..end] } else { &[] })
+}
+
+fn string_split<'a>(
+  analyzer: &mut Analyzer<'a>,
+  value: &'a str,
+  args: &[Entity<'a>],
+) -> Option<Entity<'a>> {
+  let separator = get_arg(analyzer, args, 0);
+  let limit = get_arg(analyzer, args, 1);
+  let limit = if limit.test_is_undefined() == Some(true) {
+    u32::MAX as usize
+  } else {
+    match limit.get_to_numeric(analyzer).get_literal(analyzer)? {
+      LiteralValue::Number(limit, _) => limit.0 as i64 as u32 as usize,
+      LiteralValue::Infinity(_) | LiteralValue::NaN => 0,
+      _ => return None,
+    }
+  };
+
+  if let Some(regexp) = separator.as_regexp() {
+    return regexp::string_split(analyzer, &regexp.compile()?, value, limit);
+  }
+  let parts: Vec<Vec<u16>> = if separator.test_is_undefined() == Some(true) {
+    vec![to_utf16(value)]
+  } else {
+    let separator = to_utf16(get_search_string_arg(analyzer, separator)?);
+    let units = to_utf16(value);
+    if separator.is_empty() {
+      units.iter().map(|unit| vec![*unit]).collect()
+    } else {
+      let mut parts = vec![];
+      let mut start = 0;
+      while let Some(index) = find_utf16(&units, &separator, start) {
+        parts.push(units[start..index].to_vec());
+        start = index + separator.len();
+      }
+      parts.push(units[start..].to_vec());
+      parts
+    }
+  };
+
+  let array = analyzer.new_empty_array();
+  for part in parts.iter().take(limit) {
+    array.push_element(utf16_to_entity(analyzer, part)?);
+  }
+  Some(array.into())
+}
+
+fn string_trim<'a>(
+  analyzer: &mut Analyzer<'a>,
+  value: &'a str,
+  start: bool,
+  end: bool,
+) -> Option<Entity<'a>> {
+  let value = if start { value.trim_start_matches(is_js_whitespace) } else { value };
+  let value = if end { value.trim_end_matches(is_js_whitespace) } else { value };
+  Some(analyzer.factory.string(value))
+}
+
+fn get_arg<'a>(analyzer: &Analyzer<'a>, args: &[Entity<'a>], index: usize) -> Entity<'a> {
+  args.get(index).copied().unwrap_or(analyzer.factory.undefined)
+}
+
+/// Like `get_string_arg`, but objects may be regular expressions with special behaviors
+fn get_search_string_arg<'a>(analyzer: &mut Analyzer<'a>, arg: Entity<'a>) -> Option<&'a str> {
+  arg.get_literal(analyzer)?;
+  get_string_arg(analyzer, arg)
+}
+
+/// Clamps a position argument to `0..=length`
+fn resolve_position<'a>(
+  analyzer: &Analyzer<'a>,
+  arg: Entity<'a>,
+  length: usize,
+  default: usize,
+) -> Option<usize> {
+  if arg.test_is_undefined() == Some(true) {
+    return Some(default);
+  }
+  Some(to_integer_or_infinity(analyzer, arg)?.clamp(0.0, length as f64) as usize)
+}
+
+fn find_utf16(units: &[u16], search: &[u16], start: usize) -> Option<usize> {
+  if search.is_empty() {
+    return Some(start.min(units.len()));
+  }
+  (start..=units.len().checked_sub(search.len())?).find(|index| units[*index..].starts_with(search))
+}
+
+fn to_utf16(value: &str) -> Vec<u16> {
+  value.encode_utf16().collect()
+}
+
+/// `None` if it contains lone surrogates, which can't be represented as a Rust string
+fn utf16_to_entity<'a>(analyzer: &Analyzer<'a>, units: &[u16]) -> Option<Entity<'a>> {
+  Some(to_entity(analyzer, &String::from_utf16(units).ok()?))
+}
+
+fn to_entity<'a>(analyzer: &Analyzer<'a>, value: &str) -> Entity<'a> {
+  analyzer.factory.string(analyzer.allocator.alloc_str(value))
+}
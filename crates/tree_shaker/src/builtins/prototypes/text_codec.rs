@@ -0,0 +1,46 @@
+use super::{BuiltinPrototype, object::create_object_prototype};
+use crate::{
+  analyzer::{Analyzer, Factory},
+  builtins::utils::get_string_arg,
+  dep::Dep,
+  entity::Entity,
+  init_prototype,
+  value::array_buffer::ArrayBufferViewKind,
+};
+
+pub fn create_text_encoder_prototype<'a>(factory: &Factory<'a>) -> BuiltinPrototype<'a> {
+  init_prototype!("TextEncoder", create_object_prototype(factory), {
+    "encode" => factory.implemented_builtin_fn("TextEncoder::encode", text_encoder_encode),
+    "encodeInto" => factory.pure_fn_returns_unknown,
+    "encoding" => factory.string("utf-8"),
+  })
+}
+
+/// The options of a decoder are not tracked
+pub fn create_text_decoder_prototype<'a>(factory: &Factory<'a>) -> BuiltinPrototype<'a> {
+  init_prototype!("TextDecoder", create_object_prototype(factory), {
+    "decode" => factory.pure_fn_returns_string,
+    "encoding" => factory.unknown_string,
+    "fatal" => factory.unknown_boolean,
+    "ignoreBOM" => factory.unknown_boolean,
+  })
+}
+
+/// Returns a new `Uint8Array`, whose length is known if the input is a literal string
+fn text_encoder_encode<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  this: Entity<'a>,
+  args: Entity<'a>,
+) -> Entity<'a> {
+  let input = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+  let byte_length = if input.test_is_undefined() == Some(true) {
+    Some(0)
+  } else {
+    get_string_arg(analyzer, input).map(str::len)
+  };
+  let buffer = analyzer.new_array_buffer(byte_length);
+  let view =
+    analyzer.new_array_buffer_view(ArrayBufferViewKind::Uint8, buffer.into(), Some(0), byte_length);
+  analyzer.factory.computed(view, (dep, this, input))
+}
@@ -1,3 +1,5 @@
+use crate::{analyzer::Analyzer, dep::Dep, entity::Entity, value::LiteralValue};
+
 #[macro_export]
 macro_rules! insert_prototype_property {
   ($p:expr, $k:literal, $v:expr) => {
@@ -18,3 +20,40 @@ macro_rules! init_prototype {
     }
   };
 }
+
+pub fn to_integer_or_infinity<'a>(analyzer: &Analyzer<'a>, value: Entity<'a>) -> Option<f64> {
+  match value.get_to_numeric(analyzer).get_literal(analyzer)? {
+    LiteralValue::Number(value, _) => Some(value.0.trunc()),
+    LiteralValue::Infinity(positive) => {
+      Some(if positive { f64::INFINITY } else { f64::NEG_INFINITY })
+    }
+    LiteralValue::NaN => Some(0.0),
+    _ => None,
+  }
+}
+
+/// Resolves a relative index argument like `slice` does, `None` if it is unknown
+pub fn resolve_relative_index<'a>(
+  analyzer: &Analyzer<'a>,
+  value: Entity<'a>,
+  length: usize,
+  default: usize,
+) -> Option<usize> {
+  if value.test_is_undefined() == Some(true) {
+    return Some(default);
+  }
+  let value = to_integer_or_infinity(analyzer, value)?;
+  let length = length as f64;
+  Some((if value < 0.0 { (length + value).max(0.0) } else { value.min(length) }) as usize)
+}
+
+/// Used when `this` is not the expected kind of value
+pub fn call_unknown<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Dep<'a>,
+  this: Entity<'a>,
+  args: Entity<'a>,
+) -> Entity<'a> {
+  analyzer.consume((dep, this, args));
+  analyzer.factory.unknown
+}
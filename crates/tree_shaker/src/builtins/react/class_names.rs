@@ -1,5 +1,8 @@
 use crate::{
-  analyzer::Factory, builtins::prototypes::BuiltinPrototypes, entity::Entity, value::TypeofResult,
+  analyzer::Factory,
+  builtins::prototypes::BuiltinPrototypes,
+  entity::Entity,
+  value::{PropertyKeyValue, TypeofResult},
 };
 
 pub fn create_class_names_namespace<'a>(
@@ -15,7 +18,10 @@ pub fn create_class_names_namespace<'a>(
       if TypeofResult::Object.contains(class_name.test_typeof()) {
         // This may be an array. However, this makes no difference in this logic.
         let enumerated = class_name.enumerate_properties(analyzer, dep);
-        for (_, key, value) in enumerated.known.into_values() {
+        for (key_value, (_, key, value)) in enumerated.known {
+          if let PropertyKeyValue::Symbol(_) = key_value {
+            continue;
+          }
           if value.test_truthy() != Some(false) {
             deps_1.push(key);
             deps_1.push(value);
@@ -0,0 +1,35 @@
+use super::{Builtins, prototypes::BuiltinPrototypes};
+use crate::{analyzer::Factory, entity::Entity, value::LiteralValue};
+
+impl<'a> Builtins<'a> {
+  pub fn create_require(
+    factory: &'a Factory<'a>,
+    _prototypes: &'a BuiltinPrototypes<'a>,
+  ) -> Entity<'a> {
+    factory.implemented_builtin_fn("require", |analyzer, dep, _this, args| {
+      let specifier = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+      let specifier = specifier.get_to_string(analyzer);
+
+      if let Some(LiteralValue::String(name, _m)) = specifier.get_literal(analyzer) {
+        if let Some(known) = analyzer.builtins.get_known_module(name) {
+          analyzer.consume((dep, specifier));
+          return known.default;
+        }
+        if let Some(module_id) = analyzer.resolve_and_import_module(name) {
+          let exports = analyzer.get_module_exports_object(module_id);
+          return if analyzer.modules.modules[module_id].side_effects {
+            // Loading the module is a side effect
+            analyzer.consume((dep, specifier));
+            exports
+          } else {
+            analyzer.factory.computed(exports, (dep, specifier))
+          };
+        }
+      }
+
+      analyzer.consume((dep, specifier));
+      analyzer.refer_to_global();
+      analyzer.factory.unknown
+    })
+  }
+}
@@ -1,3 +1,5 @@
+use crate::{analyzer::Analyzer, entity::Entity, value::LiteralValue};
+
 #[macro_export]
 macro_rules! init_namespace {
   ($ns:expr, $factory:expr, { $($k:expr => $v:expr,)* }) => {
@@ -50,3 +52,19 @@ macro_rules! init_map {
     }
   };
 }
+
+/// `WhiteSpace` and `LineTerminator` in the spec
+pub fn is_js_whitespace(c: char) -> bool {
+  c == '\u{FEFF}' || (c.is_whitespace() && c != '\u{85}')
+}
+
+/// Converts the argument to a literal string, which can't be mangled since its content is observed
+pub fn get_string_arg<'a>(analyzer: &mut Analyzer<'a>, arg: Entity<'a>) -> Option<&'a str> {
+  match arg.get_to_string(analyzer).get_literal(analyzer)? {
+    LiteralValue::String(value, atom) => {
+      analyzer.consume(atom);
+      Some(value)
+    }
+    _ => None,
+  }
+}
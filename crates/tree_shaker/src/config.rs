@@ -1,3 +1,5 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
@@ -12,10 +14,20 @@ impl TreeShakeJsxPreset {
   }
 }
 
+/// The environment the code runs in, which decides the host globals like `window` and `process`
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeShakeTargetEnv {
+  Unknown,
+  Browser,
+  Node,
+  Worker,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct TreeShakeConfig {
   pub enabled: bool,
   pub jsx: TreeShakeJsxPreset,
+  pub target_env: TreeShakeTargetEnv,
 
   pub max_recursion_depth: usize,
   pub remember_exhausted_variables: bool,
@@ -33,6 +45,11 @@ pub struct TreeShakeConfig {
   pub min_simple_number_value: i64,
   pub max_simple_number_value: i64,
   pub max_simple_string_length: usize,
+
+  /// Compile-time constants like `process.env.NODE_ENV`, keyed by dotted global paths
+  pub defines: BTreeMap<String, serde_json::Value>,
+  /// The `console` methods whose calls can be removed, like `debug`
+  pub pure_console_methods: BTreeSet<String>,
 }
 
 impl Default for TreeShakeConfig {
@@ -46,6 +63,7 @@ impl TreeShakeConfig {
     Self {
       enabled: true,
       jsx: TreeShakeJsxPreset::None,
+      target_env: TreeShakeTargetEnv::Unknown,
 
       max_recursion_depth: 2,
       remember_exhausted_variables: true,
@@ -63,6 +81,9 @@ impl TreeShakeConfig {
       min_simple_number_value: -999,
       max_simple_number_value: 999,
       max_simple_string_length: 12,
+
+      defines: BTreeMap::new(),
+      pure_console_methods: BTreeSet::new(),
     }
   }
 
@@ -101,6 +122,21 @@ impl TreeShakeConfig {
     self
   }
 
+  pub fn with_target_env(mut self, env: TreeShakeTargetEnv) -> Self {
+    self.target_env = env;
+    self
+  }
+
+  pub fn with_define(mut self, path: impl Into<String>, value: serde_json::Value) -> Self {
+    self.defines.insert(path.into(), value);
+    self
+  }
+
+  pub fn with_pure_console_method(mut self, method: impl Into<String>) -> Self {
+    self.pure_console_methods.insert(method.into());
+    self
+  }
+
   pub fn with_always_inline_literal(mut self, yes: bool) -> Self {
     if yes {
       self.min_simple_number_value = i64::MIN;
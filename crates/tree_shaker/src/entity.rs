@@ -5,8 +5,9 @@ use crate::{
   analyzer::Factory,
   dep::{CustomDepTrait, Dep, DepTrait},
   value::{
-    EnumeratedProperties, IteratedElements, LiteralValue, ObjectPrototype, TypeofResult, UnionHint,
-    Value, ValueTrait,
+    EnumeratedProperties, IteratedElements, LiteralValue, ObjectPrototype, ObjectValue,
+    TypeofResult, UnionHint, Value, ValueTrait, array::ArrayValue, array_buffer::ArrayBufferValue,
+    promise::PromiseValue, regexp::RegExpValue,
   },
 };
 
@@ -116,6 +117,13 @@ impl<'a> Entity<'a> {
   ) -> IteratedElements<'a> {
     self.value.iterate(analyzer, self.forward_dep(dep, analyzer))
   }
+  pub fn iterate_partially(
+    &self,
+    analyzer: &mut Analyzer<'a>,
+    dep: impl DepTrait<'a> + 'a,
+  ) -> IteratedElements<'a> {
+    self.value.iterate_partially(analyzer, self.forward_dep(dep, analyzer))
+  }
   pub fn get_shallow_dep(&self, analyzer: &Analyzer<'a>) -> Dep<'a> {
     if let Some(dep) = self.dep {
       analyzer.dep((dep, self.value.get_shallow_dep(analyzer)))
@@ -145,8 +153,12 @@ impl<'a> Entity<'a> {
     self.value.get_literal(analyzer)
   }
   /// Returns vec![(definite, key)]
-  pub fn get_own_keys(&self, analyzer: &Analyzer<'a>) -> Option<Vec<(bool, Entity<'a>)>> {
-    self.value.get_own_keys(analyzer)
+  pub fn get_own_keys(
+    &self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+  ) -> Option<Vec<(bool, Entity<'a>)>> {
+    self.value.get_own_keys(analyzer, dep)
   }
   pub fn get_constructor_prototype(
     &self,
@@ -164,6 +176,39 @@ impl<'a> Entity<'a> {
   pub fn test_nullish(&self) -> Option<bool> {
     self.value.test_nullish()
   }
+  pub fn test_is_array(&self) -> Option<bool> {
+    self.value.test_is_array()
+  }
+  pub fn test_instanceof(
+    &self,
+    analyzer: &Analyzer<'a>,
+    prototype: ObjectPrototype<'a>,
+  ) -> Option<bool> {
+    self.value.test_instanceof(analyzer, prototype)
+  }
+  pub fn get_instance_prototype(&self) -> Option<ObjectPrototype<'a>> {
+    self.value.get_instance_prototype()
+  }
+  /// Note that the dep of this entity is not included, use `get_shallow_dep` for it
+  pub fn as_array(&self) -> Option<&'a ArrayValue<'a>> {
+    self.value.as_array()
+  }
+  /// Note that the dep of this entity is not included, use `get_shallow_dep` for it
+  pub fn as_object(&self) -> Option<&'a ObjectValue<'a>> {
+    self.value.as_object()
+  }
+  /// Note that the dep of this entity is not included, use `get_shallow_dep` for it
+  pub fn as_promise(&self) -> Option<&'a PromiseValue<'a>> {
+    self.value.as_promise()
+  }
+  /// Note that the dep of this entity is not included, use `get_shallow_dep` for it
+  pub fn as_regexp(&self) -> Option<&'a RegExpValue<'a>> {
+    self.value.as_regexp()
+  }
+  /// Note that the dep of this entity is not included, use `get_shallow_dep` for it
+  pub fn as_array_buffer(&self) -> Option<&'a ArrayBufferValue<'a>> {
+    self.value.as_array_buffer()
+  }
   pub fn test_is_undefined(&self) -> Option<bool> {
     self.value.test_is_undefined()
   }
@@ -182,8 +227,9 @@ impl<'a> Entity<'a> {
     &self,
     analyzer: &mut Analyzer<'a>,
     dep: impl DepTrait<'a> + 'a,
+    partially: bool,
   ) -> Option<Entity<'a>> {
-    self.value.iterate_result_union(analyzer, self.forward_dep(dep, analyzer))
+    self.value.iterate_result_union(analyzer, self.forward_dep(dep, analyzer), partially)
   }
 
   pub fn call_as_getter(
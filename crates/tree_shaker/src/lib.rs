@@ -13,23 +13,23 @@ mod utils;
 mod value;
 pub mod vfs;
 
-use std::{cell::RefCell, collections::BTreeSet, mem, rc::Rc};
+use std::{cell::RefCell, collections::BTreeSet, mem, path::PathBuf, rc::Rc};
 
 pub use analyzer::Analyzer;
-pub use config::{TreeShakeConfig, TreeShakeJsxPreset};
+pub use config::{TreeShakeConfig, TreeShakeJsxPreset, TreeShakeTargetEnv};
 use mangling::ManglerTransformer;
-use module::ModuleInfo;
+use module::{ModuleInfo, get_source_type};
 use oxc::{
   allocator::Allocator,
   codegen::{Codegen, CodegenOptions, CodegenReturn},
   minifier::{Minifier, MinifierOptions},
   parser::Parser,
-  span::SourceType,
 };
 use oxc_ast_visit::VisitMut;
+use oxc_sourcemap::SourceMap;
 use rustc_hash::FxHashMap;
 use transformer::Transformer;
-use utils::ast;
+use utils::{ast, source_map::chain_source_map};
 use vfs::Vfs;
 
 pub struct TreeShakeOptions<F: Vfs> {
@@ -37,28 +37,41 @@ pub struct TreeShakeOptions<F: Vfs> {
   pub config: TreeShakeConfig,
   pub minify_options: Option<MinifierOptions>,
   pub codegen_options: CodegenOptions,
+  /// Generate a source map for each module, chained with the input source map from the VFS
+  pub source_map: bool,
 }
 
 pub struct TreeShakeReturn {
+  /// Keyed by module path. `map` is set when `TreeShakeOptions::source_map` is enabled.
   pub codegen_return: FxHashMap<String, CodegenReturn>,
   pub diagnostics: BTreeSet<String>,
 }
 
 pub fn tree_shake<F: Vfs + 'static>(
   options: TreeShakeOptions<F>,
-  entry: String,
+  entries: Vec<String>,
 ) -> TreeShakeReturn {
-  let TreeShakeOptions { vfs, config, minify_options, codegen_options } = options;
+  let TreeShakeOptions { vfs, config, minify_options, codegen_options, source_map } = options;
+
+  if entries.is_empty() {
+    return TreeShakeReturn {
+      codegen_return: FxHashMap::default(),
+      diagnostics: BTreeSet::from(["No entry module is provided".to_string()]),
+    };
+  }
 
   if config.enabled {
     let allocator = Allocator::default();
-    let config = allocator.alloc(config);
+    let config = &config;
 
     // Step 1: Analyze
     let mut analyzer = Analyzer::new_in(Box::new(vfs), config, &allocator);
-    analyzer.import_module(entry);
+    for entry in entries {
+      analyzer.import_entry(entry);
+    }
     analyzer.post_analysis();
     let Analyzer {
+      vfs,
       modules,
       diagnostics,
       folder,
@@ -71,7 +84,7 @@ pub fn tree_shake<F: Vfs + 'static>(
     let mangler = Rc::new(RefCell::new(mangler));
     let mut codegen_return = FxHashMap::default();
     for module_info in mem::take(&mut modules.modules) {
-      let ModuleInfo { path, program, semantic, .. } = module_info;
+      let ModuleInfo { path, program, semantic, used_id, side_effects, .. } = module_info;
 
       // Step 2: Transform
       let transformer = Transformer::new(
@@ -93,6 +106,10 @@ pub fn tree_shake<F: Vfs + 'static>(
         let program = unsafe { &*program.get() };
         allocator.alloc(transformer.transform_program(program))
       };
+      if !side_effects && !referred_deps.is_referred(used_id) {
+        // The module is not needed at all
+        program.body.clear();
+      }
 
       // Step 3: Minify
       let minifier_return = minify_options.map(|options| {
@@ -102,33 +119,73 @@ pub fn tree_shake<F: Vfs + 'static>(
 
       // Step 4: Generate output
       let codegen = Codegen::new()
-        .with_options(codegen_options.clone())
+        .with_options(get_codegen_options(&codegen_options, source_map, &path))
         .with_scoping(minifier_return.and_then(|r| r.scoping));
-      codegen_return.insert(path.to_string(), codegen.build(program));
+      let mut result = codegen.build(program);
+      chain_input_source_map(vfs.as_ref(), &path, &mut result, diagnostics);
+      codegen_return.insert(path.to_string(), result);
     }
     TreeShakeReturn { codegen_return, diagnostics: mem::take(diagnostics) }
   } else {
     let allocator = Allocator::default();
-    let config = allocator.alloc(config);
+    let config = &config;
 
-    let source_text = vfs.read_file(&entry);
-    let parser =
-      Parser::new(&allocator, &source_text, SourceType::mjs().with_jsx(config.jsx.is_enabled()));
-    let parsed = parser.parse();
-    let mut program = parsed.program;
-    let minifier_return = minify_options.map(|options| {
-      let minifier = Minifier::new(options);
-      minifier.build(&allocator, &mut program)
-    });
-    let codegen = Codegen::new()
-      .with_options(codegen_options.clone())
-      .with_scoping(minifier_return.and_then(|r| r.scoping));
     let mut codegen_return = FxHashMap::default();
-    codegen_return.insert(entry, codegen.build(&program));
     let mut diagnostics = BTreeSet::<String>::default();
-    for error in parsed.errors {
-      diagnostics.insert(error.to_string());
+    for entry in entries {
+      let source_text = match vfs.read_file(&entry) {
+        Ok(source_text) => source_text,
+        Err(error) => {
+          diagnostics.insert(format!("[{}] Couldn't read the file: {}", entry, error));
+          continue;
+        }
+      };
+      let parser =
+        Parser::new(&allocator, &source_text, get_source_type(&entry, config.jsx.is_enabled()));
+      let parsed = parser.parse();
+      let mut program = parsed.program;
+      let minifier_return = minify_options.map(|options| {
+        let minifier = Minifier::new(options);
+        minifier.build(&allocator, &mut program)
+      });
+      let codegen = Codegen::new()
+        .with_options(get_codegen_options(&codegen_options, source_map, &entry))
+        .with_scoping(minifier_return.and_then(|r| r.scoping));
+      let mut result = codegen.build(&program);
+      chain_input_source_map(&vfs, &entry, &mut result, &mut diagnostics);
+      codegen_return.insert(entry, result);
+      for error in parsed.errors {
+        diagnostics.insert(error.to_string());
+      }
     }
     TreeShakeReturn { codegen_return, diagnostics }
   }
 }
+
+fn get_codegen_options(options: &CodegenOptions, source_map: bool, path: &str) -> CodegenOptions {
+  let mut options = options.clone();
+  if source_map {
+    options.source_map_path = Some(PathBuf::from(path));
+  }
+  options
+}
+
+fn chain_input_source_map(
+  vfs: &dyn Vfs,
+  path: &str,
+  result: &mut CodegenReturn,
+  diagnostics: &mut BTreeSet<String>,
+) {
+  let Some(map) = &mut result.map else {
+    return;
+  };
+  if let Some(input) = vfs.read_source_map(path) {
+    match SourceMap::from_json_string(&input) {
+      Ok(input) => *map = chain_source_map(map, &input),
+      Err(err) => {
+        diagnostics.insert(format!("Invalid input source map for {}: {:?}", path, err));
+      }
+    }
+  }
+  map.set_file(path);
+}
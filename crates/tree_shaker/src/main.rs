@@ -7,14 +7,15 @@ use oxc::{
   minifier::{MangleOptions, MinifierOptions},
 };
 use tree_shaker::{
-  TreeShakeConfig, TreeShakeOptions, tree_shake,
-  vfs::{SingleFileFs, StdFs, Vfs},
+  TreeShakeConfig, TreeShakeOptions, TreeShakeTargetEnv, tree_shake,
+  vfs::{NodeResolverFs, SingleFileFs, StdFs, Vfs},
 };
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 struct Args {
-  path: String,
+  #[arg(required = true)]
+  paths: Vec<String>,
 
   #[arg(short, long, default_value_t = false)]
   single_file: bool,
@@ -41,11 +42,26 @@ struct Args {
   // on/off/only
   mangle: String,
 
+  #[arg(long, default_value_t = String::from("unknown"))]
+  // unknown/browser/node/worker
+  env: String,
+
+  #[arg(long)]
+  // KEY=JSON, like `process.env.NODE_ENV="production"`
+  define: Vec<String>,
+
+  #[arg(long)]
+  // Console methods whose calls can be removed, like `debug`
+  pure_console: Vec<String>,
+
   #[arg(short, long, default_value_t = 2)]
   recursion_depth: usize,
 
   #[arg(long, default_value_t = false)]
   no_remember_exhausted: bool,
+
+  #[arg(long, default_value_t = false)]
+  source_map: bool,
 }
 
 fn main() {
@@ -72,8 +88,36 @@ fn main() {
       std::process::exit(1);
     }
   })
+  .with_target_env(match args.env.as_str() {
+    "unknown" => TreeShakeTargetEnv::Unknown,
+    "browser" => TreeShakeTargetEnv::Browser,
+    "node" => TreeShakeTargetEnv::Node,
+    "worker" => TreeShakeTargetEnv::Worker,
+    _ => {
+      eprintln!("Invalid --env: {}", args.env);
+      std::process::exit(1);
+    }
+  })
   .with_max_recursion_depth(args.recursion_depth)
   .with_remember_exhausted(!args.no_remember_exhausted);
+  let shake_enabled = args.define.iter().fold(shake_enabled, |config, define| {
+    let Some((path, value)) = define.split_once('=') else {
+      eprintln!("Invalid --define: {}", define);
+      std::process::exit(1);
+    };
+    match serde_json::from_str(value) {
+      Ok(value) => config.with_define(path, value),
+      Err(why) => {
+        eprintln!("Invalid --define {}: {}", define, why);
+        std::process::exit(1);
+      }
+    }
+  });
+
+  let shake_enabled = args
+    .pure_console
+    .iter()
+    .fold(shake_enabled, |config, method| config.with_pure_console_method(method));
 
   let minify_options = MinifierOptions {
     mangle: Some(MangleOptions { top_level: true, ..Default::default() }),
@@ -82,9 +126,10 @@ fn main() {
   let min_codegen_options = CodegenOptions { minify: true, comments: false, ..Default::default() };
 
   if args.single_file {
-    let source = match std::fs::read_to_string(&args.path) {
+    let path = &args.paths[0];
+    let source = match std::fs::read_to_string(path) {
       Err(why) => {
-        eprintln!("Couldn't read {}: {}", args.path, why);
+        eprintln!("Couldn't read {}: {}", path, why);
         std::process::exit(1);
       }
       Ok(content) => content,
@@ -98,8 +143,9 @@ fn main() {
         config: shake_disabled.clone(),
         minify_options: None,
         codegen_options: CodegenOptions::default(),
+        source_map: false,
       },
-      SingleFileFs::ENTRY_PATH.to_string(),
+      vec![SingleFileFs::ENTRY_PATH.to_string()],
     );
     let copied_code = copied.codegen_return[SingleFileFs::ENTRY_PATH].code.clone();
     let minified = tree_shake(
@@ -108,8 +154,9 @@ fn main() {
         config: shake_disabled.clone(),
         minify_options: Some(minify_options),
         codegen_options: min_codegen_options.clone(),
+        source_map: false,
       },
-      SingleFileFs::ENTRY_PATH.to_string(),
+      vec![SingleFileFs::ENTRY_PATH.to_string()],
     );
     let minified_code = minified.codegen_return[SingleFileFs::ENTRY_PATH].code.clone();
     let shaken = tree_shake(
@@ -118,8 +165,9 @@ fn main() {
         config: shake_enabled,
         minify_options: None,
         codegen_options: CodegenOptions::default(),
+        source_map: false,
       },
-      SingleFileFs::ENTRY_PATH.to_string(),
+      vec![SingleFileFs::ENTRY_PATH.to_string()],
     );
     let shaken_code = shaken.codegen_return[SingleFileFs::ENTRY_PATH].code.clone();
     let shaken_minified = tree_shake(
@@ -128,8 +176,9 @@ fn main() {
         config: shake_disabled.clone(),
         minify_options: Some(minify_options),
         codegen_options: min_codegen_options,
+        source_map: false,
       },
-      SingleFileFs::ENTRY_PATH.to_string(),
+      vec![SingleFileFs::ENTRY_PATH.to_string()],
     );
     let shaken_minified_code =
       shaken_minified.codegen_return[SingleFileFs::ENTRY_PATH].code.clone();
@@ -153,7 +202,7 @@ fn main() {
     // If the input file is dir/a.js, the output file will be dir/a.out.js
     let output_path = args.output.map_or_else(
       || {
-        let mut output_path = PathBuf::from(&args.path);
+        let mut output_path = PathBuf::from(path);
         if !args.no_shake {
           output_path.set_extension("out.js");
         }
@@ -196,7 +245,7 @@ fn main() {
 
     let shaken = tree_shake(
       TreeShakeOptions {
-        vfs: StdFs,
+        vfs: NodeResolverFs::default(),
         config: shake_enabled,
         minify_options: args.minify.then_some(minify_options),
         codegen_options: if args.minify {
@@ -204,8 +253,9 @@ fn main() {
         } else {
           CodegenOptions::default()
         },
+        source_map: args.source_map,
       },
-      args.path.clone(),
+      args.paths.clone(),
     );
 
     for diagnostic in shaken.diagnostics.iter() {
@@ -245,8 +295,21 @@ fn main() {
         Ok(file) => file,
       };
       output_file.write_all(codegen_return.code.as_bytes()).unwrap();
+      if let Some(map) = &codegen_return.map {
+        let map_path = format!("{}.map", out_path.display());
+        if let Err(why) = std::fs::write(&map_path, map.to_json_string()) {
+          eprintln!("Couldn't write {}: {}", map_path, why);
+          std::process::exit(1);
+        }
+      }
 
-      let source = StdFs.read_file(&path);
+      let source = match StdFs.read_file(&path) {
+        Err(why) => {
+          eprintln!("Couldn't read {}: {}", path, why);
+          std::process::exit(1);
+        }
+        Ok(content) => content,
+      };
 
       let non_shaken = tree_shake(
         TreeShakeOptions {
@@ -258,8 +321,9 @@ fn main() {
           } else {
             CodegenOptions::default()
           },
+          source_map: false,
         },
-        SingleFileFs::ENTRY_PATH.to_string(),
+        vec![SingleFileFs::ENTRY_PATH.to_string()],
       );
       let non_shaken_code = non_shaken.codegen_return[SingleFileFs::ENTRY_PATH].code.clone();
 
@@ -2,8 +2,8 @@ use std::{cell::UnsafeCell, mem, rc::Rc};
 
 use line_index::LineIndex;
 use oxc::{
-  allocator::FromIn,
-  ast::ast::{ImportDeclaration, Program, Statement},
+  allocator::{self, FromIn},
+  ast::ast::{ImportDeclaration, Program, PropertyKind, Statement},
   parser::Parser,
   semantic::{Semantic, SemanticBuilder, SymbolId},
   span::{Atom, SourceType},
@@ -20,12 +20,15 @@ use crate::{
     variable_scope::VariableScope,
   },
   utils::{CalleeInfo, CalleeNode},
+  value::{ObjectPropertyValue, ObjectPrototype, TypeofResult},
 };
 
 #[derive(Debug, Clone, Copy)]
 pub enum NamedExport<'a> {
   Variable(VariableScopeId, SymbolId, Dep<'a>),
   ReExport(ModuleId, Atom<'a>, Dep<'a>),
+  /// `export * as ns from '...'`
+  Namespace(ModuleId, Dep<'a>),
   Value(Entity<'a>),
 }
 
@@ -37,13 +40,12 @@ impl<'a> CustomDepTrait<'a> for NamedExport<'a> {
         analyzer.consume((value, dep));
       }
       NamedExport::ReExport(module_id, name, dep) => {
-        if name == "default" {
-          analyzer.consume(analyzer.modules.modules[module_id].default_export);
-        } else {
-          let export = analyzer.modules.modules[module_id].named_exports.get(&name).copied();
-          analyzer.consume(export);
-        }
+        let value = analyzer.get_export_value(module_id, name);
+        analyzer.consume((value, dep));
+      }
+      NamedExport::Namespace(module_id, dep) => {
         analyzer.consume(dep);
+        analyzer.consume_exports(module_id);
       }
       NamedExport::Value(entity) => {
         analyzer.consume(entity);
@@ -52,6 +54,24 @@ impl<'a> CustomDepTrait<'a> for NamedExport<'a> {
   }
 }
 
+/// Runtime bindings of a CommonJS module.
+#[derive(Debug, Clone, Copy)]
+pub struct CommonJsModule<'a> {
+  /// The `module` object
+  pub module: Entity<'a>,
+  /// The `exports` binding, which initially is the same object as `module.exports`
+  pub exports: Entity<'a>,
+}
+
+/// The result of resolving an exported name, following re-exports and `export *`.
+#[derive(Debug)]
+pub enum ExportResolution<'a> {
+  NotFound,
+  Ambiguous,
+  /// The module and the name of the local export, with the deps of the re-exports in between
+  Resolved(ModuleId, Atom<'a>, allocator::Vec<'a, Dep<'a>>),
+}
+
 #[derive(Clone)]
 pub struct ModuleInfo<'a> {
   pub path: Atom<'a>,
@@ -59,9 +79,20 @@ pub struct ModuleInfo<'a> {
   pub program: &'a UnsafeCell<Program<'a>>,
   pub semantic: Rc<Semantic<'a>>,
   pub call_id: DepAtom,
+  /// Referred when the module is needed at runtime
+  pub used_id: DepAtom,
+  /// Whether loading the module has side effects, as declared by the `sideEffects` field of
+  /// `package.json`. Modules without side effects can be removed if none of their exports are used.
+  pub side_effects: bool,
 
   pub named_exports: FxHashMap<Atom<'a>, NamedExport<'a>>,
   pub default_export: Option<Entity<'a>>,
+  pub commonjs: Option<CommonJsModule<'a>>,
+  /// `export * from '...'`
+  pub star_exports: Vec<(ModuleId, Dep<'a>)>,
+  /// Has `export * from` a module whose exported names are unknown
+  pub unknown_star_exports: bool,
+  consuming_exports: bool,
 
   pub blocked_imports: Vec<(ModuleId, VariableScopeId, &'a ImportDeclaration<'a>)>,
 }
@@ -73,6 +104,7 @@ define_index_type! {
 #[derive(Default)]
 pub struct Modules<'a> {
   pub modules: IndexVec<ModuleId, ModuleInfo<'a>>,
+  pub entries: Vec<ModuleId>,
   paths: FxHashMap<String, ModuleId>,
 }
 
@@ -100,6 +132,14 @@ impl<'a> Analyzer<'a> {
     Some(self.import_module(path))
   }
 
+  pub fn import_entry(&mut self, path: String) -> ModuleId {
+    let module_id = self.import_module(path);
+    if !self.modules.entries.contains(&module_id) {
+      self.modules.entries.push(module_id);
+    }
+    module_id
+  }
+
   pub fn import_module(&mut self, path: String) -> ModuleId {
     let path = self.vfs.normalize_path(path);
 
@@ -107,12 +147,18 @@ impl<'a> Analyzer<'a> {
       return *module_id;
     }
 
-    let source_text = self.allocator.alloc_str(&self.vfs.read_file(path.as_str()));
+    let source_text = match self.vfs.read_file(path.as_str()) {
+      Ok(source_text) => self.allocator.alloc_str(&source_text),
+      Err(error) => {
+        self.add_diagnostic(format!("[{}] Couldn't read the file: {}", path, error));
+        ""
+      }
+    };
     let line_index = LineIndex::new(source_text);
     let parser = Parser::new(
       self.allocator,
       source_text,
-      SourceType::mjs().with_jsx(self.config.jsx.is_enabled()),
+      get_source_type(&path, self.config.jsx.is_enabled()),
     );
     let parsed = parser.parse();
     let program = self.allocator.alloc(UnsafeCell::new(parsed.program));
@@ -127,9 +173,15 @@ impl<'a> Analyzer<'a> {
       program,
       semantic,
       call_id: DepAtom::from_counter(),
+      used_id: DepAtom::from_counter(),
+      side_effects: self.vfs.has_side_effects(&path),
 
       named_exports: Default::default(),
       default_export: Default::default(),
+      commonjs: None,
+      star_exports: Vec::new(),
+      unknown_star_exports: false,
+      consuming_exports: false,
 
       blocked_imports: Vec::new(),
     });
@@ -165,6 +217,14 @@ impl<'a> Analyzer<'a> {
     self.scoping.cf.push(CfScope::new(CfScopeKind::Module, self.factory.vec(), false));
 
     let program = unsafe { &*program.get() };
+
+    if self.is_commonjs_module(module_id, program) {
+      let commonjs = self.create_commonjs_module();
+      self.modules.modules[module_id].commonjs = Some(commonjs);
+      // Top-level `this` is `module.exports`
+      self.scoping.variable.get_mut(root_variable_scope).this = Some(commonjs.exports);
+    }
+
     for node in &program.body {
       self.declare_statement(node);
     }
@@ -191,28 +251,306 @@ impl<'a> Analyzer<'a> {
     }
   }
 
-  pub fn consume_exports(&mut self, module_id: ModuleId) {
-    let ModuleInfo { call_id, named_exports, default_export, .. } =
-      self.modules.modules[module_id].clone();
-    self.refer_dep(call_id);
-    for named_export in named_exports.into_values() {
-      self.consume(named_export);
+  fn is_commonjs_module(&self, module_id: ModuleId, program: &Program<'a>) -> bool {
+    let module_info = &self.modules.modules[module_id];
+    if module_info.path.ends_with(".cjs") || module_info.path.ends_with(".cts") {
+      return true;
+    }
+    if program.body.iter().any(|node| matches!(node, Statement::TSExportAssignment(_))) {
+      // `export = value`
+      return true;
     }
-    if let Some(entity) = default_export {
-      self.consume(entity);
+    if program.body.iter().any(Statement::is_module_declaration) {
+      return false;
     }
+    let unresolved = module_info.semantic.scoping().root_unresolved_references();
+    ["module", "exports", "require"].iter().any(|name| unresolved.contains_key(*name))
   }
 
-  pub fn get_named_export_value(&mut self, named_export: NamedExport<'a>) -> Entity<'a> {
-    match named_export {
-      NamedExport::Variable(scope, symbol, dep) => {
-        let value = self.read_on_scope(scope, symbol).unwrap().unwrap();
+  fn create_commonjs_module(&mut self) -> CommonJsModule<'a> {
+    let exports: Entity<'a> = self
+      .new_empty_object(ObjectPrototype::Builtin(&self.builtins.prototypes.object), None)
+      .into();
+    let module =
+      self.new_empty_object(ObjectPrototype::Builtin(&self.builtins.prototypes.object), None);
+    module.init_property(self, PropertyKind::Init, self.factory.string("exports"), exports, true);
+    // `module.id`, `module.filename`, `module.require`, etc.
+    module.init_rest(self.factory, ObjectPropertyValue::Field(self.factory.unknown, false));
+    CommonJsModule { module: module.into(), exports }
+  }
+
+  /// Returns the value of `require(...)` of the module
+  pub fn get_module_exports_object(&mut self, module_id: ModuleId) -> Entity<'a> {
+    let ModuleInfo { commonjs, used_id, .. } = self.modules.modules[module_id];
+    if let Some(commonjs) = commonjs {
+      let key = self.factory.string("exports");
+      commonjs.module.get_property(self, used_id, key)
+    } else {
+      self.get_module_namespace(module_id)
+    }
+  }
+
+  /// Returns the namespace object of the module, as `import * as ns from '...'`
+  pub fn get_module_namespace(&mut self, module_id: ModuleId) -> Entity<'a> {
+    let object =
+      self.new_empty_object(ObjectPrototype::Builtin(&self.builtins.prototypes.null), None);
+    if self.modules.modules[module_id].commonjs.is_some() {
+      let exports = self.get_module_exports_object(module_id);
+      object.init_spread(self, self.factory.no_dep, exports);
+      let default = self.get_commonjs_default_export(module_id);
+      object.init_property(self, PropertyKind::Init, self.factory.string("default"), default, true);
+    } else {
+      let mut names = vec![];
+      let mut unknown = None;
+      self.collect_export_names(
+        module_id,
+        self.factory.no_dep,
+        &mut vec![],
+        &mut names,
+        &mut unknown,
+      );
+      for name in names {
+        // Ambiguous names are excluded from the namespace
+        if let Some(value) = self.resolve_export_value(module_id, name) {
+          object.init_property(
+            self,
+            PropertyKind::Init,
+            self.factory.string(name.as_str()),
+            value,
+            true,
+          );
+        }
+      }
+      if let Some(unknown) = unknown {
+        let value = self.factory.computed_unknown(unknown);
+        object.init_property(self, PropertyKind::Init, self.factory.unknown_string, value, false);
+      }
+    }
+    object.into()
+  }
+
+  /// Collects the exported names of the module, as the `GetExportedNames` abstract operation.
+  /// Modules whose exported names are only known at runtime are collected into `unknown`.
+  fn collect_export_names(
+    &self,
+    module_id: ModuleId,
+    dep: Dep<'a>,
+    visited: &mut Vec<ModuleId>,
+    names: &mut Vec<Atom<'a>>,
+    unknown: &mut Option<allocator::Vec<'a, Dep<'a>>>,
+  ) {
+    if visited.contains(&module_id) {
+      return;
+    }
+    visited.push(module_id);
+    let module_info = &self.modules.modules[module_id];
+    if module_info.commonjs.is_some() || module_info.unknown_star_exports {
+      unknown.get_or_insert_with(|| self.factory.vec()).push(self.factory.dep((dep, module_id)));
+    }
+    let is_star = visited.len() > 1;
+    for &name in module_info.named_exports.keys() {
+      if !(names.contains(&name) || is_star && name == "default") {
+        names.push(name);
+      }
+    }
+    if !is_star && module_info.default_export.is_some() && !names.contains(&"default".into()) {
+      names.push("default".into());
+    }
+    for &(star, star_dep) in &module_info.star_exports {
+      self.collect_export_names(star, self.factory.dep((dep, star_dep)), visited, names, unknown);
+    }
+  }
+
+  /// Resolves an exported name, as the `ResolveExport` abstract operation
+  pub fn resolve_export(
+    &self,
+    module_id: ModuleId,
+    name: Atom<'a>,
+    visited: &mut Vec<(ModuleId, Atom<'a>)>,
+  ) -> ExportResolution<'a> {
+    let mut resolution = self.resolve_export_in_module(module_id, name, visited);
+    if let ExportResolution::Resolved(_, _, deps) = &mut resolution {
+      // Every module along the re-export chain is needed
+      deps.push(self.factory.dep(self.modules.modules[module_id].used_id));
+    }
+    resolution
+  }
+
+  fn resolve_export_in_module(
+    &self,
+    module_id: ModuleId,
+    name: Atom<'a>,
+    visited: &mut Vec<(ModuleId, Atom<'a>)>,
+  ) -> ExportResolution<'a> {
+    if visited.contains(&(module_id, name)) {
+      // Circular import request
+      return ExportResolution::NotFound;
+    }
+    visited.push((module_id, name));
+
+    let module_info = &self.modules.modules[module_id];
+    if module_info.commonjs.is_some() {
+      return ExportResolution::Resolved(module_id, name, self.factory.vec());
+    }
+    match module_info.named_exports.get(&name) {
+      Some(&NamedExport::ReExport(module, imported, dep)) => {
+        return match self.resolve_export(module, imported, visited) {
+          ExportResolution::Resolved(module, name, mut deps) => {
+            deps.push(dep);
+            ExportResolution::Resolved(module, name, deps)
+          }
+          resolution => resolution,
+        };
+      }
+      Some(_) => return ExportResolution::Resolved(module_id, name, self.factory.vec()),
+      None => {}
+    }
+    if name == "default" {
+      // A default export cannot be provided by `export *`
+      return if module_info.default_export.is_some() {
+        ExportResolution::Resolved(module_id, name, self.factory.vec())
+      } else {
+        ExportResolution::NotFound
+      };
+    }
+
+    let mut star_resolution = None;
+    let mut commonjs_fallback = None;
+    for &(star, dep) in &module_info.star_exports {
+      if self.modules.modules[star].commonjs.is_some() {
+        // Names of a CommonJS module are only known at runtime
+        commonjs_fallback.get_or_insert((star, dep));
+        continue;
+      }
+      match self.resolve_export(star, name, visited) {
+        ExportResolution::NotFound => {}
+        ExportResolution::Ambiguous => return ExportResolution::Ambiguous,
+        ExportResolution::Resolved(module, local, mut deps) => {
+          deps.push(dep);
+          match &star_resolution {
+            None => star_resolution = Some((module, local, deps)),
+            Some((m, l, _)) => {
+              if (*m, *l) != (module, local) {
+                return ExportResolution::Ambiguous;
+              }
+            }
+          }
+        }
+      }
+    }
+    if let Some((module, local, deps)) = star_resolution {
+      ExportResolution::Resolved(module, local, deps)
+    } else if let Some((star, dep)) = commonjs_fallback {
+      ExportResolution::Resolved(star, name, self.factory.vec1(dep))
+    } else {
+      ExportResolution::NotFound
+    }
+  }
+
+  /// Returns `None` if the name is ambiguous
+  fn resolve_export_value(&mut self, module_id: ModuleId, name: Atom<'a>) -> Option<Entity<'a>> {
+    match self.resolve_export(module_id, name, &mut vec![]) {
+      ExportResolution::Resolved(module, local, deps) => {
+        let value = self.get_local_export_value(module, local);
+        Some(self.factory.computed(value, deps))
+      }
+      ExportResolution::NotFound => Some(self.factory.unknown),
+      ExportResolution::Ambiguous => None,
+    }
+  }
+
+  /// Returns the value of an exported name of the module, as `import { name } from '...'`
+  pub fn get_export_value(&mut self, module_id: ModuleId, name: Atom<'a>) -> Entity<'a> {
+    self.resolve_export_value(module_id, name).unwrap_or_else(|| {
+      self.add_diagnostic(format!(
+        "Ambiguous export \"{}\" in {}",
+        name, self.modules.modules[module_id].path
+      ));
+      self.factory.unknown
+    })
+  }
+
+  fn get_local_export_value(&mut self, module_id: ModuleId, name: Atom<'a>) -> Entity<'a> {
+    let module_info = &self.modules.modules[module_id];
+    if module_info.commonjs.is_some() {
+      return if name == "default" {
+        self.get_commonjs_default_export(module_id)
+      } else {
+        let exports = self.get_module_exports_object(module_id);
+        exports.get_property(self, self.factory.no_dep, self.factory.string(name.as_str()))
+      };
+    }
+    match module_info.named_exports.get(&name).copied() {
+      Some(NamedExport::Variable(scope, symbol, dep)) => {
+        let value = self.read_on_scope(scope, symbol).flatten().unwrap_or(self.factory.unknown);
         self.factory.computed(value, dep)
       }
-      NamedExport::ReExport(_, _, _) => todo!(),
-      NamedExport::Value(entity) => entity,
+      Some(NamedExport::Namespace(module, dep)) => {
+        let namespace = self.get_module_namespace(module);
+        self.factory.computed(namespace, dep)
+      }
+      Some(NamedExport::Value(entity)) => entity,
+      Some(NamedExport::ReExport(..)) => unreachable!(),
+      None => module_info.default_export.unwrap_or(self.factory.unknown),
     }
   }
+
+  /// The default import of a CommonJS module. Follows the interop of bundlers:
+  /// `exports.default` if `exports.__esModule` is truthy, otherwise `module.exports` itself.
+  pub fn get_commonjs_default_export(&mut self, module_id: ModuleId) -> Entity<'a> {
+    let exports = self.get_module_exports_object(module_id);
+    if !exports.test_typeof().intersects(TypeofResult::Object | TypeofResult::Function) {
+      return exports;
+    }
+    let es_module =
+      exports.get_property(self, self.factory.no_dep, self.factory.string("__esModule"));
+    let maybe_es_module = es_module.test_truthy();
+    if maybe_es_module == Some(false) {
+      return exports;
+    }
+    let default = exports.get_property(self, self.factory.no_dep, self.factory.string("default"));
+    if maybe_es_module == Some(true) {
+      default
+    } else {
+      self.factory.computed(self.factory.union((default, exports)), es_module)
+    }
+  }
+
+  pub fn consume_exports(&mut self, module_id: ModuleId) {
+    self.consume_exports_impl(module_id, true);
+  }
+
+  fn consume_exports_impl(&mut self, module_id: ModuleId, include_default: bool) {
+    let module_info = &mut self.modules.modules[module_id];
+    if module_info.consuming_exports {
+      // Circular `export *`
+      return;
+    }
+    module_info.consuming_exports = true;
+
+    let ModuleInfo {
+      call_id, used_id, named_exports, default_export, commonjs, star_exports, ..
+    } = module_info.clone();
+    self.refer_dep(call_id);
+    self.refer_dep(used_id);
+    if let Some(commonjs) = commonjs {
+      self.consume(commonjs.module);
+    }
+    for (name, named_export) in named_exports {
+      if include_default || name != "default" {
+        self.consume(named_export);
+      }
+    }
+    if include_default {
+      self.consume(default_export);
+    }
+    for (star, dep) in star_exports {
+      self.consume(dep);
+      self.consume_exports_impl(star, false);
+    }
+
+    self.modules.modules[module_id].consuming_exports = false;
+  }
 }
 
 impl CustomDepTrait<'_> for ModuleId {
@@ -220,3 +558,11 @@ impl CustomDepTrait<'_> for ModuleId {
     analyzer.consume_exports(*self);
   }
 }
+
+/// TypeScript files are recognized by their extensions, everything else is parsed as ES module.
+pub fn get_source_type(path: &str, jsx: bool) -> SourceType {
+  SourceType::from_path(path)
+    .ok()
+    .filter(|source_type| source_type.is_typescript())
+    .unwrap_or_else(|| SourceType::mjs().with_jsx(jsx))
+}
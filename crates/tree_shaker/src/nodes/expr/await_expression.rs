@@ -12,6 +12,11 @@ impl<'a> Analyzer<'a> {
     self.refer_to_global();
 
     let value = self.exec_expression(&node.argument);
+
+    if let Some(later_awaits) = self.call_scope().later_awaits {
+      later_awaits.push(self, self.dep(AstKind2::AwaitExpression(node)));
+    }
+
     value.r#await(self, AstKind2::AwaitExpression(node))
   }
 }
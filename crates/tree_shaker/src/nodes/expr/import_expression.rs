@@ -13,6 +13,9 @@ impl<'a> Analyzer<'a> {
 
     if let Some(LiteralValue::String(specifier, _m)) = specifier.get_literal(self) {
       if let Some(module_id) = self.resolve_and_import_module(specifier) {
+        if self.modules.modules[module_id].side_effects {
+          self.consume(dep);
+        }
         return self.factory.computed_unknown((module_id, dep));
       }
     }
@@ -30,7 +30,8 @@ impl<'a> Analyzer<'a> {
     self.factory.null
   }
 
-  pub fn exec_regexp_literal(&mut self, _node: &'a RegExpLiteral<'a>) -> Entity<'a> {
-    self.factory.unknown
+  pub fn exec_regexp_literal(&mut self, node: &'a RegExpLiteral<'a>) -> Entity<'a> {
+    let flags = self.allocator.alloc_str(&node.regex.flags.to_string());
+    self.new_regexp(node.regex.pattern.text.as_str(), flags)
   }
 }
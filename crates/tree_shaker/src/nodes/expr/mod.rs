@@ -77,12 +77,13 @@ impl<'a> Analyzer<'a> {
       Expression::JSXElement(node) => self.exec_jsx_element(node),
       Expression::JSXFragment(node) => self.exec_jsx_fragment(node),
 
-      Expression::V8IntrinsicExpression(_)
-      | Expression::TSAsExpression(_)
+      Expression::TSAsExpression(_)
       | Expression::TSInstantiationExpression(_)
       | Expression::TSTypeAssertion(_)
       | Expression::TSNonNullExpression(_)
-      | Expression::TSSatisfiesExpression(_) => unreachable!(),
+      | Expression::TSSatisfiesExpression(_) => self.exec_ts_expression(node),
+
+      Expression::V8IntrinsicExpression(_) => unreachable!(),
     };
     self.pop_span();
     self.try_fold_node(AstKind2::Expression(node), value)
@@ -96,7 +97,13 @@ impl<'a> Transformer<'a> {
     need_val: bool,
   ) -> Option<Expression<'a>> {
     let span = node.span();
-    let literal = need_val.then(|| self.build_folded_expr(AstKind2::Expression(node))).flatten();
+    let literal = need_val
+      .then(|| {
+        self
+          .build_folded_expr(AstKind2::Expression(node))
+          .or_else(|| self.build_defined_value(node))
+      })
+      .flatten();
     let need_val = need_val && literal.is_none();
 
     let inner = match node {
@@ -111,7 +118,11 @@ impl<'a> Transformer<'a> {
       | Expression::RegExpLiteral(_) => need_val.then(|| self.clone_node(node)),
       Expression::TemplateLiteral(node) => self.transform_template_literal(node, need_val),
       Expression::Identifier(node) => {
-        self.transform_identifier_reference_read(node, need_val).map(Expression::Identifier)
+        if let Some(member) = self.transform_ts_enum_member_reference(node, need_val) {
+          member
+        } else {
+          self.transform_identifier_reference_read(node, need_val).map(Expression::Identifier)
+        }
       }
       Expression::FunctionExpression(node) => {
         self.transform_function(node, need_val).map(Expression::FunctionExpression)
@@ -155,12 +166,13 @@ impl<'a> Transformer<'a> {
       Expression::JSXElement(node) => self.transform_jsx_element(node, need_val),
       Expression::JSXFragment(node) => self.transform_jsx_fragment(node, need_val),
 
-      Expression::V8IntrinsicExpression(_)
-      | Expression::TSAsExpression(_)
+      Expression::TSAsExpression(_)
       | Expression::TSInstantiationExpression(_)
       | Expression::TSTypeAssertion(_)
       | Expression::TSNonNullExpression(_)
-      | Expression::TSSatisfiesExpression(_) => unreachable!(),
+      | Expression::TSSatisfiesExpression(_) => self.transform_ts_expression(node, need_val),
+
+      Expression::V8IntrinsicExpression(_) => unreachable!(),
     };
 
     if let Some(literal) = literal {
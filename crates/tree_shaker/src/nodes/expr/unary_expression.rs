@@ -43,6 +43,12 @@ impl<'a> Analyzer<'a> {
       return self.factory.r#true;
     }
 
+    if let (UnaryOperator::Typeof, Expression::Identifier(node)) = (node.operator, &node.argument) {
+      if self.is_absent_global(node) {
+        return self.factory.string("undefined");
+      }
+    }
+
     let argument = self.exec_expression(&node.argument);
 
     match &node.operator {
@@ -23,6 +23,10 @@ fn unwrap_to_member_expression<'a>(
       }
       _ => None,
     },
+    // `(a.b as T)()`, `a.b!()`, etc.
+    _ if node.is_typescript_syntax() => {
+      unwrap_to_member_expression(node.get_inner_expression()).map(|(node, _)| (node, false))
+    }
     _ => None,
   }
 }
@@ -68,6 +68,10 @@ impl<'a> Analyzer<'a> {
 
     // 2. Execute keys and find constructor
     for element in &node.body.body {
+      if is_type_only_class_element(element) {
+        data.keys.push(None);
+        continue;
+      }
       let key = element.property_key().map(|key| self.exec_property_key(key));
       data.keys.push(key);
 
@@ -84,6 +88,9 @@ impl<'a> Analyzer<'a> {
     // 3. Register methods
     for (key, element) in data.keys.iter().zip(node.body.body.iter()) {
       if let ClassElement::MethodDefinition(node) = element {
+        if node.value.body.is_none() {
+          continue;
+        }
         let kind = match node.kind {
           MethodDefinitionKind::Constructor => continue,
           MethodDefinitionKind::Method => PropertyKind::Init,
@@ -109,7 +116,9 @@ impl<'a> Analyzer<'a> {
       match element {
         ClassElement::StaticBlock(node) => self.exec_static_block(node),
         ClassElement::MethodDefinition(_node) => {}
-        ClassElement::PropertyDefinition(node) if node.r#static => {
+        ClassElement::PropertyDefinition(node)
+          if node.r#static && !is_type_only_class_element(element) =>
+        {
           let key = data.keys[index].unwrap();
           let value = self.exec_property_definition(node);
           class.statics.init_property(self, PropertyKind::Init, key, value, true);
@@ -164,7 +173,7 @@ impl<'a> Analyzer<'a> {
     // 1. Init properties
     for (key, element) in data.keys.iter().zip(node.body.body.iter()) {
       if let ClassElement::PropertyDefinition(node) = element {
-        if !node.r#static {
+        if !node.r#static && !is_type_only_class_element(element) {
           let value = self.exec_property_definition(node);
           this.set_property(self, self.factory.no_dep, key.unwrap(), value);
         }
@@ -177,6 +186,7 @@ impl<'a> Analyzer<'a> {
       let dep = self.factory.dep(AstKind2::Function(function));
       self.cf_scope_mut().push_dep(dep);
       self.exec_formal_parameters(&function.params, args, DeclarationKind::FunctionParameter);
+      self.init_parameter_properties(&function.params, this);
       self.exec_function_body(function.body.as_ref().unwrap());
       if consume {
         self.consume_return_values();
@@ -224,6 +234,9 @@ impl<'a> Transformer<'a> {
         let mut transformed_body = self.ast_builder.vec();
 
         for element in body {
+          if is_type_only_class_element(element) {
+            continue;
+          }
           if let Some(element) = match element {
             ClassElement::StaticBlock(node) => {
               self.transform_static_block(node).map(ClassElement::StaticBlock)
@@ -285,6 +298,9 @@ impl<'a> Transformer<'a> {
       }
 
       for element in &body.body {
+        if is_type_only_class_element(element) {
+          continue;
+        }
         if let Some(key) = element.property_key() {
           if key.is_expression() {
             if let Some(element) = self.transform_expression(key.to_expression(), false) {
@@ -302,7 +318,9 @@ impl<'a> Transformer<'a> {
               statements.push(self.ast_builder.statement_block(span, body));
             }
           }
-          ClassElement::PropertyDefinition(node) if node.r#static => {
+          ClassElement::PropertyDefinition(node)
+            if node.r#static && !is_type_only_class_element(element) =>
+          {
             if let Some(value) = &node.value {
               let span = value.span();
               if let Some(value) = self.transform_expression(value, false) {
@@ -342,3 +360,16 @@ impl<'a> Transformer<'a> {
     }
   }
 }
+
+/// Index signatures, abstract members, overloads and `declare` fields are erased
+fn is_type_only_class_element(node: &ClassElement) -> bool {
+  match node {
+    ClassElement::TSIndexSignature(_) => true,
+    ClassElement::MethodDefinition(node) => node.value.body.is_none(),
+    ClassElement::PropertyDefinition(node) => {
+      node.declare || node.r#type == PropertyDefinitionType::TSAbstractPropertyDefinition
+    }
+    ClassElement::AccessorProperty(node) => node.r#type.is_abstract(),
+    ClassElement::StaticBlock(_) => false,
+  }
+}
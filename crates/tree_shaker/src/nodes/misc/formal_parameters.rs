@@ -6,7 +6,12 @@ use oxc::{
   span::{GetSpan, SPAN},
 };
 
-use crate::{analyzer::Analyzer, ast::DeclarationKind, entity::Entity, transformer::Transformer};
+use crate::{
+  analyzer::Analyzer,
+  ast::{AstKind2, DeclarationKind},
+  entity::Entity,
+  transformer::Transformer,
+};
 
 impl<'a> Analyzer<'a> {
   pub fn exec_formal_parameters(
@@ -37,6 +42,19 @@ impl<'a> Analyzer<'a> {
       self.init_binding_rest_element(rest, rest_init.unwrap());
     }
   }
+
+  /// `constructor(private x) {}` also does `this.x = x`
+  pub fn init_parameter_properties(&mut self, node: &'a FormalParameters<'a>, this: Entity<'a>) {
+    for param in &node.items {
+      if param.has_modifier() {
+        let id = param.pattern.get_binding_identifier().unwrap();
+        let value = self.read_symbol(id.symbol_id()).unwrap();
+        let value = self.factory.computed(value, AstKind2::FormalParameter(param));
+        let key = self.factory.string(id.name.as_str());
+        this.set_property(self, self.factory.no_dep, key, value);
+      }
+    }
+  }
 }
 
 impl<'a> Transformer<'a> {
@@ -1,4 +1,8 @@
-use oxc::{allocator, ast::ast::IdentifierReference};
+use oxc::{
+  allocator,
+  ast::ast::IdentifierReference,
+  semantic::{SymbolFlags, SymbolId},
+};
 
 use crate::{analyzer::Analyzer, ast::AstKind2, entity::Entity, transformer::Transformer};
 
@@ -7,8 +11,7 @@ impl<'a> Analyzer<'a> {
     &mut self,
     node: &'a IdentifierReference<'a>,
   ) -> Entity<'a> {
-    let reference = self.semantic().scoping().get_reference(node.reference_id());
-    let symbol = reference.symbol_id();
+    let symbol = self.get_value_symbol(node);
 
     let dep = AstKind2::IdentifierReference(node);
 
@@ -26,6 +29,9 @@ impl<'a> Analyzer<'a> {
       let arguments_consumed = self.consume_arguments();
       self.call_scope_mut().need_consume_arguments = !arguments_consumed;
       self.factory.unknown
+    } else if let Some(value) = self.get_commonjs_binding(&node.name) {
+      // `module`, `exports` and `require` in CommonJS modules
+      value
     } else if let Some(global) = self.builtins.globals.get(node.name.as_str()) {
       // Known global
       *global
@@ -51,22 +57,53 @@ impl<'a> Analyzer<'a> {
     let dep = AstKind2::IdentifierReference(node);
     let value = self.factory.computed(value, dep);
 
-    let reference = self.semantic().scoping().get_reference(node.reference_id());
-    assert!(reference.is_write());
-    let symbol = reference.symbol_id();
+    assert!(self.semantic().scoping().get_reference(node.reference_id()).is_write());
+    let symbol = self.get_value_symbol(node);
 
     if let Some(symbol) = symbol {
       self.write_symbol(symbol, value);
-    } else if self.builtins.globals.contains_key(node.name.as_str()) {
+    } else if node.name == "exports" && self.module_info().commonjs.is_some() {
+      // The binding is no longer the `module.exports` object
+      self.consume(value);
+      self.module_info_mut().commonjs.as_mut().unwrap().exports = self.factory.unknown;
+    } else if self.builtins.globals.contains_key(node.name.as_str())
+      && !self.builtins.host_globals.contains(node.name.as_str())
+    {
       self.add_diagnostic(
         "Should not write to builtin object, it may cause unexpected tree-shaking behavior",
       );
     } else {
+      self.builtins.redefine_global(node.name.as_str());
       self.consume(dep);
       self.consume(value);
       self.refer_to_global();
     }
   }
+
+  /// Whether it refers to a global which doesn't exist in the target environment. Only `typeof`
+  /// is folded for these, because reading them throws.
+  pub fn is_absent_global(&self, node: &'a IdentifierReference<'a>) -> bool {
+    self.get_value_symbol(node).is_none()
+      && self.builtins.absent_globals.contains(node.name.as_str())
+  }
+
+  /// Ambient declarations (`declare const x: T`) are provided by the environment, so they are
+  /// treated as globals.
+  fn get_value_symbol(&self, node: &'a IdentifierReference<'a>) -> Option<SymbolId> {
+    let scoping = self.semantic().scoping();
+    let symbol = scoping.get_reference(node.reference_id()).symbol_id()?;
+    (!scoping.symbol_flags(symbol).contains(SymbolFlags::Ambient)).then_some(symbol)
+  }
+
+  fn get_commonjs_binding(&self, name: &str) -> Option<Entity<'a>> {
+    let commonjs = self.module_info().commonjs?;
+    match name {
+      "module" => Some(commonjs.module),
+      "exports" => Some(commonjs.exports),
+      "require" => Some(self.builtins.require),
+      _ => None,
+    }
+  }
 }
 
 impl<'a> Transformer<'a> {
@@ -93,9 +130,11 @@ impl<'a> Transformer<'a> {
     if need_val || self.is_referred(AstKind2::IdentifierReference(node)) {
       let IdentifierReference { span, name, .. } = node;
 
-      let reference = self.semantic.scoping().get_reference(node.reference_id());
-      if let Some(symbol) = reference.symbol_id() {
-        self.update_var_decl_state(symbol, false);
+      let scoping = self.semantic.scoping();
+      if let Some(symbol) = scoping.get_reference(node.reference_id()).symbol_id() {
+        if !scoping.symbol_flags(symbol).contains(SymbolFlags::Ambient) {
+          self.update_var_decl_state(symbol, false);
+        }
       }
 
       Some(self.ast_builder.alloc_identifier_reference(*span, *name))
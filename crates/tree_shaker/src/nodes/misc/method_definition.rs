@@ -1,31 +1,24 @@
-use oxc::ast::{
-  NONE,
+use oxc::{
   ast::{
-    BindingPatternKind, ClassElement, Function, MethodDefinition, MethodDefinitionKind,
-    PropertyDefinitionType,
+    NONE,
+    ast::{
+      AssignmentOperator, AssignmentTarget, BindingPatternKind, ClassElement, Expression, Function,
+      MethodDefinition, MethodDefinitionKind, PropertyDefinitionType, SimpleAssignmentTarget,
+      Statement,
+    },
   },
+  span::SPAN,
 };
 
-use crate::transformer::Transformer;
+use crate::{ast::AstKind2, transformer::Transformer};
 
 impl<'a> Transformer<'a> {
   pub fn transform_method_definition(
     &self,
     node: &'a MethodDefinition<'a>,
   ) -> Option<ClassElement<'a>> {
-    let MethodDefinition {
-      r#type,
-      span,
-      decorators,
-      key,
-      value,
-      kind,
-      computed,
-      r#static,
-      r#override,
-      optional,
-      accessibility,
-    } = node;
+    let MethodDefinition { r#type, span, decorators, key, value, kind, computed, r#static, .. } =
+      node;
 
     if let Some(mut transformed_value) = self.transform_function(value, false) {
       let key = if node.kind.is_constructor() {
@@ -37,6 +30,9 @@ impl<'a> Transformer<'a> {
       if *kind == MethodDefinitionKind::Set {
         self.patch_method_definition_params(value, &mut transformed_value);
       }
+      if *kind == MethodDefinitionKind::Constructor {
+        self.patch_parameter_properties(value, &mut transformed_value);
+      }
 
       Some(self.ast_builder.class_element_method_definition(
         *span,
@@ -47,9 +43,9 @@ impl<'a> Transformer<'a> {
         *kind,
         *computed,
         *r#static,
-        *r#override,
-        *optional,
-        *accessibility,
+        false,
+        false,
+        None,
       ))
     } else {
       let key = self.transform_property_key(key, false);
@@ -100,4 +96,44 @@ impl<'a> Transformer<'a> {
       ));
     }
   }
+
+  /// `constructor(private x) {}` is transformed to `constructor(x) { this.x = x }`
+  fn patch_parameter_properties(
+    &self,
+    original_node: &'a Function<'a>,
+    transformed_node: &mut Function<'a>,
+  ) {
+    let Some(body) = &mut transformed_node.body else {
+      return;
+    };
+    let mut index = body
+      .statements
+      .iter()
+      .position(|statement| {
+        matches!(statement, Statement::ExpressionStatement(node) if matches!(&node.expression, Expression::CallExpression(node) if node.callee.is_super()))
+      })
+      .map_or(0, |index| index + 1);
+    for param in &original_node.params.items {
+      if param.has_modifier() && self.is_referred(AstKind2::FormalParameter(param)) {
+        let id = param.pattern.get_binding_identifier().unwrap();
+        let assignment = self.ast_builder.expression_assignment(
+          param.span,
+          AssignmentOperator::Assign,
+          AssignmentTarget::from(SimpleAssignmentTarget::from(
+            self.ast_builder.member_expression_static(
+              SPAN,
+              self.ast_builder.expression_this(SPAN),
+              self.ast_builder.identifier_name(SPAN, id.name),
+              false,
+            ),
+          )),
+          self.ast_builder.expression_identifier(SPAN, id.name),
+        );
+        body
+          .statements
+          .insert(index, self.ast_builder.statement_expression(param.span, assignment));
+        index += 1;
+      }
+    }
+  }
 }
@@ -18,7 +18,7 @@ impl<'a> Analyzer<'a> {
       SimpleAssignmentTarget::AssignmentTargetIdentifier(node) => {
         (self.exec_identifier_reference_read(node), None)
       }
-      _ => unreachable!(),
+      _ => self.exec_ts_assignment_target_read(node),
     }
   }
 
@@ -35,7 +35,7 @@ impl<'a> Analyzer<'a> {
       SimpleAssignmentTarget::AssignmentTargetIdentifier(node) => {
         self.exec_identifier_reference_write(node, value)
       }
-      _ => unreachable!(),
+      _ => self.exec_ts_assignment_target_write(node, value, cache),
     }
   }
 }
@@ -53,7 +53,7 @@ impl<'a> Transformer<'a> {
       SimpleAssignmentTarget::AssignmentTargetIdentifier(node) => {
         self.transform_identifier_reference_read(node, need_val).map(Expression::Identifier)
       }
-      _ => unreachable!(),
+      _ => self.transform_ts_assignment_target_read(node, need_val),
     }
   }
 
@@ -68,7 +68,7 @@ impl<'a> Transformer<'a> {
       SimpleAssignmentTarget::AssignmentTargetIdentifier(node) => self
         .transform_identifier_reference_write(node)
         .map(SimpleAssignmentTarget::AssignmentTargetIdentifier),
-      _ => unreachable!(),
+      _ => self.transform_ts_assignment_target_write(node),
     }
   }
 }
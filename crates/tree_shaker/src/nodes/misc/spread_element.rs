@@ -5,7 +5,7 @@ use crate::{analyzer::Analyzer, ast::AstKind2, entity::Entity, transformer::Tran
 impl<'a> Analyzer<'a> {
   pub fn exec_spread_element(&mut self, node: &'a SpreadElement<'a>) -> Option<Entity<'a>> {
     let argument = self.exec_expression(&node.argument);
-    argument.iterate_result_union(self, AstKind2::SpreadElement(node))
+    argument.iterate_result_union(self, AstKind2::SpreadElement(node), false)
   }
 }
 
@@ -2,3 +2,4 @@ mod expr;
 mod jsx;
 mod misc;
 mod stmt;
+mod ts;
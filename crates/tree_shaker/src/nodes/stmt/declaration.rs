@@ -1,9 +1,12 @@
 use oxc::ast::ast::Declaration;
 
-use crate::{analyzer::Analyzer, transformer::Transformer};
+use crate::{analyzer::Analyzer, nodes::ts::is_type_only_declaration, transformer::Transformer};
 
 impl<'a> Analyzer<'a> {
   pub fn declare_declaration(&mut self, node: &'a Declaration<'a>, exporting: bool) {
+    if is_type_only_declaration(node, self.semantic().scoping()) {
+      return;
+    }
     match node {
       Declaration::VariableDeclaration(node) => {
         self.declare_variable_declaration(node, exporting);
@@ -14,11 +17,23 @@ impl<'a> Analyzer<'a> {
       Declaration::ClassDeclaration(node) => {
         self.declare_class(node, exporting);
       }
+      Declaration::TSEnumDeclaration(node) => {
+        self.declare_ts_enum_declaration(node, exporting);
+      }
+      Declaration::TSModuleDeclaration(node) => {
+        self.declare_ts_module_declaration(node, exporting);
+      }
+      Declaration::TSImportEqualsDeclaration(node) => {
+        self.declare_ts_import_equals_declaration(node, exporting);
+      }
       _ => unreachable!(),
     }
   }
 
   pub fn init_declaration(&mut self, node: &'a Declaration<'a>) {
+    if is_type_only_declaration(node, self.semantic().scoping()) {
+      return;
+    }
     match node {
       Declaration::VariableDeclaration(node) => {
         self.init_variable_declaration(node, None);
@@ -29,6 +44,15 @@ impl<'a> Analyzer<'a> {
       Declaration::ClassDeclaration(node) => {
         self.init_class(node);
       }
+      Declaration::TSEnumDeclaration(node) => {
+        self.init_ts_enum_declaration(node);
+      }
+      Declaration::TSModuleDeclaration(node) => {
+        self.init_ts_module_declaration(node);
+      }
+      Declaration::TSImportEqualsDeclaration(node) => {
+        self.init_ts_import_equals_declaration(node);
+      }
       _ => unreachable!(),
     }
   }
@@ -36,6 +60,9 @@ impl<'a> Analyzer<'a> {
 
 impl<'a> Transformer<'a> {
   pub fn transform_declaration(&self, node: &'a Declaration<'a>) -> Option<Declaration<'a>> {
+    if is_type_only_declaration(node, self.semantic.scoping()) {
+      return None;
+    }
     match node {
       Declaration::VariableDeclaration(node) => {
         self.transform_variable_declaration(node).map(Declaration::VariableDeclaration)
@@ -46,6 +73,11 @@ impl<'a> Transformer<'a> {
       Declaration::ClassDeclaration(node) => {
         self.transform_class(node, false).map(Declaration::ClassDeclaration)
       }
+      Declaration::TSEnumDeclaration(node) => self.transform_ts_enum_declaration(node),
+      Declaration::TSModuleDeclaration(node) => self.transform_ts_module_declaration(node),
+      Declaration::TSImportEqualsDeclaration(node) => {
+        self.transform_ts_import_equals_declaration(node)
+      }
       _ => unreachable!(),
     }
   }
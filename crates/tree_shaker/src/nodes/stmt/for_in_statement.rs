@@ -3,16 +3,24 @@ use oxc::{
   span::GetSpan,
 };
 
-use crate::{analyzer::Analyzer, ast::AstKind2, scope::CfScopeKind, transformer::Transformer};
+use crate::{
+  analyzer::Analyzer, ast::AstKind2, scope::CfScopeKind, transformer::Transformer,
+  value::TypeofResult,
+};
 
 impl<'a> Analyzer<'a> {
   pub fn exec_for_in_statement(&mut self, node: &'a ForInStatement<'a>) {
     let right = self.exec_expression(&node.right);
 
-    if let Some(keys) = right.get_own_keys(self) {
+    let keys_dep = self.dep((AstKind2::ForInStatement(node), right));
+    if let Some(keys) = right.get_own_keys(self, keys_dep) {
       let dep = self.factory.dep((right.get_shallow_dep(self), AstKind2::ForInStatement(node)));
       self.push_cf_scope_with_deps(CfScopeKind::LoopBreak, self.factory.vec1(dep), false);
       for (definite, key) in keys {
+        if !key.test_typeof().contains(TypeofResult::String) {
+          // Symbol keys are skipped
+          continue;
+        }
         self.push_cf_scope_with_deps(
           CfScopeKind::LoopContinue,
           self.factory.vec1(self.factory.always_mangable_dep(key)),
@@ -60,7 +68,7 @@ impl<'a> Transformer<'a> {
     let left = if need_loop { self.transform_for_statement_left(left) } else { None };
     let body = if need_loop { self.transform_statement(body) } else { None };
 
-    if left.is_none() && body.is_none() {
+    if !need_loop {
       return self
         .transform_expression(right, false)
         .map(|expr| self.ast_builder.statement_expression(*span, expr));
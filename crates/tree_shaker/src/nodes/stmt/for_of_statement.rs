@@ -18,10 +18,13 @@ impl<'a> Analyzer<'a> {
 
     self.declare_for_statement_left(&node.left);
 
-    let Some(iterated) = right.iterate_result_union(self, AstKind2::ForOfStatement(node)) else {
+    let Some(iterated) = right.iterate_result_union(self, AstKind2::ForOfStatement(node), true)
+    else {
       return;
     };
 
+    // The steps have side effects if the loop is already referred, so exiting early matters
+    let effectful_steps = self.is_referred(AstKind2::ForOfStatement(node));
     let dep = self.dep((AstKind2::ForOfStatement(node), right));
 
     self.push_cf_scope_with_deps(CfScopeKind::LoopBreak, self.factory.vec1(dep), false);
@@ -32,6 +35,11 @@ impl<'a> Analyzer<'a> {
       analyzer.push_cf_scope(CfScopeKind::LoopContinue, true);
       analyzer.exec_statement(&node.body);
       analyzer.pop_cf_scope();
+
+      if effectful_steps {
+        // The next step
+        analyzer.refer_to_global();
+      }
     });
     self.pop_cf_scope();
   }
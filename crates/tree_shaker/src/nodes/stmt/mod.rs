@@ -92,6 +92,7 @@ impl<'a> Transformer<'a> {
       match_declaration!(Statement) => {
         self.transform_declaration(node.to_declaration()).map(Statement::from)
       }
+      Statement::TSExportAssignment(node) => self.transform_ts_export_assignment(node),
       match_module_declaration!(Statement) => {
         self.transform_module_declaration(node.to_module_declaration()).map(Statement::from)
       }
@@ -1,28 +1,40 @@
-use oxc::ast::ast::{
-  ExportDefaultDeclaration, ExportDefaultDeclarationKind, ExportNamedDeclaration,
-  ImportDeclaration, ImportDeclarationSpecifier, ImportDefaultSpecifier, ImportNamespaceSpecifier,
-  ImportSpecifier, ModuleDeclaration, ModuleExportName, PropertyKind,
+use oxc::{
+  ast::ast::{
+    ExportDefaultDeclaration, ExportDefaultDeclarationKind, ExportNamedDeclaration,
+    ImportDeclaration, ImportDeclarationSpecifier, ImportDefaultSpecifier,
+    ImportNamespaceSpecifier, ImportSpecifier, ModuleDeclaration, ModuleExportName, Statement,
+  },
+  semantic::{Scoping, SymbolFlags, SymbolId},
 };
 
 use crate::{
   Analyzer, ast::DeclarationKind, module::NamedExport, transformer::Transformer,
-  utils::ast::AstKind2, value::ObjectPrototype,
+  utils::ast::AstKind2,
 };
 
 impl<'a> Analyzer<'a> {
   pub fn declare_module_declaration(&mut self, node: &'a ModuleDeclaration<'a>) {
     match node {
       ModuleDeclaration::ImportDeclaration(node) => {
+        if node.import_kind.is_type() {
+          return;
+        }
         if let Some(specifiers) = &node.specifiers {
           for specifier in specifiers {
-            self.declare_binding_identifier(specifier.local(), false, DeclarationKind::Import);
+            if !is_type_import_specifier(specifier) {
+              self.declare_binding_identifier(specifier.local(), false, DeclarationKind::Import);
+            }
           }
         }
       }
       ModuleDeclaration::ExportNamedDeclaration(node) => {
+        if node.export_kind.is_type() {
+          return;
+        }
         if let Some(source) = &node.source {
+          let specifiers = node.specifiers.iter().filter(|s| s.export_kind.is_value());
           if let Some(known) = self.builtins.get_known_module(&source.value) {
-            for specifier in &node.specifiers {
+            for specifier in specifiers {
               let value = known.namespace.get_property(
                 self,
                 AstKind2::ExportSpecifier(specifier),
@@ -34,7 +46,7 @@ impl<'a> Analyzer<'a> {
                 .insert(specifier.exported.name(), NamedExport::Value(value));
             }
           } else if let Some(module) = self.resolve_and_import_module(&source.value) {
-            for specifier in &node.specifiers {
+            for specifier in specifiers {
               let dep = self.dep(AstKind2::ExportSpecifier(specifier));
               self.module_info_mut().named_exports.insert(
                 specifier.exported.name(),
@@ -42,7 +54,7 @@ impl<'a> Analyzer<'a> {
               );
             }
           } else {
-            for specifier in &node.specifiers {
+            for specifier in specifiers {
               let value = self.factory.computed_unknown(AstKind2::ExportSpecifier(specifier));
               self
                 .module_info_mut()
@@ -56,11 +68,19 @@ impl<'a> Analyzer<'a> {
           self.declare_declaration(declaration, true);
         }
         for specifier in &node.specifiers {
+          if specifier.export_kind.is_type() {
+            continue;
+          }
           match &specifier.local {
             ModuleExportName::IdentifierReference(node) => {
               let dep = self.dep(AstKind2::ExportSpecifier(specifier));
-              let reference = self.semantic().scoping().get_reference(node.reference_id());
-              if let Some(symbol) = reference.symbol_id() {
+              let scoping = self.semantic().scoping();
+              let symbol = scoping.get_reference(node.reference_id()).symbol_id();
+              if symbol.is_some_and(|symbol| !is_value_symbol(scoping, symbol)) {
+                // Types are erased
+                continue;
+              }
+              if let Some(symbol) = symbol {
                 let scope = self.scoping.variable.current_id();
                 self
                   .module_info_mut()
@@ -77,8 +97,8 @@ impl<'a> Analyzer<'a> {
       ModuleDeclaration::ExportDefaultDeclaration(node) => {
         match &node.declaration {
           ExportDefaultDeclarationKind::FunctionDeclaration(node) => {
-            if node.id.is_none() {
-              // Patch `export default function(){}`
+            if node.id.is_none() || node.is_typescript_syntax() {
+              // Patch `export default function(){}`, and skip overloads
               return;
             }
             // Pass `exporting` as `false` because it is actually used as an expression
@@ -95,19 +115,64 @@ impl<'a> Analyzer<'a> {
           _expr => {}
         };
       }
-      ModuleDeclaration::ExportAllDeclaration(_node) => {
-        // Nothing to do
+      ModuleDeclaration::ExportAllDeclaration(node) => {
+        if node.export_kind.is_type() {
+          return;
+        }
+        let dep = self.dep(AstKind2::ExportAllDeclaration(node));
+        let known = self.builtins.get_known_module(&node.source.value);
+        let resolved =
+          if known.is_none() { self.resolve_and_import_module(&node.source.value) } else { None };
+        if let Some(exported) = &node.exported {
+          let named_export = if let Some(known) = known {
+            NamedExport::Value(self.factory.computed(known.namespace, dep))
+          } else if let Some(resolved) = resolved {
+            NamedExport::Namespace(resolved, dep)
+          } else {
+            // The module can't be tree-shaken
+            self.consume(dep);
+            NamedExport::Value(self.factory.unknown)
+          };
+          self.module_info_mut().named_exports.insert(exported.name(), named_export);
+        } else if let Some(resolved) = resolved {
+          if self.modules.modules[resolved].side_effects {
+            self.consume(dep);
+          }
+          self.module_info_mut().star_exports.push((resolved, dep));
+        } else {
+          // The exported names are unknown
+          self.consume(dep);
+          self.module_info_mut().unknown_star_exports = true;
+        }
+      }
+      ModuleDeclaration::TSExportAssignment(_node) => {
+        // Not hoisted
+      }
+      ModuleDeclaration::TSNamespaceExportDeclaration(_node) => {
+        // Only for type declarations
       }
-      _ => unreachable!(),
     }
   }
 
   pub fn init_import_declaration(&mut self, node: &'a ImportDeclaration<'a>) {
-    if let Some(specifiers) = &node.specifiers {
-      let name = node.source.value.as_str();
-      let known = self.builtins.get_known_module(name);
-      let resolved = if known.is_none() { self.resolve_and_import_module(name) } else { None };
+    if node.import_kind.is_type() {
+      // `import type` doesn't load the module
+      return;
+    }
+    let name = node.source.value.as_str();
+    let known = self.builtins.get_known_module(name);
+    let resolved = if known.is_none() { self.resolve_and_import_module(name) } else { None };
 
+    let side_effects = match resolved {
+      Some(resolved) => self.modules.modules[resolved].side_effects,
+      // Side-effect-only imports of external modules are kept
+      None => node.specifiers.is_none(),
+    };
+    if side_effects {
+      self.consume(AstKind2::ImportDeclaration(node));
+    }
+
+    if let Some(specifiers) = &node.specifiers {
       if let Some(resolved) = resolved {
         if self.module_stack.contains(&resolved) {
           // Circular dependency
@@ -118,7 +183,7 @@ impl<'a> Analyzer<'a> {
         }
       }
 
-      for specifier in specifiers {
+      for specifier in specifiers.iter().filter(|s| !is_type_import_specifier(s)) {
         let value = if let Some(known) = known {
           match specifier {
             ImportDeclarationSpecifier::ImportDefaultSpecifier(_node) => known.default,
@@ -129,36 +194,15 @@ impl<'a> Analyzer<'a> {
             }
           }
         } else if let Some(resolved) = resolved {
-          let module_info = &self.modules.modules[resolved];
           match specifier {
             ImportDeclarationSpecifier::ImportDefaultSpecifier(_node) => {
-              module_info.default_export.unwrap_or(self.factory.unknown)
+              self.get_export_value(resolved, "default".into())
             }
             ImportDeclarationSpecifier::ImportNamespaceSpecifier(_node) => {
-              // FIXME: This is not accurate
-              let named_exports = module_info.named_exports.clone();
-              let object = self
-                .new_empty_object(ObjectPrototype::Builtin(&self.builtins.prototypes.null), None);
-              for (key, named_export) in named_exports {
-                let value = self.get_named_export_value(named_export);
-                object.init_property(
-                  self,
-                  PropertyKind::Init,
-                  self.factory.string(key.as_str()),
-                  value,
-                  true,
-                );
-              }
-              object.into()
+              self.get_module_namespace(resolved)
             }
             ImportDeclarationSpecifier::ImportSpecifier(node) => {
-              if let Some(named_export) =
-                module_info.named_exports.get(&node.imported.name()).copied()
-              {
-                self.get_named_export_value(named_export)
-              } else {
-                self.factory.unknown
-              }
+              self.get_export_value(resolved, node.imported.name())
             }
           }
         } else {
@@ -175,8 +219,8 @@ impl<'a> Analyzer<'a> {
         // Hoisted
       }
       ModuleDeclaration::ExportNamedDeclaration(node) => {
-        if node.source.is_some() {
-          // Re-exports. Nothing to do.
+        if node.source.is_some() || node.export_kind.is_type() {
+          // Re-exports or types. Nothing to do.
           return;
         }
         if let Some(declaration) = &node.declaration {
@@ -185,6 +229,12 @@ impl<'a> Analyzer<'a> {
       }
       ModuleDeclaration::ExportDefaultDeclaration(node) => {
         let value = match &node.declaration {
+          ExportDefaultDeclarationKind::TSInterfaceDeclaration(_node) => return,
+          ExportDefaultDeclarationKind::FunctionDeclaration(node)
+            if node.is_typescript_syntax() =>
+          {
+            return;
+          }
           ExportDefaultDeclarationKind::FunctionDeclaration(node) => self.exec_function(node),
           ExportDefaultDeclarationKind::ClassDeclaration(node) => {
             if node.id.is_none() {
@@ -202,16 +252,36 @@ impl<'a> Analyzer<'a> {
         self.module_info_mut().default_export = Some(value);
       }
       ModuleDeclaration::ExportAllDeclaration(_node) => {
-        if self.module_stack.len() > 1 {
-          todo!("ExportAllDeclaration");
-        }
+        // Hoisted
       }
-      _ => unreachable!(),
+      ModuleDeclaration::TSExportAssignment(node) => {
+        self.exec_ts_export_assignment(node);
+      }
+      ModuleDeclaration::TSNamespaceExportDeclaration(_node) => {}
     }
   }
 }
 
 impl<'a> Transformer<'a> {
+  pub fn collect_loaded_modules(&self, body: &'a [Statement<'a>]) {
+    let mut loaded_modules = self.loaded_modules.borrow_mut();
+    for statement in body {
+      if let Statement::ImportDeclaration(node) = statement {
+        if node.specifiers.as_ref().is_some_and(|specifiers| {
+          specifiers.iter().any(|s| self.is_referred(AstKind2::BindingIdentifier(s.local())))
+        }) {
+          loaded_modules.insert(node.source.value);
+        }
+      }
+    }
+  }
+
+  /// Whether to keep `import 'source'` for the side effects of the module
+  fn need_side_effect_import(&self, node: &'a ImportDeclaration<'a>) -> bool {
+    self.is_referred(AstKind2::ImportDeclaration(node))
+      && self.loaded_modules.borrow_mut().insert(node.source.value)
+  }
+
   pub fn transform_module_declaration(
     &self,
     node: &'a ModuleDeclaration<'a>,
@@ -220,12 +290,18 @@ impl<'a> Transformer<'a> {
       ModuleDeclaration::ImportDeclaration(node) => {
         let ImportDeclaration { span, specifiers, source, with_clause, import_kind, phase } =
           node.as_ref();
+        if import_kind.is_type() {
+          return None;
+        }
         if let Some(specifiers) = specifiers {
           let mut transformed_specifiers = self.ast_builder.vec();
           for specifier in specifiers {
             let specifier = match specifier {
               ImportDeclarationSpecifier::ImportSpecifier(node) => {
                 let ImportSpecifier { span, local, imported, import_kind } = node.as_ref();
+                if import_kind.is_type() {
+                  continue;
+                }
                 self.transform_binding_identifier(local).map(|local| {
                   self.ast_builder.import_declaration_specifier_import_specifier(
                     *span,
@@ -256,9 +332,18 @@ impl<'a> Transformer<'a> {
               transformed_specifiers.push(specifier);
             }
           }
-          // FIXME: side effect in module
           if transformed_specifiers.is_empty() {
-            None
+            // Only keep the side effects of the module
+            self.need_side_effect_import(node).then(|| {
+              self.ast_builder.module_declaration_import_declaration(
+                *span,
+                None,
+                source.clone(),
+                *phase,
+                self.clone_node(with_clause),
+                *import_kind,
+              )
+            })
           } else {
             Some(self.ast_builder.module_declaration_import_declaration(
               *span,
@@ -270,14 +355,16 @@ impl<'a> Transformer<'a> {
             ))
           }
         } else {
-          Some(self.ast_builder.module_declaration_import_declaration(
-            *span,
-            None,
-            source.clone(),
-            *phase,
-            self.clone_node(with_clause),
-            *import_kind,
-          ))
+          self.need_side_effect_import(node).then(|| {
+            self.ast_builder.module_declaration_import_declaration(
+              *span,
+              None,
+              source.clone(),
+              *phase,
+              self.clone_node(with_clause),
+              *import_kind,
+            )
+          })
         }
       }
       ModuleDeclaration::ExportNamedDeclaration(node) => {
@@ -289,9 +376,28 @@ impl<'a> Transformer<'a> {
           export_kind,
           with_clause,
         } = node.as_ref();
+        if export_kind.is_type() {
+          return None;
+        }
         if source.is_some() {
-          // Re-exports. Nothing to do.
-          return Some(ModuleDeclaration::ExportNamedDeclaration(self.clone_node(node)));
+          // Re-exports. Only types are removed.
+          let mut transformed_specifiers = self.ast_builder.vec();
+          for specifier in specifiers {
+            if specifier.export_kind.is_value() {
+              transformed_specifiers.push(self.clone_node(specifier));
+            }
+          }
+          if transformed_specifiers.is_empty() && !specifiers.is_empty() {
+            return None;
+          }
+          return Some(self.ast_builder.module_declaration_export_named_declaration(
+            *span,
+            None,
+            transformed_specifiers,
+            source.clone(),
+            *export_kind,
+            self.clone_node(with_clause),
+          ));
         }
         let declaration = declaration.as_ref().and_then(|d| self.transform_declaration(d));
         let mut transformed_specifiers = self.ast_builder.vec();
@@ -315,6 +421,12 @@ impl<'a> Transformer<'a> {
       ModuleDeclaration::ExportDefaultDeclaration(node) => {
         let ExportDefaultDeclaration { span, declaration, exported } = node.as_ref();
         let declaration = match declaration {
+          ExportDefaultDeclarationKind::TSInterfaceDeclaration(_node) => return None,
+          ExportDefaultDeclarationKind::FunctionDeclaration(node)
+            if node.is_typescript_syntax() =>
+          {
+            return None;
+          }
           ExportDefaultDeclarationKind::FunctionDeclaration(node) => {
             ExportDefaultDeclarationKind::FunctionDeclaration(
               self.transform_function(node, true).unwrap(),
@@ -334,9 +446,25 @@ impl<'a> Transformer<'a> {
         ))
       }
       ModuleDeclaration::ExportAllDeclaration(node) => {
-        Some(ModuleDeclaration::ExportAllDeclaration(self.clone_node(node)))
+        if self.is_referred(AstKind2::ExportAllDeclaration(node)) {
+          Some(ModuleDeclaration::ExportAllDeclaration(self.clone_node(node)))
+        } else {
+          None
+        }
       }
-      _ => unreachable!(),
+      ModuleDeclaration::TSExportAssignment(_node) => unreachable!(),
+      ModuleDeclaration::TSNamespaceExportDeclaration(_node) => None,
     }
   }
 }
+
+fn is_type_import_specifier(node: &ImportDeclarationSpecifier) -> bool {
+  matches!(node, ImportDeclarationSpecifier::ImportSpecifier(node) if node.import_kind.is_type())
+}
+
+/// Whether the symbol exists at runtime
+fn is_value_symbol(scoping: &Scoping, symbol: SymbolId) -> bool {
+  let flags = scoping.symbol_flags(symbol);
+  flags.intersects(SymbolFlags::Value | SymbolFlags::Import)
+    && !flags.contains(SymbolFlags::Ambient)
+}
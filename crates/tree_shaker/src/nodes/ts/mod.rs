@@ -0,0 +1,31 @@
+mod ts_enum_declaration;
+mod ts_export_assignment;
+mod ts_expression;
+mod ts_import_equals_declaration;
+mod ts_module_declaration;
+
+use oxc::{
+  ast::ast::{Declaration, TSModuleDeclarationKind, TSModuleDeclarationName},
+  semantic::{Scoping, SymbolFlags},
+};
+
+/// Declarations which only exist in the type system, including `declare`d ones, function overloads,
+/// and namespaces that contain only types.
+pub fn is_type_only_declaration(node: &Declaration, scoping: &Scoping) -> bool {
+  match node {
+    Declaration::FunctionDeclaration(node) => node.is_typescript_syntax(),
+    Declaration::TSTypeAliasDeclaration(_) | Declaration::TSInterfaceDeclaration(_) => true,
+    Declaration::TSModuleDeclaration(node) => {
+      node.declare
+        || node.kind == TSModuleDeclarationKind::Global
+        || match &node.id {
+          TSModuleDeclarationName::Identifier(id) => {
+            !scoping.symbol_flags(id.symbol_id()).contains(SymbolFlags::ValueModule)
+          }
+          TSModuleDeclarationName::StringLiteral(_) => true,
+        }
+    }
+    Declaration::TSImportEqualsDeclaration(node) => node.import_kind.is_type(),
+    node => node.declare(),
+  }
+}
@@ -0,0 +1,192 @@
+use oxc::{
+  ast::{
+    AstKind,
+    ast::{
+      AssignmentOperator, AssignmentTarget, BinaryOperator, Declaration, Expression,
+      IdentifierReference, NumberBase, SimpleAssignmentTarget, TSEnumDeclaration,
+    },
+  },
+  semantic::SymbolFlags,
+  span::{Atom, SPAN},
+};
+
+use crate::{
+  analyzer::Analyzer,
+  ast::{AstKind2, DeclarationKind},
+  folding::FoldingState,
+  transformer::Transformer,
+  value::LiteralValue,
+};
+
+#[derive(Default)]
+struct Data<'a> {
+  /// The value of a member without initializer
+  auto_value: FoldingState<'a>,
+  /// Whether `E[E["A"] = value] = "A"` is needed
+  reverse_mapping: bool,
+}
+
+impl<'a> Analyzer<'a> {
+  pub fn declare_ts_enum_declaration(&mut self, node: &'a TSEnumDeclaration<'a>, exporting: bool) {
+    self.declare_binding_identifier(&node.id, exporting, DeclarationKind::Var);
+  }
+
+  pub fn init_ts_enum_declaration(&mut self, node: &'a TSEnumDeclaration<'a>) {
+    let object = self.init_ts_namespace_object(&node.id);
+    let scope = node.scope_id.get().unwrap();
+
+    let mut last_value = None;
+    for member in &node.body.members {
+      let name = member.id.static_name();
+      let data = self.load_data::<Data>(AstKind2::TSEnumMember(member));
+
+      let value = if let Some(initializer) = &member.initializer {
+        self.exec_expression(initializer)
+      } else {
+        let value = match last_value {
+          Some(last_value) => self.op_add(last_value, self.factory.number(1.0, None)),
+          None => self.factory.number(0.0, None),
+        };
+        let literal = value.get_literal(self).filter(|literal| literal.can_build_expr(self));
+        data.auto_value = match (data.auto_value, literal) {
+          (FoldingState::Initial, Some(literal)) => FoldingState::Foldable(literal),
+          (FoldingState::Foldable(old), Some(literal)) if old == literal => {
+            FoldingState::Foldable(literal)
+          }
+          _ => FoldingState::UnFoldable,
+        };
+        value
+      };
+
+      let key = self.factory.string(name.as_str());
+      object.set_property(self, self.factory.no_dep, key, value);
+      // String members don't have reverse mappings
+      if !matches!(value.get_literal(self), Some(LiteralValue::String(..))) {
+        data.reverse_mapping = true;
+        let reverse_key = value.get_to_property_key(self);
+        object.set_property(self, self.factory.no_dep, reverse_key, key);
+      }
+
+      // Members can be referenced by name in later initializers
+      if let Some(symbol) = self.semantic().scoping().get_binding(scope, &name) {
+        let decl_node = AstKind2::TSEnumMember(member);
+        self.declare_symbol(symbol, decl_node, false, DeclarationKind::Const, None);
+        self.init_symbol(symbol, Some(value), decl_node);
+      }
+
+      last_value = Some(value);
+    }
+  }
+}
+
+impl<'a> Transformer<'a> {
+  pub fn transform_ts_enum_declaration(
+    &self,
+    node: &'a TSEnumDeclaration<'a>,
+  ) -> Option<Declaration<'a>> {
+    let TSEnumDeclaration { span, id, body, .. } = node;
+
+    if !self.is_referred(AstKind2::BindingIdentifier(id)) {
+      let has_effect = body.members.iter().any(|member| {
+        member
+          .initializer
+          .as_ref()
+          .is_some_and(|init| self.transform_expression(init, false).is_some())
+      });
+      if !has_effect {
+        return None;
+      }
+    }
+
+    let name = id.name;
+    let mut statements = self.ast_builder.vec();
+    let mut last_member = None;
+    for member in &body.members {
+      let member_name = member.id.static_name();
+      let data = self.get_data::<Data>(AstKind2::TSEnumMember(member));
+
+      let value = if let Some(initializer) = &member.initializer {
+        self.transform_expression(initializer, true).unwrap()
+      } else if let Some(literal) = data.auto_value.get_foldable_literal() {
+        literal.build_expr(self, member.span, None)
+      } else if let Some(last_member) = last_member {
+        self.ast_builder.expression_binary(
+          SPAN,
+          self.build_ts_enum_member_access(name, last_member),
+          BinaryOperator::Addition,
+          self.ast_builder.expression_numeric_literal(SPAN, 1.0, None, NumberBase::Decimal),
+        )
+      } else {
+        self.ast_builder.expression_numeric_literal(SPAN, 0.0, None, NumberBase::Decimal)
+      };
+
+      // E["A"] = value
+      let mut expression = self.build_ts_enum_member_assignment(
+        name,
+        self.ast_builder.expression_string_literal(SPAN, member_name, None),
+        value,
+      );
+      if data.reverse_mapping {
+        // E[E["A"] = value] = "A"
+        expression = self.build_ts_enum_member_assignment(
+          name,
+          expression,
+          self.ast_builder.expression_string_literal(SPAN, member_name, None),
+        );
+      }
+      statements.push(self.ast_builder.statement_expression(member.span, expression));
+
+      last_member = Some(member_name);
+    }
+
+    Some(self.build_ts_namespace_declaration(*span, name, statements))
+  }
+
+  /// Enum members referenced by name inside the enum are rewritten to `E.A`.
+  pub fn transform_ts_enum_member_reference(
+    &self,
+    node: &'a IdentifierReference<'a>,
+    need_val: bool,
+  ) -> Option<Option<Expression<'a>>> {
+    let scoping = self.semantic.scoping();
+    let symbol = scoping.get_reference(node.reference_id()).symbol_id()?;
+    if !scoping.symbol_flags(symbol).contains(SymbolFlags::EnumMember) {
+      return None;
+    }
+    let scope_node = scoping.get_node_id(scoping.symbol_scope_id(symbol));
+    let AstKind::TSEnumDeclaration(enum_node) = self.semantic.nodes().kind(scope_node) else {
+      unreachable!()
+    };
+    Some(need_val.then(|| self.build_ts_enum_member_access(enum_node.id.name, node.name)))
+  }
+
+  fn build_ts_enum_member_access(&self, name: Atom<'a>, member: Atom<'a>) -> Expression<'a> {
+    Expression::from(self.ast_builder.member_expression_computed(
+      SPAN,
+      self.ast_builder.expression_identifier(SPAN, name),
+      self.ast_builder.expression_string_literal(SPAN, member, None),
+      false,
+    ))
+  }
+
+  fn build_ts_enum_member_assignment(
+    &self,
+    name: Atom<'a>,
+    key: Expression<'a>,
+    value: Expression<'a>,
+  ) -> Expression<'a> {
+    self.ast_builder.expression_assignment(
+      SPAN,
+      AssignmentOperator::Assign,
+      AssignmentTarget::from(SimpleAssignmentTarget::from(
+        self.ast_builder.member_expression_computed(
+          SPAN,
+          self.ast_builder.expression_identifier(SPAN, name),
+          key,
+          false,
+        ),
+      )),
+      value,
+    )
+  }
+}
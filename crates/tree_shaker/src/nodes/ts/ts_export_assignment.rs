@@ -0,0 +1,54 @@
+use oxc::{
+  ast::ast::{
+    AssignmentOperator, AssignmentTarget, SimpleAssignmentTarget, Statement, TSExportAssignment,
+  },
+  span::SPAN,
+};
+
+use crate::{analyzer::Analyzer, ast::AstKind2, transformer::Transformer};
+
+impl<'a> Analyzer<'a> {
+  /// `export = value` is the same as `module.exports = value`
+  pub fn exec_ts_export_assignment(&mut self, node: &'a TSExportAssignment<'a>) {
+    let dep = AstKind2::TSExportAssignment(node);
+    let value = self.exec_expression(&node.expression);
+    if let Some(commonjs) = self.module_info().commonjs {
+      let key = self.factory.string("exports");
+      commonjs.module.set_property(self, dep, key, value);
+    } else {
+      self.consume((dep, value));
+    }
+  }
+}
+
+impl<'a> Transformer<'a> {
+  pub fn transform_ts_export_assignment(
+    &self,
+    node: &'a TSExportAssignment<'a>,
+  ) -> Option<Statement<'a>> {
+    let TSExportAssignment { span, expression } = node;
+
+    let need_assignment = self.is_referred(AstKind2::TSExportAssignment(node));
+    let expression = self.transform_expression(expression, need_assignment)?;
+    Some(self.ast_builder.statement_expression(
+      *span,
+      if need_assignment {
+        self.ast_builder.expression_assignment(
+          *span,
+          AssignmentOperator::Assign,
+          AssignmentTarget::from(SimpleAssignmentTarget::from(
+            self.ast_builder.member_expression_static(
+              SPAN,
+              self.ast_builder.expression_identifier(SPAN, "module"),
+              self.ast_builder.identifier_name(SPAN, "exports"),
+              false,
+            ),
+          )),
+          expression,
+        )
+      } else {
+        expression
+      },
+    ))
+  }
+}
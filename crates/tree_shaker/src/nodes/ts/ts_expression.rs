@@ -0,0 +1,110 @@
+use oxc::ast::{
+  ast::{Expression, SimpleAssignmentTarget},
+  match_member_expression,
+};
+
+use crate::{analyzer::Analyzer, entity::Entity, transformer::Transformer};
+
+impl<'a> Analyzer<'a> {
+  /// `a as T`, `a satisfies T`, `<T>a`, `a!` and `f<T>` are the same as `a` at runtime
+  pub fn exec_ts_expression(&mut self, node: &'a Expression<'a>) -> Entity<'a> {
+    self.exec_expression(get_ts_expression_inner(node))
+  }
+
+  /// `(a as T) = 1`, `a! += 1`, etc.
+  pub fn exec_ts_assignment_target_read(
+    &mut self,
+    node: &'a SimpleAssignmentTarget<'a>,
+  ) -> (Entity<'a>, Option<(Entity<'a>, Entity<'a>)>) {
+    let node = get_ts_assignment_target_inner(node);
+    match node {
+      match_member_expression!(Expression) => {
+        let (value, cache) = self.exec_member_expression_read(node.to_member_expression(), true);
+        (value, Some(cache))
+      }
+      Expression::Identifier(node) => (self.exec_identifier_reference_read(node), None),
+      _ => unreachable!(),
+    }
+  }
+
+  pub fn exec_ts_assignment_target_write(
+    &mut self,
+    node: &'a SimpleAssignmentTarget<'a>,
+    value: Entity<'a>,
+    cache: Option<(Entity<'a>, Entity<'a>)>,
+  ) {
+    let node = get_ts_assignment_target_inner(node);
+    match node {
+      match_member_expression!(Expression) => {
+        self.exec_member_expression_write(node.to_member_expression(), value, cache)
+      }
+      Expression::Identifier(node) => self.exec_identifier_reference_write(node, value),
+      _ => unreachable!(),
+    }
+  }
+}
+
+impl<'a> Transformer<'a> {
+  pub fn transform_ts_expression(
+    &self,
+    node: &'a Expression<'a>,
+    need_val: bool,
+  ) -> Option<Expression<'a>> {
+    self.transform_expression(get_ts_expression_inner(node), need_val)
+  }
+
+  pub fn transform_ts_assignment_target_read(
+    &self,
+    node: &'a SimpleAssignmentTarget<'a>,
+    need_val: bool,
+  ) -> Option<Expression<'a>> {
+    let node = get_ts_assignment_target_inner(node);
+    match node {
+      match_member_expression!(Expression) => {
+        self.transform_member_expression_read(node.to_member_expression(), need_val)
+      }
+      Expression::Identifier(node) => {
+        self.transform_identifier_reference_read(node, need_val).map(Expression::Identifier)
+      }
+      _ => unreachable!(),
+    }
+  }
+
+  pub fn transform_ts_assignment_target_write(
+    &self,
+    node: &'a SimpleAssignmentTarget<'a>,
+  ) -> Option<SimpleAssignmentTarget<'a>> {
+    let node = get_ts_assignment_target_inner(node);
+    match node {
+      match_member_expression!(Expression) => self
+        .transform_member_expression_write(node.to_member_expression())
+        .map(SimpleAssignmentTarget::from),
+      Expression::Identifier(node) => self
+        .transform_identifier_reference_write(node)
+        .map(SimpleAssignmentTarget::AssignmentTargetIdentifier),
+      _ => unreachable!(),
+    }
+  }
+}
+
+fn get_ts_expression_inner<'a>(node: &'a Expression<'a>) -> &'a Expression<'a> {
+  match node {
+    Expression::TSAsExpression(node) => &node.expression,
+    Expression::TSSatisfiesExpression(node) => &node.expression,
+    Expression::TSTypeAssertion(node) => &node.expression,
+    Expression::TSNonNullExpression(node) => &node.expression,
+    Expression::TSInstantiationExpression(node) => &node.expression,
+    _ => unreachable!(),
+  }
+}
+
+fn get_ts_assignment_target_inner<'a>(node: &'a SimpleAssignmentTarget<'a>) -> &'a Expression<'a> {
+  let expression = match node {
+    SimpleAssignmentTarget::TSAsExpression(node) => &node.expression,
+    SimpleAssignmentTarget::TSSatisfiesExpression(node) => &node.expression,
+    SimpleAssignmentTarget::TSTypeAssertion(node) => &node.expression,
+    SimpleAssignmentTarget::TSNonNullExpression(node) => &node.expression,
+    _ => unreachable!(),
+  };
+  expression.get_inner_expression()
+}
@@ -0,0 +1,132 @@
+use oxc::{
+  ast::{
+    NONE,
+    ast::{
+      Argument, BindingPatternKind, Declaration, Expression, TSImportEqualsDeclaration,
+      TSModuleReference, TSTypeName, VariableDeclarationKind,
+    },
+  },
+  span::SPAN,
+};
+
+use crate::{
+  analyzer::Analyzer,
+  ast::{AstKind2, DeclarationKind},
+  entity::Entity,
+  transformer::Transformer,
+};
+
+impl<'a> Analyzer<'a> {
+  pub fn declare_ts_import_equals_declaration(
+    &mut self,
+    node: &'a TSImportEqualsDeclaration<'a>,
+    exporting: bool,
+  ) {
+    self.declare_binding_identifier(&node.id, exporting, DeclarationKind::Const);
+  }
+
+  pub fn init_ts_import_equals_declaration(&mut self, node: &'a TSImportEqualsDeclaration<'a>) {
+    let dep = AstKind2::TSImportEqualsDeclaration(node);
+    let value = match &node.module_reference {
+      // `import x = require('y')`
+      TSModuleReference::ExternalModuleReference(reference) => {
+        let specifier = self.factory.string(reference.expression.value.as_str());
+        let args = self.factory.arguments(self.factory.vec1((false, specifier)));
+        let require = self.builtins.require;
+        require.call(self, dep, self.factory.undefined, args)
+      }
+      // `import x = N.y`
+      TSModuleReference::IdentifierReference(node) => self.exec_identifier_reference_read(node),
+      TSModuleReference::QualifiedName(node) => {
+        let object = self.exec_ts_type_name(&node.left);
+        let key = self.factory.string(node.right.name.as_str());
+        object.get_property(self, dep, key)
+      }
+    };
+    self.init_binding_identifier(&node.id, Some(self.factory.computed(value, dep)));
+  }
+
+  fn exec_ts_type_name(&mut self, node: &'a TSTypeName<'a>) -> Entity<'a> {
+    match node {
+      TSTypeName::IdentifierReference(node) => self.exec_identifier_reference_read(node),
+      TSTypeName::QualifiedName(node) => {
+        let object = self.exec_ts_type_name(&node.left);
+        let key = self.factory.string(node.right.name.as_str());
+        object.get_property(self, self.factory.no_dep, key)
+      }
+    }
+  }
+}
+
+impl<'a> Transformer<'a> {
+  pub fn transform_ts_import_equals_declaration(
+    &self,
+    node: &'a TSImportEqualsDeclaration<'a>,
+  ) -> Option<Declaration<'a>> {
+    let TSImportEqualsDeclaration { span, id, module_reference, .. } = node;
+
+    let id = match self.transform_binding_identifier(id) {
+      Some(id) => id,
+      None if self.is_referred(AstKind2::TSImportEqualsDeclaration(node)) => {
+        self.build_unused_binding_identifier(id.span)
+      }
+      None => return None,
+    };
+
+    let init = match module_reference {
+      TSModuleReference::ExternalModuleReference(reference) => self.ast_builder.expression_call(
+        reference.span,
+        self.ast_builder.expression_identifier(SPAN, "require"),
+        NONE,
+        self.ast_builder.vec1(Argument::StringLiteral(
+          self.ast_builder.alloc(self.clone_node(&reference.expression)),
+        )),
+        false,
+      ),
+      TSModuleReference::IdentifierReference(node) => {
+        Expression::Identifier(self.transform_identifier_reference_read(node, true).unwrap())
+      }
+      TSModuleReference::QualifiedName(node) => {
+        Expression::from(self.ast_builder.member_expression_static(
+          node.span,
+          self.transform_ts_type_name(&node.left),
+          self.clone_node(&node.right),
+          false,
+        ))
+      }
+    };
+
+    Some(self.ast_builder.declaration_variable(
+      *span,
+      VariableDeclarationKind::Const,
+      self.ast_builder.vec1(self.ast_builder.variable_declarator(
+        *span,
+        VariableDeclarationKind::Const,
+        self.ast_builder.binding_pattern(
+          BindingPatternKind::BindingIdentifier(self.ast_builder.alloc(id)),
+          NONE,
+          false,
+        ),
+        Some(init),
+        false,
+      )),
+      false,
+    ))
+  }
+
+  fn transform_ts_type_name(&self, node: &'a TSTypeName<'a>) -> Expression<'a> {
+    match node {
+      TSTypeName::IdentifierReference(node) => {
+        Expression::Identifier(self.transform_identifier_reference_read(node, true).unwrap())
+      }
+      TSTypeName::QualifiedName(node) => {
+        Expression::from(self.ast_builder.member_expression_static(
+          node.span,
+          self.transform_ts_type_name(&node.left),
+          self.clone_node(&node.right),
+          false,
+        ))
+      }
+    }
+  }
+}
@@ -0,0 +1,302 @@
+use oxc::{
+  allocator,
+  ast::{
+    NONE,
+    ast::{
+      Argument, AssignmentOperator, AssignmentTarget, BindingIdentifier, Declaration,
+      ExportNamedDeclaration, Expression, FormalParameterKind, LogicalOperator, ModuleExportName,
+      SimpleAssignmentTarget, Statement, TSModuleDeclaration, TSModuleDeclarationBody,
+      TSModuleDeclarationName, VariableDeclarationKind,
+    },
+  },
+  semantic::Scoping,
+  span::{Atom, SPAN, Span},
+};
+
+use crate::{
+  analyzer::Analyzer,
+  ast::{AstKind2, DeclarationKind},
+  entity::Entity,
+  nodes::ts::is_type_only_declaration,
+  transformer::Transformer,
+  value::ObjectPrototype,
+};
+
+impl<'a> Analyzer<'a> {
+  pub fn declare_ts_module_declaration(
+    &mut self,
+    node: &'a TSModuleDeclaration<'a>,
+    exporting: bool,
+  ) {
+    self.declare_binding_identifier(get_namespace_id(node), exporting, DeclarationKind::Var);
+  }
+
+  pub fn init_ts_module_declaration(&mut self, node: &'a TSModuleDeclaration<'a>) -> Entity<'a> {
+    let object = self.init_ts_namespace_object(get_namespace_id(node));
+
+    match &node.body {
+      Some(TSModuleDeclarationBody::TSModuleBlock(block)) => {
+        for statement in &block.body {
+          match get_exported_declaration(statement) {
+            Some(declaration) => self.declare_declaration(declaration, false),
+            None if is_local_export(statement) => {}
+            None => self.declare_statement(statement),
+          }
+        }
+        for statement in &block.body {
+          match get_exported_declaration(statement) {
+            Some(declaration) => {
+              self.init_declaration(declaration);
+              for id in get_declaration_bindings(declaration, self.semantic().scoping()) {
+                let value = self.read_symbol(id.symbol_id()).unwrap_or(self.factory.unknown);
+                let value = self.factory.computed(value, AstKind2::BindingIdentifier(id));
+                let key = self.factory.string(id.name.as_str());
+                object.set_property(self, self.factory.no_dep, key, value);
+              }
+            }
+            None if is_local_export(statement) => {
+              let Statement::ExportNamedDeclaration(node) = statement else { unreachable!() };
+              for specifier in &node.specifiers {
+                let ModuleExportName::IdentifierReference(local) = &specifier.local else {
+                  unreachable!()
+                };
+                let value = self.exec_identifier_reference_read(local);
+                let value = self.factory.computed(value, AstKind2::ExportSpecifier(specifier));
+                let key = self.factory.string(specifier.exported.name().as_str());
+                object.set_property(self, self.factory.no_dep, key, value);
+              }
+            }
+            None => self.init_statement(statement),
+          }
+        }
+      }
+      Some(TSModuleDeclarationBody::TSModuleDeclaration(inner)) => {
+        // `namespace A.B {}` is the same as `namespace A { export namespace B {} }`
+        let id = get_namespace_id(inner);
+        self.declare_ts_module_declaration(inner, false);
+        let value = self.init_ts_module_declaration(inner);
+        let value = self.factory.computed(value, AstKind2::BindingIdentifier(id));
+        let key = self.factory.string(id.name.as_str());
+        object.set_property(self, self.factory.no_dep, key, value);
+      }
+      None => {}
+    }
+
+    object
+  }
+
+  /// Enums and namespaces with the same name are merged into the same object.
+  pub fn init_ts_namespace_object(&mut self, id: &'a BindingIdentifier<'a>) -> Entity<'a> {
+    let symbol = id.symbol_id();
+    let object = if self.semantic().scoping().symbol_span(symbol) == id.span {
+      self.new_empty_object(ObjectPrototype::Builtin(&self.builtins.prototypes.object), None).into()
+    } else {
+      self.read_symbol(symbol).unwrap_or(self.factory.unknown)
+    };
+    self.init_binding_identifier(id, Some(object));
+    object
+  }
+}
+
+impl<'a> Transformer<'a> {
+  pub fn transform_ts_module_declaration(
+    &self,
+    node: &'a TSModuleDeclaration<'a>,
+  ) -> Option<Declaration<'a>> {
+    let TSModuleDeclaration { span, body, .. } = node;
+    let id = get_namespace_id(node);
+    let name = id.name;
+
+    let mut statements = self.ast_builder.vec();
+    match body {
+      Some(TSModuleDeclarationBody::TSModuleBlock(block)) => {
+        for statement in &block.body {
+          if let Some(declaration) = get_exported_declaration(statement) {
+            if let Some(declaration) = self.transform_declaration(declaration) {
+              statements.push(Statement::from(declaration));
+            }
+            for id in get_declaration_bindings(declaration, self.semantic.scoping()) {
+              if self.is_referred(AstKind2::BindingIdentifier(id)) {
+                statements.push(self.build_ts_namespace_export(
+                  name,
+                  id.name,
+                  self.ast_builder.expression_identifier(id.span, id.name),
+                ));
+              }
+            }
+          } else if is_local_export(statement) {
+            let Statement::ExportNamedDeclaration(node) = statement else { unreachable!() };
+            for specifier in &node.specifiers {
+              if self.is_referred(AstKind2::ExportSpecifier(specifier)) {
+                let ModuleExportName::IdentifierReference(local) = &specifier.local else {
+                  unreachable!()
+                };
+                let local = self.transform_identifier_reference_read(local, true).unwrap();
+                statements.push(self.build_ts_namespace_export(
+                  name,
+                  specifier.exported.name(),
+                  Expression::Identifier(local),
+                ));
+              }
+            }
+          } else if let Some(statement) = self.transform_statement(statement) {
+            statements.push(statement);
+          }
+        }
+      }
+      Some(TSModuleDeclarationBody::TSModuleDeclaration(inner)) => {
+        if let Some(declaration) = self.transform_ts_module_declaration(inner) {
+          statements.push(Statement::from(declaration));
+          let inner_id = get_namespace_id(inner);
+          if self.is_referred(AstKind2::BindingIdentifier(inner_id)) {
+            statements.push(self.build_ts_namespace_export(
+              name,
+              inner_id.name,
+              self.ast_builder.expression_identifier(inner_id.span, inner_id.name),
+            ));
+          }
+        }
+      }
+      None => {}
+    }
+
+    if statements.is_empty() && !self.is_referred(AstKind2::BindingIdentifier(id)) {
+      return None;
+    }
+
+    Some(self.build_ts_namespace_declaration(*span, name, statements))
+  }
+
+  /// `var N = ((N) => { ...; return N; })(N || {})`
+  pub fn build_ts_namespace_declaration(
+    &self,
+    span: Span,
+    name: Atom<'a>,
+    mut statements: allocator::Vec<'a, Statement<'a>>,
+  ) -> Declaration<'a> {
+    statements.push(
+      self
+        .ast_builder
+        .statement_return(SPAN, Some(self.ast_builder.expression_identifier(SPAN, name))),
+    );
+    let callee = self.ast_builder.expression_arrow_function(
+      span,
+      false,
+      false,
+      NONE,
+      self.ast_builder.formal_parameters(
+        SPAN,
+        FormalParameterKind::ArrowFormalParameters,
+        self.ast_builder.vec1(self.ast_builder.formal_parameter(
+          SPAN,
+          self.ast_builder.vec(),
+          self.ast_builder.binding_pattern(
+            self.ast_builder.binding_pattern_kind_binding_identifier(SPAN, name),
+            NONE,
+            false,
+          ),
+          None,
+          false,
+          false,
+        )),
+        NONE,
+      ),
+      NONE,
+      self.ast_builder.function_body(SPAN, self.ast_builder.vec(), statements),
+    );
+    let argument = self.ast_builder.expression_logical(
+      SPAN,
+      self.ast_builder.expression_identifier(SPAN, name),
+      LogicalOperator::Or,
+      self.ast_builder.expression_object(SPAN, self.ast_builder.vec()),
+    );
+    let init = self.ast_builder.expression_call(
+      span,
+      callee,
+      NONE,
+      self.ast_builder.vec1(Argument::from(argument)),
+      false,
+    );
+    self.ast_builder.declaration_variable(
+      span,
+      VariableDeclarationKind::Var,
+      self.ast_builder.vec1(self.ast_builder.variable_declarator(
+        span,
+        VariableDeclarationKind::Var,
+        self.ast_builder.binding_pattern(
+          self.ast_builder.binding_pattern_kind_binding_identifier(SPAN, name),
+          NONE,
+          false,
+        ),
+        Some(init),
+        false,
+      )),
+      false,
+    )
+  }
+
+  /// `N.x = value`
+  fn build_ts_namespace_export(
+    &self,
+    namespace: Atom<'a>,
+    name: Atom<'a>,
+    value: Expression<'a>,
+  ) -> Statement<'a> {
+    self.ast_builder.statement_expression(
+      SPAN,
+      self.ast_builder.expression_assignment(
+        SPAN,
+        AssignmentOperator::Assign,
+        AssignmentTarget::from(SimpleAssignmentTarget::from(
+          self.ast_builder.member_expression_static(
+            SPAN,
+            self.ast_builder.expression_identifier(SPAN, namespace),
+            self.ast_builder.identifier_name(SPAN, name),
+            false,
+          ),
+        )),
+        value,
+      ),
+    )
+  }
+}
+
+fn get_namespace_id<'a>(node: &'a TSModuleDeclaration<'a>) -> &'a BindingIdentifier<'a> {
+  match &node.id {
+    TSModuleDeclarationName::Identifier(id) => id,
+    TSModuleDeclarationName::StringLiteral(_) => unreachable!(),
+  }
+}
+
+/// `export const x = 1` inside a namespace
+fn get_exported_declaration<'a>(statement: &'a Statement<'a>) -> Option<&'a Declaration<'a>> {
+  match statement {
+    Statement::ExportNamedDeclaration(node) => node.declaration.as_ref(),
+    _ => None,
+  }
+}
+
+/// `export { x }` inside a namespace
+fn is_local_export(statement: &Statement) -> bool {
+  matches!(
+    statement,
+    Statement::ExportNamedDeclaration(node) if matches!(node.as_ref(), ExportNamedDeclaration { declaration: None, source: None, export_kind, .. } if export_kind.is_value())
+  )
+}
+
+fn get_declaration_bindings<'a>(
+  declaration: &'a Declaration<'a>,
+  scoping: &Scoping,
+) -> Vec<&'a BindingIdentifier<'a>> {
+  if is_type_only_declaration(declaration, scoping) {
+    vec![]
+  } else if let Declaration::VariableDeclaration(node) = declaration {
+    node
+      .declarations
+      .iter()
+      .flat_map(|declarator| declarator.id.get_binding_identifiers())
+      .collect()
+  } else {
+    declaration.id().into_iter().collect()
+  }
+}
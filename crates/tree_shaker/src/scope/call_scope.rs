@@ -5,7 +5,7 @@ use oxc::allocator;
 use super::variable_scope::VariableScopeId;
 use crate::{
   analyzer::Analyzer,
-  dep::{DepAtom, DepTrait},
+  dep::{Dep, DepAtom, DepTrait, LazyDep},
   entity::Entity,
   utils::CalleeInfo,
 };
@@ -20,6 +20,9 @@ pub struct CallScope<'a> {
   pub is_async: bool,
   pub is_generator: bool,
   pub need_consume_arguments: bool,
+  /// Consumed with the promise reactions registered in this call, since the later `await`s let
+  /// them run first
+  pub later_awaits: Option<LazyDep<'a, Dep<'a>>>,
 
   #[cfg(feature = "flame")]
   pub scope_guard: flame::SpanGuard,
@@ -45,6 +48,7 @@ impl<'a> CallScope<'a> {
       is_async,
       is_generator,
       need_consume_arguments: false,
+      later_awaits: None,
 
       #[cfg(feature = "flame")]
       scope_guard: flame::start_guard(callee.debug_name.to_string()),
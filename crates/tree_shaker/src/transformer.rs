@@ -16,9 +16,9 @@ use oxc::{
     },
   },
   semantic::{ScopeId, Semantic, SymbolId},
-  span::{GetSpan, SPAN, Span},
+  span::{Atom, GetSpan, SPAN, Span},
 };
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
   TreeShakeConfig, analyzer::conditional::ConditionalDataMap, dep::ReferredDeps,
@@ -43,6 +43,8 @@ pub struct Transformer<'a> {
   pub need_unused_assignment_target: Cell<bool>,
   pub need_non_nullish_helper: Cell<bool>,
   pub unused_identifier_names: RefCell<FxHashMap<u64, usize>>,
+  /// Sources of the modules which are already loaded by the kept import declarations
+  pub loaded_modules: RefCell<FxHashSet<Atom<'a>>>,
 }
 
 impl<'a> Transformer<'a> {
@@ -73,6 +75,7 @@ impl<'a> Transformer<'a> {
       need_unused_assignment_target: Cell::new(false),
       need_non_nullish_helper: Cell::new(false),
       unused_identifier_names: Default::default(),
+      loaded_modules: Default::default(),
     }
   }
 
@@ -81,6 +84,8 @@ impl<'a> Transformer<'a> {
 
     let mut transformed_body = self.ast_builder.vec();
 
+    self.collect_loaded_modules(body);
+
     for statement in body {
       if let Some(statement) = self.transform_statement(statement) {
         transformed_body.push(statement);
@@ -146,6 +146,9 @@ ast_kind_2! {
   JSXAttributeItem(&'a JSXAttributeItem<'a>),
   JSXMemberExpression(&'a JSXMemberExpression<'a>),
   JsxExpressionContainer(&'a JSXExpressionContainer<'a>),
+  TSEnumMember(&'a TSEnumMember<'a>),
+  TSImportEqualsDeclaration(&'a TSImportEqualsDeclaration<'a>),
+  TSExportAssignment(&'a TSExportAssignment<'a>),
 
   // extras
   Expression(&'a Expression<'a>),
@@ -0,0 +1,254 @@
+use regex::{Captures, Regex, RegexBuilder};
+
+/// `\s` in JavaScript, as the content of a character class
+const JS_WHITESPACE_CLASS: &str =
+  r"\t\n\x0B\x0C\r \xA0\u1680\u2000-\u200A\u2028\u2029\u202F\u205F\u3000\uFEFF";
+
+/// A JavaScript regular expression translated into the syntax of the `regex` crate. Only the
+/// patterns and inputs whose semantics are the same in both engines are supported.
+#[derive(Debug)]
+pub struct JsRegex {
+  regex: Regex,
+  pub global: bool,
+  ignore_case: bool,
+  multiline: bool,
+  unicode: bool,
+}
+
+impl JsRegex {
+  /// `None` if the pattern uses features which can't be evaluated, like lookarounds,
+  /// backreferences, and the `y`, `d` and `v` flags
+  pub fn new(source: &str, flags: &str) -> Option<Self> {
+    let mut global = false;
+    let mut ignore_case = false;
+    let mut multiline = false;
+    let mut dot_all = false;
+    let mut unicode = false;
+    for flag in flags.chars() {
+      let enabled = match flag {
+        'g' => &mut global,
+        'i' => &mut ignore_case,
+        'm' => &mut multiline,
+        's' => &mut dot_all,
+        'u' => &mut unicode,
+        _ => return None,
+      };
+      if *enabled {
+        return None;
+      }
+      *enabled = true;
+    }
+    if ignore_case && !source.is_ascii() {
+      // The case folding rules are different
+      return None;
+    }
+
+    let translated = translate_pattern(source, dot_all, unicode)?;
+    let regex = RegexBuilder::new(&translated)
+      .case_insensitive(ignore_case)
+      .multi_line(multiline)
+      .size_limit(1 << 20)
+      .build()
+      .ok()?;
+    Some(JsRegex { regex, global, ignore_case, multiline, unicode })
+  }
+
+  /// Whether matching against `input` behaves the same as in JavaScript
+  pub fn supports_input(&self, input: &str) -> bool {
+    if self.ignore_case && !input.is_ascii() {
+      return false;
+    }
+    if self.multiline && input.contains(['\r', '\u{2028}', '\u{2029}']) {
+      // Only `\n` is a line terminator in the `regex` crate
+      return false;
+    }
+    // Without the `u` flag, surrogate pairs are matched as two characters
+    self.unicode || input.chars().all(|c| c.len_utf16() == 1)
+  }
+
+  /// The first match starting at or after the byte offset `start`
+  pub fn exec_at<'s>(&self, input: &'s str, start: usize) -> Option<Captures<'s>> {
+    self.regex.captures_at(input, start)
+  }
+
+  /// All the matches, advancing by one character after an empty match like JavaScript does
+  pub fn exec_all<'s>(&self, input: &'s str) -> Vec<Captures<'s>> {
+    let mut matches = vec![];
+    let mut last_index = 0;
+    while last_index <= input.len() {
+      let Some(captures) = self.regex.captures_at(input, last_index) else {
+        break;
+      };
+      let whole = captures.get(0).unwrap();
+      last_index =
+        if whole.is_empty() { next_char_boundary(input, whole.end()) } else { whole.end() };
+      matches.push(captures);
+    }
+    matches
+  }
+
+  /// The names of the capture groups, including the implicit whole match group
+  pub fn capture_names(&self) -> impl Iterator<Item = Option<&str>> {
+    self.regex.capture_names()
+  }
+
+  pub fn has_named_groups(&self) -> bool {
+    self.regex.capture_names().any(|name| name.is_some())
+  }
+}
+
+/// The byte offset after the character at `index`, or past the end of the input
+pub fn next_char_boundary(input: &str, index: usize) -> usize {
+  input[index..].chars().next().map_or(index + 1, |c| index + c.len_utf8())
+}
+
+/// Converts a byte offset into a JavaScript string index
+pub fn utf16_index(input: &str, index: usize) -> usize {
+  input[..index].encode_utf16().count()
+}
+
+fn translate_pattern(source: &str, dot_all: bool, unicode: bool) -> Option<String> {
+  let chars: Vec<char> = source.chars().collect();
+  let mut result = String::with_capacity(source.len());
+  let mut in_class = false;
+  // Whether each open group contains capturing groups
+  let mut groups: Vec<bool> = vec![];
+  let mut i = 0;
+  while i < chars.len() {
+    let c = chars[i];
+    i += 1;
+    if c == '\\' {
+      let escaped = *chars.get(i)?;
+      i += 1;
+      let hex_digits = |count: usize| {
+        chars.get(i..i + count).is_some_and(|digits| digits.iter().all(char::is_ascii_hexdigit))
+      };
+      match escaped {
+        'd' if in_class => result.push_str("0-9"),
+        'w' if in_class => result.push_str("0-9A-Za-z_"),
+        's' if in_class => result.push_str(JS_WHITESPACE_CLASS),
+        'b' if in_class => result.push_str(r"\x08"),
+        'D' | 'W' | 'S' | 'B' if in_class => return None,
+        'd' => result.push_str("[0-9]"),
+        'D' => result.push_str("[^0-9]"),
+        'w' => result.push_str("[0-9A-Za-z_]"),
+        'W' => result.push_str("[^0-9A-Za-z_]"),
+        's' => result.push_str(&format!("[{JS_WHITESPACE_CLASS}]")),
+        'S' => result.push_str(&format!("[^{JS_WHITESPACE_CLASS}]")),
+        'b' => result.push_str(r"(?-u:\b)"),
+        'B' => result.push_str(r"(?-u:\B)"),
+        'n' | 'r' | 't' | 'f' | 'v' => {
+          result.push('\\');
+          result.push(escaped);
+        }
+        '0' if !chars.get(i).is_some_and(char::is_ascii_digit) => result.push_str(r"\x00"),
+        'x' if hex_digits(2) => result.push_str(r"\x"),
+        'u' if hex_digits(4) => result.push_str(r"\u"),
+        'u' if unicode && chars.get(i) == Some(&'{') => result.push_str(r"\u"),
+        'p' | 'P' if unicode => {
+          result.push('\\');
+          result.push(escaped);
+        }
+        // Backreferences, control characters and legacy octal escapes
+        c if c.is_ascii_alphanumeric() => return None,
+        c => result.push_str(&regex::escape(&c.to_string())),
+      }
+      continue;
+    }
+
+    if in_class {
+      match c {
+        ']' => {
+          in_class = false;
+          result.push(']');
+        }
+        // Nested classes and set operations in the `regex` crate
+        '[' | '&' | '~' => {
+          result.push('\\');
+          result.push(c);
+        }
+        '-' if chars.get(i) == Some(&'-') => return None,
+        c => result.push(c),
+      }
+      continue;
+    }
+
+    match c {
+      '[' => {
+        let negated = chars.get(i) == Some(&'^');
+        if negated {
+          i += 1;
+        }
+        if chars.get(i) == Some(&']') {
+          // `[]` and `[^]` are not supported by the `regex` crate
+          return None;
+        }
+        in_class = true;
+        result.push_str(if negated { "[^" } else { "[" });
+      }
+      '.' => {
+        result.push_str(if dot_all { "(?s:.)" } else { r"[^\n\r\u2028\u2029]" });
+      }
+      '(' => {
+        let capturing = if chars.get(i) == Some(&'?') {
+          match (chars.get(i + 1), chars.get(i + 2)) {
+            (Some(':'), _) => false,
+            (Some('<'), Some(c)) if *c != '=' && *c != '!' => true,
+            // Lookarounds
+            _ => return None,
+          }
+        } else {
+          true
+        };
+        if capturing {
+          for group in &mut groups {
+            *group = true;
+          }
+        }
+        groups.push(false);
+        result.push('(');
+      }
+      ')' => {
+        let has_captures = groups.pop()?;
+        if has_captures && matches!(chars.get(i), Some('*' | '+' | '?' | '{')) {
+          // JavaScript resets the captures in each iteration
+          return None;
+        }
+        result.push(')');
+      }
+      '{' => {
+        if let Some(len) = quantifier_len(&chars[i..]) {
+          result.push('{');
+          result.extend(&chars[i..i + len]);
+          i += len;
+        } else if unicode {
+          return None;
+        } else {
+          result.push_str(r"\{");
+        }
+      }
+      '}' | ']' => {
+        if unicode {
+          return None;
+        }
+        result.push('\\');
+        result.push(c);
+      }
+      c => result.push(c),
+    }
+  }
+  (!in_class && groups.is_empty()).then_some(result)
+}
+
+/// The length of `n}`, `n,}` or `n,m}` after `{`, `None` if it is not a quantifier
+fn quantifier_len(chars: &[char]) -> Option<usize> {
+  let end = chars.iter().position(|c| *c == '}')?;
+  let mut parts = chars[..end].split(|c| *c == ',');
+  let is_number = |part: &[char]| !part.is_empty() && part.iter().all(char::is_ascii_digit);
+  let valid = match (parts.next(), parts.next(), parts.next()) {
+    (Some(min), None, None) => is_number(min),
+    (Some(min), Some(max), None) => is_number(min) && (max.is_empty() || is_number(max)),
+    _ => false,
+  };
+  valid.then_some(end + 1)
+}
@@ -7,7 +7,9 @@ mod escape_template_element_value;
 mod f64_with_eq;
 mod found;
 mod get_two_mut;
+mod js_regex;
 mod private_identifier_name;
+pub mod source_map;
 mod symbol_id;
 
 pub use callee_info::*;
@@ -15,3 +17,4 @@ pub use data::*;
 pub use f64_with_eq::*;
 pub use found::*;
 pub use get_two_mut::*;
+pub use js_regex::*;
@@ -0,0 +1,44 @@
+use oxc_sourcemap::{SourceMap, SourceMapBuilder};
+
+/// Remap `map` (generated code -> input code) through `input` (input code -> original code),
+/// so that the result points at the original code directly.
+pub fn chain_source_map(map: &SourceMap, input: &SourceMap) -> SourceMap {
+  let lookup_table = input.generate_lookup_table();
+  let mut builder = SourceMapBuilder::default();
+  if let Some(file) = map.get_file() {
+    builder.set_file(file);
+  }
+
+  for token in map.get_tokens() {
+    if token.get_source_id().is_none() {
+      continue;
+    }
+    let Some(original) =
+      input.lookup_token(&lookup_table, token.get_src_line(), token.get_src_col())
+    else {
+      // Not covered by the input source map
+      continue;
+    };
+    let Some(source_id) = original.get_source_id() else {
+      continue;
+    };
+    let source = input.get_source(source_id).unwrap_or_default();
+    let content = input.get_source_content(source_id).unwrap_or_default();
+    let source_id = builder.add_source_and_content(source, content);
+    let name = original
+      .get_name_id()
+      .and_then(|id| input.get_name(id))
+      .or_else(|| token.get_name_id().and_then(|id| map.get_name(id)));
+    let name_id = name.map(|name| builder.add_name(name));
+    builder.add_token(
+      token.get_dst_line(),
+      token.get_dst_col(),
+      original.get_src_line(),
+      original.get_src_col(),
+      Some(source_id),
+      name_id,
+    );
+  }
+
+  builder.into_sourcemap()
+}
@@ -96,7 +96,7 @@ impl<'a> ValueTrait<'a> for ArgumentsValue<'a> {
     let mut rest: Option<allocator::Vec<'a, Entity<'a>>> = None;
     for (spread, entity) in &self.arguments {
       if *spread {
-        if let Some(iterated) = entity.iterate_result_union(analyzer, dep) {
+        if let Some(iterated) = entity.iterate_result_union(analyzer, dep, false) {
           if let Some(rest) = &mut rest {
             rest.push(iterated);
           } else {
@@ -162,7 +162,7 @@ impl<'a> ArgumentsValue<'a> {
   ) -> (Entity<'a>, Dep<'a>) {
     let (known1, rest1, dep1) = args1.iterate(analyzer, dep);
     if let Some(rest1) = rest1 {
-      let value2 = args2.iterate_result_union(analyzer, dep);
+      let value2 = args2.iterate_result_union(analyzer, dep, false);
       let rest =
         if let Some(value2) = value2 { analyzer.factory.union((rest1, value2)) } else { rest1 };
       (
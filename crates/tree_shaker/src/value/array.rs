@@ -7,8 +7,8 @@ use oxc::allocator;
 use rustc_hash::FxHashMap;
 
 use super::{
-  EnumeratedProperties, IteratedElements, LiteralValue, ObjectId, PropertyKeyValue, TypeofResult,
-  ValueTrait, consumed_object,
+  EnumeratedProperties, IteratedElements, LiteralValue, ObjectId, ObjectPrototype,
+  PropertyKeyValue, TypeofResult, ValueTrait, consumed_object,
 };
 use crate::{
   analyzer::{Analyzer, exhaustive::ExhaustiveDepId},
@@ -20,11 +20,16 @@ use crate::{
 
 pub struct ArrayValue<'a> {
   pub consumed: Cell<bool>,
+  /// Non-empty if the elements are unknown
   pub deps: RefCell<DepCollector<'a>>,
+  /// Deps of the mutations applied to the tracked elements
+  pub mutations: RefCell<DepCollector<'a>>,
   pub cf_scope: CfScopeId,
   pub object_id: ObjectId,
   pub elements: RefCell<allocator::Vec<'a, Entity<'a>>>,
   pub rest: RefCell<allocator::Vec<'a, Entity<'a>>>,
+  /// Properties other than the elements, like `index` of `RegExp.prototype.exec` results
+  pub named: RefCell<allocator::Vec<'a, (&'a str, Entity<'a>)>>,
 }
 
 impl fmt::Debug for ArrayValue<'_> {
@@ -34,6 +39,7 @@ impl fmt::Debug for ArrayValue<'_> {
       .field("deps", &self.deps.borrow())
       .field("elements", &self.elements.borrow())
       .field("rest", &self.rest.borrow())
+      .field("named", &self.named.borrow())
       .finish()
   }
 }
@@ -43,8 +49,12 @@ impl<'a> ValueTrait<'a> for ArrayValue<'a> {
     use_consumed_flag!(self);
 
     self.deps.borrow().consume_all(analyzer);
+    self.mutations.borrow().consume_all(analyzer);
     self.elements.borrow().consume(analyzer);
     self.rest.borrow().consume(analyzer);
+    for (_, value) in self.named.borrow().iter() {
+      analyzer.consume(*value);
+    }
 
     let target_depth = analyzer.find_first_different_cf_scope(self.cf_scope);
     analyzer.mark_exhaustive_write(ExhaustiveDepId::ObjectAll(self.object_id), target_depth);
@@ -81,7 +91,7 @@ impl<'a> ValueTrait<'a> for ArrayValue<'a> {
       return analyzer.factory.computed_unknown((self, dep, key));
     }
 
-    let dep = analyzer.dep((self.deps.borrow_mut().collect(analyzer.factory), dep, key));
+    let dep = analyzer.dep((self.mutations.borrow_mut().collect(analyzer.factory), dep, key));
     if let Some(key_literals) = key.get_to_literals(analyzer) {
       let mut result = analyzer.factory.vec();
       let mut rest_added = false;
@@ -101,6 +111,8 @@ impl<'a> ValueTrait<'a> for ArrayValue<'a> {
                 || analyzer.factory.computed_unknown_number(&self.rest),
                 |length| analyzer.factory.number(length as f64, None),
               ));
+            } else if let Some(value) = self.get_named(key) {
+              result.push(value);
             } else if let Some(property) =
               analyzer.builtins.prototypes.array.get_keyed(PropertyKeyValue::String(key))
             {
@@ -109,13 +121,25 @@ impl<'a> ValueTrait<'a> for ArrayValue<'a> {
               result.push(analyzer.factory.unmatched_prototype_property);
             }
           }
-          LiteralValue::Symbol(_key, _) => todo!(),
+          LiteralValue::Symbol(key, _) => {
+            if let Some(property) =
+              analyzer.builtins.prototypes.array.get_keyed(PropertyKeyValue::Symbol(key))
+            {
+              result.push(property);
+            } else {
+              result.push(analyzer.factory.unmatched_prototype_property);
+            }
+          }
           _ => unreachable!("Invalid property key"),
         }
       }
       analyzer.factory.computed_union(result, dep)
     } else {
-      analyzer.factory.computed_unknown((&self.elements, &self.rest, dep))
+      let named = allocator::Vec::from_iter_in(
+        self.named.borrow().iter().map(|(_, value)| *value),
+        analyzer.allocator,
+      );
+      analyzer.factory.computed_unknown((&self.elements, &self.rest, named, dep))
     }
   }
 
@@ -190,7 +214,8 @@ impl<'a> ValueTrait<'a> for ArrayValue<'a> {
               break 'known;
             }
           }
-          LiteralValue::Symbol(_key, _) => todo!(),
+          // Symbol keyed properties of arrays are not tracked
+          LiteralValue::Symbol(_, _) => break 'known,
           _ => unreachable!("Invalid property key"),
         }
       }
@@ -231,6 +256,9 @@ impl<'a> ValueTrait<'a> for ArrayValue<'a> {
       known
         .insert(PropertyKeyValue::String(i_str), (true, analyzer.factory.string(i_str), *element));
     }
+    for (key, value) in self.named.borrow().iter() {
+      known.insert(PropertyKeyValue::String(key), (true, analyzer.factory.string(key), *value));
+    }
     let rest = self.rest.borrow();
     let unknown = (!rest.is_empty()).then(|| {
       analyzer.factory.union(allocator::Vec::from_iter_in(rest.iter().copied(), analyzer.allocator))
@@ -239,7 +267,7 @@ impl<'a> ValueTrait<'a> for ArrayValue<'a> {
     EnumeratedProperties {
       known,
       unknown,
-      dep: analyzer.dep((self.deps.borrow_mut().collect(analyzer.factory), dep)),
+      dep: analyzer.dep((self.mutations.borrow_mut().collect(analyzer.factory), dep)),
     }
   }
 
@@ -306,7 +334,7 @@ impl<'a> ValueTrait<'a> for ArrayValue<'a> {
         self.rest.borrow().iter().copied(),
         analyzer.allocator,
       )),
-      dep,
+      analyzer.dep((self.mutations.borrow_mut().collect(analyzer.factory), dep)),
     )
   }
 
@@ -336,6 +364,17 @@ impl<'a> ValueTrait<'a> for ArrayValue<'a> {
     self.into()
   }
 
+  fn test_instanceof(
+    &'a self,
+    analyzer: &Analyzer<'a>,
+    prototype: ObjectPrototype<'a>,
+  ) -> Option<bool> {
+    if self.consumed.get() {
+      return None;
+    }
+    ObjectPrototype::Builtin(&analyzer.builtins.prototypes.array).test_inherits(analyzer, prototype)
+  }
+
   fn test_typeof(&self) -> TypeofResult {
     TypeofResult::Object
   }
@@ -347,6 +386,14 @@ impl<'a> ValueTrait<'a> for ArrayValue<'a> {
   fn test_nullish(&self) -> Option<bool> {
     Some(false)
   }
+
+  fn test_is_array(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn as_array(&'a self) -> Option<&'a ArrayValue<'a>> {
+    Some(self)
+  }
 }
 
 impl<'a> ArrayValue<'a> {
@@ -362,10 +409,60 @@ impl<'a> ArrayValue<'a> {
     self.rest.borrow_mut().push(rest);
   }
 
+  pub fn init_named(&self, key: &'a str, value: Entity<'a>) {
+    self.named.borrow_mut().push((key, value));
+  }
+
+  fn get_named(&self, key: &str) -> Option<Entity<'a>> {
+    self.named.borrow().iter().find(|(k, _)| *k == key).map(|(_, value)| *value)
+  }
+
   pub fn get_length(&self) -> Option<usize> {
     if self.rest.borrow().is_empty() { Some(self.elements.borrow().len()) } else { None }
   }
 
+  /// Applies a determinate mutation to the tracked elements and rest. If the elements are unknown,
+  /// the mutation is indeterminate, or `mutate` returns `None` (it must not touch the elements in
+  /// that case), the array becomes unknown and `None` is returned. `values` are the entities which
+  /// may be stored into the array.
+  pub fn mutate<R>(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    values: Entity<'a>,
+    mutate: impl FnOnce(
+      &mut Analyzer<'a>,
+      &mut allocator::Vec<'a, Entity<'a>>,
+      &mut allocator::Vec<'a, Entity<'a>>,
+    ) -> Option<R>,
+  ) -> Option<R> {
+    if self.consumed.get() {
+      consumed_object::unknown_mutate(analyzer, dep);
+      analyzer.consume(values);
+      return None;
+    }
+
+    let (is_exhaustive, indeterminate, exec_deps) = self.prepare_mutation(analyzer, dep);
+
+    if is_exhaustive {
+      self.consume(analyzer);
+      consumed_object::unknown_mutate(analyzer, dep);
+      analyzer.consume(values);
+      return None;
+    }
+
+    if !indeterminate && self.deps.borrow().is_empty() {
+      let result = mutate(analyzer, &mut self.elements.borrow_mut(), &mut self.rest.borrow_mut());
+      if result.is_some() {
+        self.mutations.borrow_mut().push(analyzer.dep(exec_deps));
+        return result;
+      }
+    }
+
+    self.deps.borrow_mut().push(analyzer.dep((exec_deps, values)));
+    None
+  }
+
   fn prepare_mutation(
     &self,
     analyzer: &mut Analyzer<'a>,
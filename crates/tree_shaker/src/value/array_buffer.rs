@@ -0,0 +1,649 @@
+use std::cell::{Cell, RefCell};
+
+use super::{
+  EnumeratedProperties, IteratedElements, LiteralValue, ObjectId, ObjectPrototype,
+  PropertyKeyValue, TypeofResult, ValueTrait, consumed_object,
+};
+use crate::{
+  analyzer::{Analyzer, exhaustive::ExhaustiveDepId},
+  builtins::{BuiltinPrototype, BuiltinPrototypes},
+  dep::{Dep, DepCollector, DepVec},
+  entity::Entity,
+  scope::CfScopeId,
+  use_consumed_flag,
+};
+
+/// Iterating a view with a larger length only tracks the element type
+const MAX_TRACKED_ITERATED_LENGTH: usize = 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArrayBufferViewKind {
+  Int8,
+  Uint8,
+  Uint8Clamped,
+  Int16,
+  Uint16,
+  Int32,
+  Uint32,
+  Float32,
+  Float64,
+  BigInt64,
+  BigUint64,
+  DataView,
+}
+
+impl ArrayBufferViewKind {
+  pub const TYPED_ARRAYS: [Self; 11] = [
+    ArrayBufferViewKind::Int8,
+    ArrayBufferViewKind::Uint8,
+    ArrayBufferViewKind::Uint8Clamped,
+    ArrayBufferViewKind::Int16,
+    ArrayBufferViewKind::Uint16,
+    ArrayBufferViewKind::Int32,
+    ArrayBufferViewKind::Uint32,
+    ArrayBufferViewKind::Float32,
+    ArrayBufferViewKind::Float64,
+    ArrayBufferViewKind::BigInt64,
+    ArrayBufferViewKind::BigUint64,
+  ];
+
+  pub fn name(self) -> &'static str {
+    match self {
+      ArrayBufferViewKind::Int8 => "Int8Array",
+      ArrayBufferViewKind::Uint8 => "Uint8Array",
+      ArrayBufferViewKind::Uint8Clamped => "Uint8ClampedArray",
+      ArrayBufferViewKind::Int16 => "Int16Array",
+      ArrayBufferViewKind::Uint16 => "Uint16Array",
+      ArrayBufferViewKind::Int32 => "Int32Array",
+      ArrayBufferViewKind::Uint32 => "Uint32Array",
+      ArrayBufferViewKind::Float32 => "Float32Array",
+      ArrayBufferViewKind::Float64 => "Float64Array",
+      ArrayBufferViewKind::BigInt64 => "BigInt64Array",
+      ArrayBufferViewKind::BigUint64 => "BigUint64Array",
+      ArrayBufferViewKind::DataView => "DataView",
+    }
+  }
+
+  /// The size of an element in bytes, `1` for `DataView`
+  pub fn bytes_per_element(self) -> usize {
+    match self {
+      ArrayBufferViewKind::Int8
+      | ArrayBufferViewKind::Uint8
+      | ArrayBufferViewKind::Uint8Clamped
+      | ArrayBufferViewKind::DataView => 1,
+      ArrayBufferViewKind::Int16 | ArrayBufferViewKind::Uint16 => 2,
+      ArrayBufferViewKind::Int32 | ArrayBufferViewKind::Uint32 | ArrayBufferViewKind::Float32 => 4,
+      ArrayBufferViewKind::Float64
+      | ArrayBufferViewKind::BigInt64
+      | ArrayBufferViewKind::BigUint64 => 8,
+    }
+  }
+
+  pub fn is_typed_array(self) -> bool {
+    self != ArrayBufferViewKind::DataView
+  }
+
+  pub fn prototype<'a>(self, prototypes: &'a BuiltinPrototypes<'a>) -> &'a BuiltinPrototype<'a> {
+    match self {
+      ArrayBufferViewKind::DataView => &prototypes.data_view,
+      kind => &prototypes.typed_arrays[kind as usize],
+    }
+  }
+
+  fn unknown_element<'a>(self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    match self {
+      ArrayBufferViewKind::BigInt64 | ArrayBufferViewKind::BigUint64 => {
+        analyzer.factory.unknown_bigint
+      }
+      _ => analyzer.factory.unknown_number,
+    }
+  }
+}
+
+/// `ArrayBuffer` instances. The contents are never tracked, only the deps of the writes to them,
+/// so that unused buffers and the writes to them can be removed.
+#[derive(Debug)]
+pub struct ArrayBufferValue<'a> {
+  pub consumed: Cell<bool>,
+  /// Deps of the writes to the contents
+  pub writes: RefCell<DepCollector<'a>>,
+  /// `None` if unknown, or if the buffer may be resized or detached
+  pub byte_length: Cell<Option<usize>>,
+  pub cf_scope: CfScopeId,
+  pub object_id: ObjectId,
+}
+
+impl<'a> ValueTrait<'a> for ArrayBufferValue<'a> {
+  fn consume(&'a self, analyzer: &mut Analyzer<'a>) {
+    use_consumed_flag!(self);
+
+    self.writes.borrow().consume_all(analyzer);
+
+    let target_depth = analyzer.find_first_different_cf_scope(self.cf_scope);
+    analyzer.mark_exhaustive_write(ExhaustiveDepId::ObjectAll(self.object_id), target_depth);
+  }
+
+  fn unknown_mutate(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) {
+    // Like `transfer` and `resize`
+    self.byte_length.set(None);
+    self.write(analyzer, dep);
+  }
+
+  fn get_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    key: Entity<'a>,
+  ) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::get_property(self, analyzer, dep, key);
+    }
+    if let Some(LiteralValue::String(key_str, _)) = key.get_literal(analyzer) {
+      let value = match key_str {
+        "byteLength" | "maxByteLength" => Some(self.get_byte_length(analyzer)),
+        "resizable" | "detached" => Some(match self.get_known_byte_length(analyzer) {
+          Some(_) => analyzer.factory.r#false,
+          None => analyzer.factory.computed_unknown_boolean(self),
+        }),
+        _ => None,
+      };
+      if let Some(value) = value {
+        return analyzer.factory.computed(value, (dep, key));
+      }
+    }
+    analyzer.builtins.prototypes.array_buffer.get_property(analyzer, self.into(), key, dep)
+  }
+
+  fn set_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    key: Entity<'a>,
+    value: Entity<'a>,
+  ) {
+    self.consume(analyzer);
+    consumed_object::set_property(analyzer, dep, key, value)
+  }
+
+  fn enumerate_properties(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+  ) -> EnumeratedProperties<'a> {
+    if self.consumed.get() {
+      return consumed_object::enumerate_properties(self, analyzer, dep);
+    }
+    EnumeratedProperties { known: Default::default(), unknown: None, dep }
+  }
+
+  fn delete_property(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>, key: Entity<'a>) {
+    self.consume(analyzer);
+    consumed_object::delete_property(analyzer, dep, key)
+  }
+
+  fn call(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    this: Entity<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    consumed_object::call(self, analyzer, dep, this, args)
+  }
+
+  fn construct(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    consumed_object::construct(self, analyzer, dep, args)
+  }
+
+  fn jsx(&'a self, analyzer: &mut Analyzer<'a>, props: Entity<'a>) -> Entity<'a> {
+    consumed_object::jsx(self, analyzer, props)
+  }
+
+  fn r#await(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::r#await(analyzer, dep);
+    }
+    analyzer.factory.computed(self.into(), dep)
+  }
+
+  fn iterate(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) -> IteratedElements<'a> {
+    analyzer.throw_builtin_error("ArrayBuffer is not iterable");
+    consumed_object::iterate(analyzer, analyzer.dep((self, dep)))
+  }
+
+  fn get_to_string(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::get_to_string(analyzer);
+    }
+    analyzer.factory.string("[object ArrayBuffer]")
+  }
+
+  fn get_to_numeric(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::get_to_numeric(analyzer);
+    }
+    analyzer.factory.nan
+  }
+
+  fn get_to_boolean(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    analyzer.factory.boolean(true)
+  }
+
+  fn get_to_property_key(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    self.get_to_string(analyzer)
+  }
+
+  fn get_to_jsx_child(&'a self, _analyzer: &Analyzer<'a>) -> Entity<'a> {
+    self.into()
+  }
+
+  fn as_array_buffer(&'a self) -> Option<&'a ArrayBufferValue<'a>> {
+    Some(self)
+  }
+
+  fn test_instanceof(
+    &'a self,
+    analyzer: &Analyzer<'a>,
+    prototype: ObjectPrototype<'a>,
+  ) -> Option<bool> {
+    if self.consumed.get() {
+      return None;
+    }
+    ObjectPrototype::Builtin(&analyzer.builtins.prototypes.array_buffer)
+      .test_inherits(analyzer, prototype)
+  }
+
+  fn test_typeof(&self) -> TypeofResult {
+    TypeofResult::Object
+  }
+
+  fn test_truthy(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn test_nullish(&self) -> Option<bool> {
+    Some(false)
+  }
+
+  fn test_is_array(&self) -> Option<bool> {
+    Some(false)
+  }
+}
+
+impl<'a> ArrayBufferValue<'a> {
+  /// Writes unknown data to the contents, which doesn't change the length
+  pub fn write(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) {
+    if self.consumed.get() {
+      return consumed_object::unknown_mutate(analyzer, dep);
+    }
+
+    let (is_exhaustive, exec_deps) = self.prepare_mutation(analyzer, dep);
+
+    if is_exhaustive {
+      self.consume(analyzer);
+      return consumed_object::unknown_mutate(analyzer, dep);
+    }
+
+    self.writes.borrow_mut().push(analyzer.dep(exec_deps));
+  }
+
+  /// Returns the dep of the current contents
+  pub fn read(&'a self, analyzer: &mut Analyzer<'a>) -> Dep<'a> {
+    if self.consumed.get() {
+      return analyzer.factory.no_dep;
+    }
+    analyzer.mark_exhaustive_read(ExhaustiveDepId::ObjectAll(self.object_id), self.cf_scope);
+    self.writes.borrow_mut().collect(analyzer.factory)
+  }
+
+  pub fn get_known_byte_length(&'a self, analyzer: &mut Analyzer<'a>) -> Option<usize> {
+    if self.consumed.get() {
+      return None;
+    }
+    analyzer.mark_exhaustive_read(ExhaustiveDepId::ObjectAll(self.object_id), self.cf_scope);
+    self.byte_length.get()
+  }
+
+  fn get_byte_length(&'a self, analyzer: &mut Analyzer<'a>) -> Entity<'a> {
+    match self.get_known_byte_length(analyzer) {
+      Some(byte_length) => analyzer.factory.number(byte_length as f64, None),
+      None => analyzer.factory.computed_unknown_number(self),
+    }
+  }
+
+  fn prepare_mutation(&self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) -> (bool, DepVec<'a>) {
+    let target_depth = analyzer.find_first_different_cf_scope(self.cf_scope);
+
+    let mut is_exhaustive = false;
+    let mut exec_deps = analyzer.factory.vec1(dep);
+    for depth in target_depth..analyzer.scoping.cf.stack.len() {
+      let scope = analyzer.scoping.cf.get_mut_from_depth(depth);
+      is_exhaustive |= scope.is_exhaustive();
+      if let Some(dep) = scope.deps.try_collect(analyzer.factory) {
+        exec_deps.push(dep);
+      }
+    }
+
+    analyzer.mark_exhaustive_write(ExhaustiveDepId::ObjectAll(self.object_id), target_depth);
+    analyzer.request_exhaustive_callbacks(ExhaustiveDepId::ObjectAll(self.object_id));
+
+    (is_exhaustive, exec_deps)
+  }
+}
+
+/// Typed arrays and `DataView` instances. Elements are always unknown, and writing them is a
+/// write to the underlying buffer.
+#[derive(Debug)]
+pub struct ArrayBufferViewValue<'a> {
+  pub kind: ArrayBufferViewKind,
+  pub consumed: Cell<bool>,
+  /// A tracked `ArrayBuffer`, or any other value which the view may share memory with
+  pub buffer: Entity<'a>,
+  pub byte_offset: Option<usize>,
+  /// The number of elements, or bytes for `DataView`. `None` if unknown
+  pub length: Option<usize>,
+}
+
+impl<'a> ValueTrait<'a> for ArrayBufferViewValue<'a> {
+  fn consume(&'a self, analyzer: &mut Analyzer<'a>) {
+    use_consumed_flag!(self);
+
+    analyzer.consume(self.buffer);
+  }
+
+  fn unknown_mutate(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) {
+    if self.consumed.get() {
+      return consumed_object::unknown_mutate(analyzer, dep);
+    }
+    self.write(analyzer, dep);
+  }
+
+  fn get_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    key: Entity<'a>,
+  ) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::get_property(self, analyzer, dep, key);
+    }
+
+    let Some(key_literals) = key.get_to_literals(analyzer) else {
+      return analyzer.factory.computed_unknown((self, dep, key));
+    };
+
+    let prototype = self.kind.prototype(analyzer.builtins.prototypes);
+    let mut values = analyzer.factory.vec();
+    for key_literal in key_literals {
+      let (property_key, _) = key_literal.into();
+      let value = match property_key {
+        PropertyKeyValue::String(key_str) => self.get_own_property(analyzer, key_str),
+        PropertyKeyValue::Symbol(_) => None,
+      };
+      values.push(
+        value
+          .or_else(|| prototype.get_keyed(property_key))
+          .unwrap_or(analyzer.factory.unmatched_prototype_property),
+      );
+    }
+    analyzer.factory.computed_union(values, (dep, key))
+  }
+
+  fn set_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    key: Entity<'a>,
+    value: Entity<'a>,
+  ) {
+    if !self.consumed.get() && self.kind.is_typed_array() {
+      let is_index = key.get_to_literals(analyzer).is_some_and(|key_literals| {
+        key_literals.into_iter().all(
+          |key_literal| matches!(key_literal, LiteralValue::String(key, _) if is_index_key(key)),
+        )
+      });
+      if is_index {
+        return self.write(analyzer, analyzer.dep((dep, key, value)));
+      }
+    }
+    // Other properties are rarely used
+    self.consume(analyzer);
+    consumed_object::set_property(analyzer, dep, key, value)
+  }
+
+  fn enumerate_properties(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+  ) -> EnumeratedProperties<'a> {
+    if self.consumed.get() {
+      return consumed_object::enumerate_properties(self, analyzer, dep);
+    }
+    if !self.kind.is_typed_array() || self.get_length(analyzer) == Some(0) {
+      return EnumeratedProperties { known: Default::default(), unknown: None, dep };
+    }
+    let read_dep = self.read(analyzer);
+    EnumeratedProperties {
+      known: Default::default(),
+      unknown: Some(self.kind.unknown_element(analyzer)),
+      dep: analyzer.dep((read_dep, dep)),
+    }
+  }
+
+  fn delete_property(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>, key: Entity<'a>) {
+    self.consume(analyzer);
+    consumed_object::delete_property(analyzer, dep, key)
+  }
+
+  fn call(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    this: Entity<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    consumed_object::call(self, analyzer, dep, this, args)
+  }
+
+  fn construct(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    consumed_object::construct(self, analyzer, dep, args)
+  }
+
+  fn jsx(&'a self, analyzer: &mut Analyzer<'a>, props: Entity<'a>) -> Entity<'a> {
+    consumed_object::jsx(self, analyzer, props)
+  }
+
+  fn r#await(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::r#await(analyzer, dep);
+    }
+    analyzer.factory.computed(self.into(), dep)
+  }
+
+  fn iterate(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) -> IteratedElements<'a> {
+    if !self.kind.is_typed_array() {
+      analyzer.throw_builtin_error("DataView is not iterable");
+      return consumed_object::iterate(analyzer, analyzer.dep((self, dep)));
+    }
+
+    if self.consumed.get() {
+      return consumed_object::iterate(analyzer, dep);
+    }
+
+    let element = self.kind.unknown_element(analyzer);
+    let length = self.get_length(analyzer);
+    let read_dep = self.read(analyzer);
+    let dep = analyzer.dep((read_dep, dep));
+    match length {
+      Some(length) if length <= MAX_TRACKED_ITERATED_LENGTH => (vec![element; length], None, dep),
+      _ => (vec![], Some(element), dep),
+    }
+  }
+
+  fn get_to_string(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::get_to_string(analyzer);
+    }
+    if self.kind.is_typed_array() {
+      // Joins the elements
+      analyzer.factory.computed_unknown_string(self)
+    } else {
+      analyzer.factory.string("[object DataView]")
+    }
+  }
+
+  fn get_to_numeric(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::get_to_numeric(analyzer);
+    }
+    if self.kind.is_typed_array() {
+      analyzer.factory.computed_unknown(self)
+    } else {
+      analyzer.factory.nan
+    }
+  }
+
+  fn get_to_boolean(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    analyzer.factory.boolean(true)
+  }
+
+  fn get_to_property_key(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    self.get_to_string(analyzer)
+  }
+
+  fn get_to_jsx_child(&'a self, _analyzer: &Analyzer<'a>) -> Entity<'a> {
+    self.into()
+  }
+
+  fn test_instanceof(
+    &'a self,
+    analyzer: &Analyzer<'a>,
+    prototype: ObjectPrototype<'a>,
+  ) -> Option<bool> {
+    if self.consumed.get() {
+      return None;
+    }
+    ObjectPrototype::Builtin(self.kind.prototype(analyzer.builtins.prototypes))
+      .test_inherits(analyzer, prototype)
+  }
+
+  fn test_typeof(&self) -> TypeofResult {
+    TypeofResult::Object
+  }
+
+  fn test_truthy(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn test_nullish(&self) -> Option<bool> {
+    Some(false)
+  }
+
+  fn test_is_array(&self) -> Option<bool> {
+    Some(false)
+  }
+}
+
+impl<'a> ArrayBufferViewValue<'a> {
+  /// The length is only known while the tracked buffer is not resized or detached
+  pub fn get_length(&'a self, analyzer: &mut Analyzer<'a>) -> Option<usize> {
+    let buffer = self.buffer.as_array_buffer()?;
+    buffer.get_known_byte_length(analyzer)?;
+    self.length
+  }
+
+  fn get_own_property(&'a self, analyzer: &mut Analyzer<'a>, key: &str) -> Option<Entity<'a>> {
+    let bytes_per_element = self.kind.bytes_per_element();
+    let known_number = |analyzer: &mut Analyzer<'a>, value: Option<usize>| match value {
+      Some(value) => analyzer.factory.number(value as f64, None),
+      None => analyzer.factory.computed_unknown_number(self.buffer),
+    };
+    Some(match key {
+      "buffer" => match self.buffer.as_array_buffer() {
+        Some(_) => self.buffer,
+        // The source may have been copied to a new buffer
+        None => analyzer.factory.computed_unknown(self.buffer),
+      },
+      "byteOffset" => {
+        let byte_offset = self.get_length(analyzer).and(self.byte_offset);
+        known_number(analyzer, byte_offset)
+      }
+      "byteLength" => {
+        let byte_length =
+          self.get_length(analyzer).and_then(|length| length.checked_mul(bytes_per_element));
+        known_number(analyzer, byte_length)
+      }
+      "length" if self.kind.is_typed_array() => {
+        let length = self.get_length(analyzer);
+        known_number(analyzer, length)
+      }
+      key if self.kind.is_typed_array() && is_index_key(key) => {
+        let index = key.parse::<usize>().unwrap();
+        let element = self.kind.unknown_element(analyzer);
+        let read_dep = self.read(analyzer);
+        match self.get_length(analyzer) {
+          Some(length) if index < length => analyzer.factory.computed(element, read_dep),
+          Some(_) => analyzer.factory.undefined,
+          None => analyzer
+            .factory
+            .computed(analyzer.factory.union((element, analyzer.factory.undefined)), read_dep),
+        }
+      }
+      _ => return None,
+    })
+  }
+
+  fn write(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) {
+    match self.buffer.as_array_buffer() {
+      Some(buffer) => buffer.write(analyzer, dep),
+      None => self.buffer.unknown_mutate(analyzer, dep),
+    }
+  }
+
+  fn read(&'a self, analyzer: &mut Analyzer<'a>) -> Dep<'a> {
+    match self.buffer.as_array_buffer() {
+      Some(buffer) => buffer.read(analyzer),
+      None => analyzer.dep(self.buffer),
+    }
+  }
+}
+
+/// Only the canonical forms of array indices are treated as element accesses
+fn is_index_key(key: &str) -> bool {
+  key.parse::<usize>().is_ok_and(|index| index.to_string() == key)
+}
+
+impl<'a> Analyzer<'a> {
+  pub fn new_array_buffer(&mut self, byte_length: Option<usize>) -> &'a ArrayBufferValue<'a> {
+    self.allocator.alloc(ArrayBufferValue {
+      consumed: Cell::new(false),
+      writes: RefCell::new(DepCollector::new(self.factory.vec())),
+      byte_length: Cell::new(byte_length),
+      cf_scope: self.scoping.cf.current_id(),
+      object_id: self.scoping.alloc_object_id(),
+    })
+  }
+
+  pub fn new_array_buffer_view(
+    &mut self,
+    kind: ArrayBufferViewKind,
+    buffer: Entity<'a>,
+    byte_offset: Option<usize>,
+    length: Option<usize>,
+  ) -> Entity<'a> {
+    self
+      .allocator
+      .alloc(ArrayBufferViewValue { kind, consumed: Cell::new(false), buffer, byte_offset, length })
+      .into()
+  }
+}
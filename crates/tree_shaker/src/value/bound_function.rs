@@ -0,0 +1,162 @@
+use std::cell::Cell;
+
+use super::{
+  EnumeratedProperties, IteratedElements, ObjectPrototype, ObjectValue, TypeofResult, ValueTrait,
+  arguments::ArgumentsValue, consumed_object,
+};
+use crate::{analyzer::Analyzer, dep::Dep, entity::Entity, use_consumed_flag};
+
+/// Functions created by `Function.prototype.bind`. Calls are forwarded to the target with the
+/// bound `this` and the bound arguments prepended.
+#[derive(Debug)]
+pub struct BoundFunctionValue<'a> {
+  pub consumed: Cell<bool>,
+  pub target: Entity<'a>,
+  pub bound_this: Entity<'a>,
+  /// An array of the bound arguments
+  pub bound_args: Entity<'a>,
+  /// Holds `name` and `length`. Bound functions don't have `prototype`.
+  pub statics: &'a ObjectValue<'a>,
+}
+
+impl<'a> ValueTrait<'a> for BoundFunctionValue<'a> {
+  fn consume(&'a self, analyzer: &mut Analyzer<'a>) {
+    use_consumed_flag!(self);
+
+    analyzer.consume((self.target, self.bound_this, self.bound_args));
+    self.statics.consume(analyzer);
+  }
+
+  fn unknown_mutate(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) {
+    self.consume(analyzer);
+    consumed_object::unknown_mutate(analyzer, dep);
+  }
+
+  fn get_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    key: Entity<'a>,
+  ) -> Entity<'a> {
+    self.statics.get_property(analyzer, dep, key)
+  }
+
+  fn set_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    key: Entity<'a>,
+    value: Entity<'a>,
+  ) {
+    self.statics.set_property(analyzer, dep, key, value)
+  }
+
+  fn enumerate_properties(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+  ) -> EnumeratedProperties<'a> {
+    self.statics.enumerate_properties(analyzer, dep)
+  }
+
+  fn delete_property(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>, key: Entity<'a>) {
+    self.statics.delete_property(analyzer, dep, key)
+  }
+
+  fn call(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    this: Entity<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::call(self, analyzer, dep, this, args);
+    }
+
+    // The `this` at the call site is ignored
+    let (args, dep) = ArgumentsValue::from_concatenate(analyzer, self.bound_args, args, dep);
+    self.target.call(analyzer, dep, self.bound_this, args)
+  }
+
+  fn construct(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::construct(self, analyzer, dep, args);
+    }
+
+    // The bound `this` is ignored by `new`
+    let (args, dep) = ArgumentsValue::from_concatenate(analyzer, self.bound_args, args, dep);
+    self.target.construct(analyzer, dep, args)
+  }
+
+  fn jsx(&'a self, analyzer: &mut Analyzer<'a>, props: Entity<'a>) -> Entity<'a> {
+    self.call(
+      analyzer,
+      analyzer.factory.no_dep,
+      analyzer.factory.unknown,
+      analyzer.factory.arguments(analyzer.factory.vec1((false, props))),
+    )
+  }
+
+  fn r#await(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) -> Entity<'a> {
+    consumed_object::r#await(analyzer, dep)
+  }
+
+  fn iterate(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) -> IteratedElements<'a> {
+    self.consume(analyzer);
+    consumed_object::iterate(analyzer, dep)
+  }
+
+  fn get_to_string(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    consumed_object::get_to_string(analyzer)
+  }
+
+  fn get_to_numeric(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    consumed_object::get_to_numeric(analyzer)
+  }
+
+  fn get_to_boolean(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    analyzer.factory.boolean(true)
+  }
+
+  fn get_to_property_key(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    self.get_to_string(analyzer)
+  }
+
+  fn get_to_jsx_child(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    analyzer.factory.unknown
+  }
+
+  fn get_own_keys(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+  ) -> Option<Vec<(bool, Entity<'a>)>> {
+    self.statics.get_own_keys(analyzer, dep)
+  }
+
+  fn test_instanceof(
+    &'a self,
+    analyzer: &Analyzer<'a>,
+    prototype: ObjectPrototype<'a>,
+  ) -> Option<bool> {
+    self.statics.test_instanceof(analyzer, prototype)
+  }
+
+  fn test_typeof(&self) -> TypeofResult {
+    TypeofResult::Function
+  }
+
+  fn test_truthy(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn test_nullish(&self) -> Option<bool> {
+    Some(false)
+  }
+}
@@ -1,4 +1,4 @@
-use std::{cell::Cell, fmt::Debug};
+use std::{cell::Cell, fmt::Debug, marker::PhantomData};
 
 use super::{
   EnumeratedProperties, IteratedElements, ObjectPrototype, ObjectValue, TypeofResult, ValueTrait,
@@ -6,6 +6,7 @@ use super::{
 };
 use crate::{
   analyzer::{Analyzer, Factory},
+  builtins::BuiltinPrototype,
   dep::Dep,
   entity::Entity,
   use_consumed_flag,
@@ -16,6 +17,10 @@ trait BuiltinFnImpl<'a>: Debug {
   fn object(&self) -> Option<&'a ObjectValue<'a>> {
     None
   }
+  /// The `prototype` of a builtin class
+  fn prototype(&self) -> Option<&'a BuiltinPrototype<'a>> {
+    None
+  }
   fn call_impl(
     &self,
     analyzer: &mut Analyzer<'a>,
@@ -23,6 +28,14 @@ trait BuiltinFnImpl<'a>: Debug {
     this: Entity<'a>,
     args: Entity<'a>,
   ) -> Entity<'a>;
+  fn construct_impl(
+    &self,
+    _analyzer: &mut Analyzer<'a>,
+    _dep: Dep<'a>,
+    _args: Entity<'a>,
+  ) -> Option<Entity<'a>> {
+    None
+  }
   fn consume(&'a self, _analyzer: &mut Analyzer<'a>) {}
 }
 
@@ -106,7 +119,9 @@ impl<'a, T: BuiltinFnImpl<'a>> ValueTrait<'a> for T {
     dep: Dep<'a>,
     args: Entity<'a>,
   ) -> Entity<'a> {
-    consumed_object::construct(self, analyzer, dep, args)
+    self
+      .construct_impl(analyzer, dep, args)
+      .unwrap_or_else(|| consumed_object::construct(self, analyzer, dep, args))
   }
 
   fn jsx(&'a self, analyzer: &mut Analyzer<'a>, props: Entity<'a>) -> Entity<'a> {
@@ -160,6 +175,22 @@ impl<'a, T: BuiltinFnImpl<'a>> ValueTrait<'a> for T {
     None
   }
 
+  fn test_instanceof(
+    &'a self,
+    analyzer: &Analyzer<'a>,
+    prototype: ObjectPrototype<'a>,
+  ) -> Option<bool> {
+    match self.object() {
+      Some(object) => object.test_instanceof(analyzer, prototype),
+      None => ObjectPrototype::Builtin(&analyzer.builtins.prototypes.function)
+        .test_inherits(analyzer, prototype),
+    }
+  }
+
+  fn get_instance_prototype(&'a self) -> Option<ObjectPrototype<'a>> {
+    self.prototype().map(ObjectPrototype::Builtin)
+  }
+
   fn test_typeof(&self) -> TypeofResult {
     TypeofResult::Function
   }
@@ -248,6 +279,77 @@ impl<'a> Analyzer<'a> {
   }
 }
 
+pub trait BuiltinConstructorImplementation<'a>:
+  Fn(&mut Analyzer<'a>, Dep<'a>, Entity<'a>) -> Entity<'a>
+{
+}
+impl<'a, T: Fn(&mut Analyzer<'a>, Dep<'a>, Entity<'a>) -> Entity<'a>>
+  BuiltinConstructorImplementation<'a> for T
+{
+}
+
+/// Behavior of calling a builtin class without `new`
+pub type BuiltinConstructorCall<'a> = fn(&mut Analyzer<'a>, Dep<'a>, Entity<'a>) -> Entity<'a>;
+
+/// A builtin class. Calling it without `new` throws unless `call` is provided
+pub struct ImplementedBuiltinConstructorValue<'a, C: BuiltinConstructorImplementation<'a> + 'a> {
+  pub name: &'static str,
+  pub implementation: C,
+  pub object: &'a ObjectValue<'a>,
+  /// The prototype of the instances, if they are tracked
+  pub prototype: Option<&'a BuiltinPrototype<'a>>,
+  pub call: Option<BuiltinConstructorCall<'a>>,
+  pub phantom: PhantomData<&'a ()>,
+}
+
+impl<'a, C: BuiltinConstructorImplementation<'a> + 'a> Debug
+  for ImplementedBuiltinConstructorValue<'a, C>
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("ImplementedBuiltinConstructorValue").finish()
+  }
+}
+
+impl<'a, C: BuiltinConstructorImplementation<'a> + 'a> BuiltinFnImpl<'a>
+  for ImplementedBuiltinConstructorValue<'a, C>
+{
+  fn name(&self) -> &'static str {
+    self.name
+  }
+  fn object(&self) -> Option<&'a ObjectValue<'a>> {
+    Some(self.object)
+  }
+  fn prototype(&self) -> Option<&'a BuiltinPrototype<'a>> {
+    self.prototype
+  }
+  fn call_impl(
+    &self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    this: Entity<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    if let Some(call) = self.call {
+      return call(analyzer, dep, args);
+    }
+    analyzer.throw_builtin_error(format!("Constructor {} requires 'new'", self.name));
+    if analyzer.config.preserve_exceptions {
+      analyzer.consume((dep, this, args));
+      analyzer.factory.unknown
+    } else {
+      analyzer.factory.never
+    }
+  }
+  fn construct_impl(
+    &self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    args: Entity<'a>,
+  ) -> Option<Entity<'a>> {
+    Some((self.implementation)(analyzer, dep, args))
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct PureBuiltinFnValue<'a> {
   return_value: fn(&Factory<'a>) -> Entity<'a>,
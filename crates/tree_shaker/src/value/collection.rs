@@ -0,0 +1,786 @@
+use std::{
+  cell::{Cell, RefCell},
+  fmt,
+};
+
+use oxc::allocator;
+
+use super::{
+  EnumeratedProperties, IteratedElements, LiteralValue, ObjectId, ObjectPrototype,
+  PropertyKeyValue, TypeofResult, ValueTrait, consumed_object,
+};
+use crate::{
+  analyzer::{Analyzer, exhaustive::ExhaustiveDepId},
+  builtins::{BuiltinPrototype, BuiltinPrototypes},
+  dep::{Dep, DepCollector, DepVec},
+  entity::Entity,
+  scope::CfScopeId,
+  use_consumed_flag,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollectionKind {
+  Map,
+  Set,
+  WeakMap,
+  WeakSet,
+}
+
+impl CollectionKind {
+  pub fn is_map(self) -> bool {
+    matches!(self, CollectionKind::Map | CollectionKind::WeakMap)
+  }
+
+  pub fn is_weak(self) -> bool {
+    matches!(self, CollectionKind::WeakMap | CollectionKind::WeakSet)
+  }
+
+  pub fn prototype<'a>(self, prototypes: &'a BuiltinPrototypes<'a>) -> &'a BuiltinPrototype<'a> {
+    match self {
+      CollectionKind::Map => &prototypes.map,
+      CollectionKind::Set => &prototypes.set,
+      CollectionKind::WeakMap => &prototypes.weak_map,
+      CollectionKind::WeakSet => &prototypes.weak_set,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum CollectionMethod {
+  Get,
+  Set,
+  Add,
+  Has,
+  Delete,
+  Clear,
+  ForEach,
+  Keys,
+  Values,
+  Entries,
+}
+
+impl CollectionMethod {
+  fn from_name(kind: CollectionKind, name: &str) -> Option<Self> {
+    let method = match name {
+      "get" if kind.is_map() => CollectionMethod::Get,
+      "set" if kind.is_map() => CollectionMethod::Set,
+      "add" if !kind.is_map() => CollectionMethod::Add,
+      "has" => CollectionMethod::Has,
+      "delete" => CollectionMethod::Delete,
+      "clear" if !kind.is_weak() => CollectionMethod::Clear,
+      "forEach" if !kind.is_weak() => CollectionMethod::ForEach,
+      "keys" if !kind.is_weak() => CollectionMethod::Keys,
+      "values" if !kind.is_weak() => CollectionMethod::Values,
+      "entries" if !kind.is_weak() => CollectionMethod::Entries,
+      _ => return None,
+    };
+    Some(method)
+  }
+
+  fn name(self, kind: CollectionKind) -> &'static str {
+    match (kind, self) {
+      (CollectionKind::Map, CollectionMethod::Get) => "Map.prototype.get",
+      (CollectionKind::Map, CollectionMethod::Set) => "Map.prototype.set",
+      (CollectionKind::Map, CollectionMethod::Has) => "Map.prototype.has",
+      (CollectionKind::Map, CollectionMethod::Delete) => "Map.prototype.delete",
+      (CollectionKind::Map, CollectionMethod::Clear) => "Map.prototype.clear",
+      (CollectionKind::Map, CollectionMethod::ForEach) => "Map.prototype.forEach",
+      (CollectionKind::Map, CollectionMethod::Keys) => "Map.prototype.keys",
+      (CollectionKind::Map, CollectionMethod::Values) => "Map.prototype.values",
+      (CollectionKind::Map, CollectionMethod::Entries) => "Map.prototype.entries",
+      (CollectionKind::Set, CollectionMethod::Add) => "Set.prototype.add",
+      (CollectionKind::Set, CollectionMethod::Has) => "Set.prototype.has",
+      (CollectionKind::Set, CollectionMethod::Delete) => "Set.prototype.delete",
+      (CollectionKind::Set, CollectionMethod::Clear) => "Set.prototype.clear",
+      (CollectionKind::Set, CollectionMethod::ForEach) => "Set.prototype.forEach",
+      (CollectionKind::Set, CollectionMethod::Keys) => "Set.prototype.keys",
+      (CollectionKind::Set, CollectionMethod::Values) => "Set.prototype.values",
+      (CollectionKind::Set, CollectionMethod::Entries) => "Set.prototype.entries",
+      (CollectionKind::WeakMap, CollectionMethod::Get) => "WeakMap.prototype.get",
+      (CollectionKind::WeakMap, CollectionMethod::Set) => "WeakMap.prototype.set",
+      (CollectionKind::WeakMap, CollectionMethod::Has) => "WeakMap.prototype.has",
+      (CollectionKind::WeakMap, CollectionMethod::Delete) => "WeakMap.prototype.delete",
+      (CollectionKind::WeakSet, CollectionMethod::Add) => "WeakSet.prototype.add",
+      (CollectionKind::WeakSet, CollectionMethod::Has) => "WeakSet.prototype.has",
+      (CollectionKind::WeakSet, CollectionMethod::Delete) => "WeakSet.prototype.delete",
+      _ => unreachable!(),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct CollectionEntry<'a> {
+  pub key_literal: LiteralValue<'a>,
+  /// The keys passed to `set`/`add`, each carrying the deps of the insertion
+  pub keys: allocator::Vec<'a, Entity<'a>>,
+  /// Possible values of this entry. Empty if the entry definitely doesn't exist
+  pub values: allocator::Vec<'a, Entity<'a>>,
+  /// Does this entry definitely exist
+  pub definite: bool,
+  /// Why this entry is non-existent
+  pub non_existent: DepCollector<'a>,
+}
+
+/// `Map`, `Set`, `WeakMap` and `WeakSet` instances. Entries keyed by literals are tracked
+/// precisely, while any other mutation makes the whole collection unknown.
+pub struct CollectionValue<'a> {
+  pub kind: CollectionKind,
+  pub consumed: Cell<bool>,
+  /// Non-empty if the entries are not precisely known
+  pub deps: RefCell<DepCollector<'a>>,
+  pub cf_scope: CfScopeId,
+  pub object_id: ObjectId,
+  /// In insertion order
+  pub entries: RefCell<allocator::Vec<'a, CollectionEntry<'a>>>,
+  /// Whether the iteration order is known
+  pub ordered: Cell<bool>,
+  /// Counts the mutations, to find out whether a callback mutates the collection
+  pub mutations: Cell<usize>,
+}
+
+impl fmt::Debug for CollectionValue<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("CollectionValue")
+      .field("kind", &self.kind)
+      .field("consumed", &self.consumed.get())
+      .field("deps", &self.deps.borrow())
+      .field("entries", &self.entries.borrow())
+      .finish()
+  }
+}
+
+impl<'a> ValueTrait<'a> for CollectionValue<'a> {
+  fn consume(&'a self, analyzer: &mut Analyzer<'a>) {
+    use_consumed_flag!(self);
+
+    self.deps.borrow().consume_all(analyzer);
+    for entry in self.entries.borrow().iter() {
+      for key in entry.keys.iter().chain(entry.values.iter()) {
+        analyzer.consume(*key);
+      }
+      entry.non_existent.consume_all(analyzer);
+    }
+
+    let target_depth = analyzer.find_first_different_cf_scope(self.cf_scope);
+    analyzer.mark_exhaustive_write(ExhaustiveDepId::ObjectAll(self.object_id), target_depth);
+  }
+
+  fn unknown_mutate(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) {
+    if self.consumed.get() {
+      return consumed_object::unknown_mutate(analyzer, dep);
+    }
+
+    let (is_exhaustive, _, exec_deps) = self.prepare_mutation(analyzer, dep);
+
+    if is_exhaustive {
+      self.consume(analyzer);
+      return consumed_object::unknown_mutate(analyzer, dep);
+    }
+
+    self.deps.borrow_mut().push(analyzer.dep(exec_deps));
+  }
+
+  fn get_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    key: Entity<'a>,
+  ) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::get_property(self, analyzer, dep, key);
+    }
+
+    let Some(key_literals) = key.get_to_literals(analyzer) else {
+      return analyzer.factory.computed_unknown((self, dep, key));
+    };
+
+    let mut values = analyzer.factory.vec();
+    for key_literal in key_literals {
+      let LiteralValue::String(key_str, _) = key_literal else {
+        values.push(analyzer.factory.unmatched_prototype_property);
+        continue;
+      };
+      if key_str == "size" && !self.kind.is_weak() {
+        values.push(self.get_size(analyzer));
+      } else if let Some(method) = CollectionMethod::from_name(self.kind, key_str) {
+        values.push(self.create_method(analyzer, method));
+      } else if let Some(property) = self
+        .kind
+        .prototype(analyzer.builtins.prototypes)
+        .get_keyed(PropertyKeyValue::String(key_str))
+      {
+        values.push(property);
+      } else {
+        values.push(analyzer.factory.unmatched_prototype_property);
+      }
+    }
+    analyzer.factory.computed_union(values, (dep, key))
+  }
+
+  fn set_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    key: Entity<'a>,
+    value: Entity<'a>,
+  ) {
+    self.unknown_mutate(analyzer, analyzer.dep((dep, key, value)));
+  }
+
+  fn enumerate_properties(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+  ) -> EnumeratedProperties<'a> {
+    if self.consumed.get() {
+      return consumed_object::enumerate_properties(self, analyzer, dep);
+    }
+
+    // Entries are not properties
+    EnumeratedProperties {
+      known: Default::default(),
+      unknown: (!self.deps.borrow().is_empty()).then_some(analyzer.factory.unknown),
+      dep: analyzer.dep((self.deps.borrow_mut().collect(analyzer.factory), dep)),
+    }
+  }
+
+  fn delete_property(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>, key: Entity<'a>) {
+    self.unknown_mutate(analyzer, analyzer.dep((dep, key)));
+  }
+
+  fn call(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    this: Entity<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    consumed_object::call(self, analyzer, dep, this, args)
+  }
+
+  fn construct(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    consumed_object::construct(self, analyzer, dep, args)
+  }
+
+  fn jsx(&'a self, analyzer: &mut Analyzer<'a>, props: Entity<'a>) -> Entity<'a> {
+    consumed_object::jsx(self, analyzer, props)
+  }
+
+  fn r#await(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::r#await(analyzer, dep);
+    }
+    analyzer.factory.computed(self.into(), dep)
+  }
+
+  fn iterate(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) -> IteratedElements<'a> {
+    if self.kind.is_weak() {
+      analyzer.throw_builtin_error(format!("{:?} is not iterable", self.kind));
+      return consumed_object::iterate(analyzer, analyzer.dep((self, dep)));
+    }
+
+    if self.consumed.get() {
+      return consumed_object::iterate(analyzer, dep);
+    }
+
+    analyzer.mark_exhaustive_read(ExhaustiveDepId::ObjectAll(self.object_id), self.cf_scope);
+
+    if !self.deps.borrow().is_empty() {
+      return (vec![], Some(analyzer.factory.unknown), analyzer.dep((self, dep)));
+    }
+
+    let dep = analyzer.dep((self.collect_entries_dep(analyzer), dep));
+    let mut known = vec![];
+    let mut rest = analyzer.factory.vec();
+    let ordered = self.ordered.get();
+    for entry in self.entries.borrow().iter() {
+      if entry.values.is_empty() {
+        continue;
+      }
+      let item = self.create_entry_item(analyzer, entry);
+      if ordered && entry.definite && rest.is_empty() {
+        known.push(item);
+      } else {
+        rest.push(item);
+      }
+    }
+    (known, analyzer.factory.try_union(rest), dep)
+  }
+
+  fn get_to_string(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::get_to_string(analyzer);
+    }
+    analyzer.factory.computed_unknown_string(self)
+  }
+
+  fn get_to_numeric(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::get_to_numeric(analyzer);
+    }
+    analyzer.factory.computed_unknown(self)
+  }
+
+  fn get_to_boolean(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    analyzer.factory.boolean(true)
+  }
+
+  fn get_to_property_key(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    self.get_to_string(analyzer)
+  }
+
+  fn get_to_jsx_child(&'a self, _analyzer: &Analyzer<'a>) -> Entity<'a> {
+    self.into()
+  }
+
+  fn test_typeof(&self) -> TypeofResult {
+    TypeofResult::Object
+  }
+
+  fn test_truthy(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn test_nullish(&self) -> Option<bool> {
+    Some(false)
+  }
+
+  fn test_is_array(&self) -> Option<bool> {
+    Some(false)
+  }
+
+  fn test_instanceof(
+    &'a self,
+    analyzer: &Analyzer<'a>,
+    prototype: ObjectPrototype<'a>,
+  ) -> Option<bool> {
+    if self.consumed.get() {
+      return None;
+    }
+    ObjectPrototype::Builtin(self.kind.prototype(analyzer.builtins.prototypes))
+      .test_inherits(analyzer, prototype)
+  }
+}
+
+impl<'a> CollectionValue<'a> {
+  /// Used by the constructor to add the initial entries
+  pub fn init_entry(&'a self, analyzer: &mut Analyzer<'a>, key: Entity<'a>, value: Entity<'a>) {
+    let dep = analyzer.factory.no_dep;
+    if let Some(key_literal) = self.get_key_literal(analyzer, key) {
+      self.insert(analyzer, key_literal, key, value, false, dep);
+    } else {
+      self.deps.borrow_mut().push(analyzer.dep((key, value)));
+    }
+  }
+
+  fn create_method(&'a self, analyzer: &Analyzer<'a>, method: CollectionMethod) -> Entity<'a> {
+    analyzer.factory.implemented_consumable_fn(
+      method.name(self.kind),
+      move |analyzer, dep, this, args| {
+        if self.consumed.get() || !this.value_eq(self.into()) {
+          // Called with another `this`, or the method itself is consumed
+          analyzer.consume((self, dep, this, args));
+          return analyzer.factory.unknown;
+        }
+        self.call_method(analyzer, dep, method, args)
+      },
+    )
+  }
+
+  fn call_method(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    method: CollectionMethod,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    match method {
+      CollectionMethod::Get | CollectionMethod::Has => {
+        let key = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+        self.read_entry(analyzer, dep, key, matches!(method, CollectionMethod::Has))
+      }
+      CollectionMethod::Set | CollectionMethod::Add => {
+        let args = args.destruct_as_array(analyzer, dep, 2, false).0;
+        let (key, value) = (args[0], if self.kind.is_map() { args[1] } else { args[0] });
+        self.write_entry(analyzer, dep, key, value);
+        analyzer.factory.computed(self.into(), dep)
+      }
+      CollectionMethod::Delete => {
+        let key = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+        self.delete_entry(analyzer, dep, key)
+      }
+      CollectionMethod::Clear => {
+        self.clear_entries(analyzer, dep);
+        analyzer.factory.undefined
+      }
+      CollectionMethod::ForEach => {
+        let args = args.destruct_as_array(analyzer, dep, 2, false).0;
+        self.for_each(analyzer, dep, args[0], args[1]);
+        analyzer.factory.undefined
+      }
+      CollectionMethod::Keys | CollectionMethod::Values | CollectionMethod::Entries => {
+        analyzer.mark_exhaustive_read(ExhaustiveDepId::ObjectAll(self.object_id), self.cf_scope);
+        analyzer.factory.computed_unknown((self, dep))
+      }
+    }
+  }
+
+  fn read_entry(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    key: Entity<'a>,
+    is_has: bool,
+  ) -> Entity<'a> {
+    analyzer.mark_exhaustive_read(ExhaustiveDepId::ObjectAll(self.object_id), self.cf_scope);
+
+    let key_literal = self.get_key_literal(analyzer, key);
+    let Some(key_literal) = key_literal.filter(|_| self.deps.borrow().is_empty()) else {
+      return if is_has {
+        analyzer.factory.computed_unknown_boolean((self, dep, key))
+      } else {
+        analyzer.factory.computed_unknown((self, dep, key))
+      };
+    };
+
+    let mut entries = self.entries.borrow_mut();
+    let Some(entry) = entries.iter_mut().find(|entry| entry.key_literal == key_literal) else {
+      return analyzer.factory.computed(
+        if is_has { analyzer.factory.r#false } else { analyzer.factory.undefined },
+        (dep, key),
+      );
+    };
+
+    let non_existent = entry.non_existent.collect(analyzer.factory);
+    if is_has {
+      let keys = allocator::Vec::from_iter_in(entry.keys.iter().copied(), analyzer.allocator);
+      let result = match (entry.definite, entry.values.is_empty()) {
+        (true, _) => analyzer.factory.r#true,
+        (false, true) => analyzer.factory.r#false,
+        (false, false) => analyzer.factory.unknown_boolean,
+      };
+      analyzer.factory.computed(result, (dep, (key, (keys, non_existent))))
+    } else {
+      let mut values =
+        allocator::Vec::from_iter_in(entry.values.iter().copied(), analyzer.allocator);
+      if !entry.definite {
+        values.push(analyzer.factory.undefined);
+      }
+      analyzer.factory.computed_union(values, (dep, (key, non_existent)))
+    }
+  }
+
+  fn write_entry(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    key: Entity<'a>,
+    value: Entity<'a>,
+  ) {
+    let (is_exhaustive, indeterminate, exec_deps) = self.prepare_mutation(analyzer, dep);
+
+    if is_exhaustive {
+      self.consume(analyzer);
+      return consumed_object::set_property(analyzer, dep, key, value);
+    }
+
+    let exec_dep = analyzer.dep(exec_deps);
+    match self.get_key_literal(analyzer, key).filter(|_| self.deps.borrow().is_empty()) {
+      Some(key_literal) => self.insert(analyzer, key_literal, key, value, indeterminate, exec_dep),
+      None => self.deps.borrow_mut().push(analyzer.dep((exec_dep, key, value))),
+    }
+  }
+
+  fn delete_entry(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    key: Entity<'a>,
+  ) -> Entity<'a> {
+    let (is_exhaustive, indeterminate, exec_deps) = self.prepare_mutation(analyzer, dep);
+
+    if is_exhaustive {
+      self.consume(analyzer);
+      consumed_object::delete_property(analyzer, dep, key);
+      return analyzer.factory.unknown_boolean;
+    }
+
+    let exec_dep = analyzer.dep(exec_deps);
+    let key_literal = self.get_key_literal(analyzer, key);
+    let Some(key_literal) = key_literal.filter(|_| self.deps.borrow().is_empty()) else {
+      self.deps.borrow_mut().push(analyzer.dep((exec_dep, key)));
+      return analyzer.factory.computed_unknown_boolean((self, exec_dep, key));
+    };
+
+    let mut entries = self.entries.borrow_mut();
+    let Some(entry) = entries.iter_mut().find(|entry| entry.key_literal == key_literal) else {
+      return analyzer.factory.computed(analyzer.factory.r#false, (dep, key));
+    };
+
+    let keys = allocator::Vec::from_iter_in(entry.keys.iter().copied(), analyzer.allocator);
+    let deleted = match (entry.definite, entry.values.is_empty()) {
+      (true, _) if !indeterminate => analyzer.factory.r#true,
+      (false, true) => analyzer.factory.r#false,
+      _ => analyzer.factory.unknown_boolean,
+    };
+    let deleted = analyzer
+      .factory
+      .computed(deleted, (exec_dep, (key, (keys, entry.non_existent.collect(analyzer.factory)))));
+
+    if !indeterminate {
+      entry.values.clear();
+    }
+    entry.definite = false;
+    entry.non_existent.push(analyzer.dep((exec_dep, key)));
+
+    deleted
+  }
+
+  fn clear_entries(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) {
+    let (is_exhaustive, indeterminate, exec_deps) = self.prepare_mutation(analyzer, dep);
+
+    if is_exhaustive {
+      self.consume(analyzer);
+      return consumed_object::unknown_mutate(analyzer, dep);
+    }
+
+    let exec_dep = analyzer.dep(exec_deps);
+    if !self.deps.borrow().is_empty() {
+      self.deps.borrow_mut().push(exec_dep);
+      return;
+    }
+
+    for entry in self.entries.borrow_mut().iter_mut() {
+      if !indeterminate {
+        entry.values.clear();
+      }
+      entry.definite = false;
+      entry.non_existent.push(exec_dep);
+    }
+  }
+
+  /// The callback is called with the known entries one by one. Once it mutates the collection,
+  /// the remaining entries are unknown, so it is called with any entry in an indeterminate scope.
+  fn for_each(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    callback: Entity<'a>,
+    this_arg: Entity<'a>,
+  ) {
+    let (known, mut rest, mut iterate_dep) = self.iterate(analyzer, dep);
+    let self_entity: Entity<'a> = self.into();
+    let call = |analyzer: &mut Analyzer<'a>, item: Entity<'a>, iterate_dep: Dep<'a>| {
+      let args = if self.kind.is_map() {
+        let item = item.destruct_as_array(analyzer, iterate_dep, 2, false).0;
+        allocator::Vec::from_iter_in(
+          [(false, item[1]), (false, item[0]), (false, self_entity)],
+          analyzer.allocator,
+        )
+      } else {
+        allocator::Vec::from_iter_in(
+          [(false, item), (false, item), (false, self_entity)],
+          analyzer.allocator,
+        )
+      };
+      let args = analyzer.factory.arguments(args);
+      callback.call(analyzer, iterate_dep, this_arg, args);
+    };
+
+    for item in known {
+      let mutations = self.mutations.get();
+      call(analyzer, item, iterate_dep);
+      if self.mutations.get() != mutations {
+        let (known, remaining, remaining_dep) = self.iterate(analyzer, dep);
+        let mut items = analyzer.factory.vec();
+        items.extend(known);
+        items.extend(remaining);
+        rest = analyzer.factory.try_union(items);
+        iterate_dep = remaining_dep;
+        break;
+      }
+    }
+    if let Some(rest) = rest {
+      analyzer.push_indeterminate_cf_scope();
+      call(analyzer, rest, iterate_dep);
+      analyzer.pop_cf_scope();
+    }
+  }
+
+  fn get_size(&'a self, analyzer: &mut Analyzer<'a>) -> Entity<'a> {
+    analyzer.mark_exhaustive_read(ExhaustiveDepId::ObjectAll(self.object_id), self.cf_scope);
+
+    if !self.deps.borrow().is_empty() {
+      return analyzer.factory.computed_unknown_number(self);
+    }
+
+    let mut size = 0;
+    let mut known = true;
+    for entry in self.entries.borrow().iter() {
+      if entry.definite {
+        size += 1;
+      } else if !entry.values.is_empty() {
+        known = false;
+      }
+    }
+    let dep = self.collect_entries_dep(analyzer);
+    analyzer.factory.computed(
+      if known {
+        analyzer.factory.number(size as f64, None)
+      } else {
+        analyzer.factory.unknown_number
+      },
+      dep,
+    )
+  }
+
+  fn insert(
+    &self,
+    analyzer: &mut Analyzer<'a>,
+    key_literal: LiteralValue<'a>,
+    key: Entity<'a>,
+    value: Entity<'a>,
+    indeterminate: bool,
+    exec_dep: Dep<'a>,
+  ) {
+    let key = analyzer.factory.computed(key, exec_dep);
+    let value = analyzer.factory.computed(value, exec_dep);
+
+    let mut entries = self.entries.borrow_mut();
+    let index = entries.iter().position(|entry| entry.key_literal == key_literal);
+    if let Some(index) = index {
+      let entry = &mut entries[index];
+      entry.keys.push(key);
+      if entry.values.is_empty() {
+        // Re-inserted entries are moved to the end
+        let mut entry = entries.remove(index);
+        entry.values.push(value);
+        entry.definite = !indeterminate;
+        entries.push(entry);
+      } else if entry.definite || indeterminate {
+        if !indeterminate {
+          entry.values.clear();
+        }
+        entry.values.push(value);
+      } else {
+        // Whether the entry is moved to the end is unknown
+        self.ordered.set(false);
+        entry.values.clear();
+        entry.values.push(value);
+        entry.definite = true;
+      }
+    } else {
+      entries.push(CollectionEntry {
+        key_literal,
+        keys: analyzer.factory.vec1(key),
+        values: analyzer.factory.vec1(value),
+        definite: !indeterminate,
+        non_existent: DepCollector::new(analyzer.factory.vec()),
+      });
+    }
+  }
+
+  /// `[key, value]` for `Map`, `value` for `Set`
+  fn create_entry_item(
+    &self,
+    analyzer: &mut Analyzer<'a>,
+    entry: &CollectionEntry<'a>,
+  ) -> Entity<'a> {
+    let key = analyzer
+      .factory
+      .union(allocator::Vec::from_iter_in(entry.keys.iter().copied(), analyzer.allocator));
+    if self.kind.is_map() {
+      let value = analyzer
+        .factory
+        .union(allocator::Vec::from_iter_in(entry.values.iter().copied(), analyzer.allocator));
+      let item = analyzer.new_empty_array();
+      item.push_element(key);
+      item.push_element(value);
+      item.into()
+    } else {
+      key
+    }
+  }
+
+  fn collect_entries_dep(&self, analyzer: &Analyzer<'a>) -> Dep<'a> {
+    let mut deps = analyzer.factory.vec();
+    for entry in self.entries.borrow_mut().iter_mut() {
+      deps.push(
+        analyzer.dep(allocator::Vec::from_iter_in(entry.keys.iter().copied(), analyzer.allocator)),
+      );
+      if let Some(dep) = entry.non_existent.try_collect(analyzer.factory) {
+        deps.push(dep);
+      }
+    }
+    analyzer.dep(deps)
+  }
+
+  /// Keys are compared by SameValueZero
+  fn get_key_literal(
+    &self,
+    analyzer: &mut Analyzer<'a>,
+    key: Entity<'a>,
+  ) -> Option<LiteralValue<'a>> {
+    if self.kind.is_weak() {
+      return None;
+    }
+    Some(match key.get_literal(analyzer)? {
+      LiteralValue::String(value, atom) => {
+        // The key can't be mangled since it is observable through iteration
+        analyzer.consume(atom);
+        LiteralValue::String(value, None)
+      }
+      LiteralValue::Number(value, _) => {
+        LiteralValue::Number(if value.0 == 0.0 { 0.0.into() } else { value }, None)
+      }
+      LiteralValue::Symbol(_, _) => return None,
+      literal => literal,
+    })
+  }
+
+  fn prepare_mutation(
+    &self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+  ) -> (bool, bool, DepVec<'a>) {
+    let target_depth = analyzer.find_first_different_cf_scope(self.cf_scope);
+
+    let mut is_exhaustive = false;
+    let mut indeterminate = false;
+    let mut exec_deps = analyzer.factory.vec1(dep);
+    for depth in target_depth..analyzer.scoping.cf.stack.len() {
+      let scope = analyzer.scoping.cf.get_mut_from_depth(depth);
+      is_exhaustive |= scope.is_exhaustive();
+      indeterminate |= scope.is_indeterminate();
+      if let Some(dep) = scope.deps.try_collect(analyzer.factory) {
+        exec_deps.push(dep);
+      }
+    }
+
+    analyzer.mark_exhaustive_write(ExhaustiveDepId::ObjectAll(self.object_id), target_depth);
+    analyzer.request_exhaustive_callbacks(ExhaustiveDepId::ObjectAll(self.object_id));
+    self.mutations.set(self.mutations.get() + 1);
+
+    (is_exhaustive, indeterminate, exec_deps)
+  }
+}
+
+impl<'a> Analyzer<'a> {
+  pub fn new_collection(&mut self, kind: CollectionKind) -> &'a mut CollectionValue<'a> {
+    self.allocator.alloc(CollectionValue {
+      kind,
+      consumed: Cell::new(false),
+      deps: RefCell::new(DepCollector::new(self.factory.vec())),
+      cf_scope: self.scoping.cf.current_id(),
+      object_id: self.scoping.alloc_object_id(),
+      entries: RefCell::new(self.factory.vec()),
+      ordered: Cell::new(true),
+      mutations: Cell::new(0),
+    })
+  }
+}
@@ -57,6 +57,8 @@ impl<'a> ValueTrait<'a> for FunctionValue<'a> {
   ) {
     // TODO: Support analyzing this kind of mutation
     if analyzer.op_strict_eq(key, analyzer.factory.string("prototype")).0 != Some(false) {
+      // The existing instances still inherit from the previous prototype
+      self.prototype.consume(analyzer);
       return consumed_object::set_property(analyzer, dep, key, value);
     }
 
@@ -154,8 +156,12 @@ impl<'a> ValueTrait<'a> for FunctionValue<'a> {
     analyzer.factory.unknown
   }
 
-  fn get_own_keys(&'a self, analyzer: &Analyzer<'a>) -> Option<Vec<(bool, Entity<'a>)>> {
-    self.statics.get_own_keys(analyzer)
+  fn get_own_keys(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+  ) -> Option<Vec<(bool, Entity<'a>)>> {
+    self.statics.get_own_keys(analyzer, dep)
   }
 
   fn get_constructor_prototype(
@@ -166,6 +172,24 @@ impl<'a> ValueTrait<'a> for FunctionValue<'a> {
     Some((dep, ObjectPrototype::Custom(self.statics), ObjectPrototype::Custom(self.prototype)))
   }
 
+  fn test_instanceof(
+    &'a self,
+    analyzer: &Analyzer<'a>,
+    prototype: ObjectPrototype<'a>,
+  ) -> Option<bool> {
+    self.statics.test_instanceof(analyzer, prototype)
+  }
+
+  fn get_instance_prototype(&'a self) -> Option<ObjectPrototype<'a>> {
+    let has_prototype = match self.callee.node {
+      CalleeNode::Function(node) => !node.r#async,
+      CalleeNode::ClassConstructor(_) => true,
+      _ => false,
+    };
+    (has_prototype && !self.prototype.consumed.get() && !self.statics.may_have_instance_check())
+      .then_some(ObjectPrototype::Custom(self.prototype))
+  }
+
   fn test_typeof(&self) -> TypeofResult {
     TypeofResult::Function
   }
@@ -0,0 +1,167 @@
+use super::{
+  EnumeratedProperties, IteratedElements, LiteralValue, TypeofResult, ValueTrait, consumed_object,
+};
+use crate::{analyzer::Analyzer, dep::Dep, entity::Entity};
+
+/// Objects provided by the host environment, like `process` and `document`, whose properties are
+/// unknown. The properties of the global object alias the builtin globals.
+#[derive(Debug)]
+pub struct HostObjectValue {
+  pub is_global: bool,
+}
+
+impl<'a> ValueTrait<'a> for HostObjectValue {
+  fn consume(&'a self, _analyzer: &mut Analyzer<'a>) {}
+
+  fn unknown_mutate(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) {
+    if self.is_global {
+      analyzer.builtins.redefine_all_globals();
+    }
+    consumed_object::unknown_mutate(analyzer, dep)
+  }
+
+  fn get_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    key: Entity<'a>,
+  ) -> Entity<'a> {
+    if self.is_global {
+      if let Some(value) = self.get_builtin_global(analyzer, key) {
+        return analyzer.factory.computed(value, (dep, key));
+      }
+    }
+    consumed_object::get_property(self, analyzer, dep, key)
+  }
+
+  fn set_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    key: Entity<'a>,
+    value: Entity<'a>,
+  ) {
+    if self.is_global {
+      self.redefine_globals(analyzer, key);
+    }
+    consumed_object::set_property(analyzer, dep, key, value)
+  }
+
+  fn enumerate_properties(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+  ) -> EnumeratedProperties<'a> {
+    consumed_object::enumerate_properties(self, analyzer, dep)
+  }
+
+  fn delete_property(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>, key: Entity<'a>) {
+    if self.is_global {
+      self.redefine_globals(analyzer, key);
+    }
+    consumed_object::delete_property(analyzer, dep, key)
+  }
+
+  fn call(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    this: Entity<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    consumed_object::call(self, analyzer, dep, this, args)
+  }
+
+  fn construct(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    consumed_object::construct(self, analyzer, dep, args)
+  }
+
+  fn jsx(&'a self, analyzer: &mut Analyzer<'a>, props: Entity<'a>) -> Entity<'a> {
+    consumed_object::jsx(self, analyzer, props)
+  }
+
+  fn r#await(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) -> Entity<'a> {
+    consumed_object::r#await(analyzer, dep)
+  }
+
+  fn iterate(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) -> IteratedElements<'a> {
+    consumed_object::iterate(analyzer, dep)
+  }
+
+  fn get_to_string(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    consumed_object::get_to_string(analyzer)
+  }
+
+  fn get_to_numeric(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    consumed_object::get_to_numeric(analyzer)
+  }
+
+  fn get_to_boolean(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    analyzer.factory.boolean(true)
+  }
+
+  fn get_to_property_key(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    self.get_to_string(analyzer)
+  }
+
+  fn get_to_jsx_child(&'a self, _analyzer: &Analyzer<'a>) -> Entity<'a> {
+    self.into()
+  }
+
+  fn test_typeof(&self) -> TypeofResult {
+    TypeofResult::Object
+  }
+
+  fn test_truthy(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn test_nullish(&self) -> Option<bool> {
+    Some(false)
+  }
+
+  fn test_is_array(&self) -> Option<bool> {
+    Some(false)
+  }
+}
+
+impl HostObjectValue {
+  /// The globals written through the global object are no longer the ones of the target
+  /// environment
+  fn redefine_globals<'a>(&self, analyzer: &mut Analyzer<'a>, key: Entity<'a>) {
+    let key = key.get_to_property_key(analyzer);
+    let Some(key_literals) = key.get_to_literals(analyzer) else {
+      analyzer.builtins.redefine_all_globals();
+      return;
+    };
+    for key_literal in key_literals {
+      if let LiteralValue::String(key, _) = key_literal {
+        analyzer.builtins.redefine_global(key);
+      }
+    }
+  }
+
+  /// Returns the builtin globals if all the possible keys are known ones
+  fn get_builtin_global<'a>(
+    &self,
+    analyzer: &mut Analyzer<'a>,
+    key: Entity<'a>,
+  ) -> Option<Entity<'a>> {
+    let key = key.get_to_property_key(analyzer);
+    let mut values = analyzer.factory.vec();
+    for key_literal in key.get_to_literals(analyzer)? {
+      let LiteralValue::String(key, atom) = key_literal else {
+        return None;
+      };
+      values.push(*analyzer.builtins.globals.get(key)?);
+      // The names of globals can't be mangled
+      analyzer.consume(atom);
+    }
+    analyzer.factory.try_union(values)
+  }
+}
@@ -275,7 +275,11 @@ impl<'a> ValueTrait<'a> for LiteralValue<'a> {
     Some(*self)
   }
 
-  fn get_own_keys(&'a self, _analyzer: &Analyzer<'a>) -> Option<Vec<(bool, Entity<'a>)>> {
+  fn get_own_keys(
+    &'a self,
+    _analyzer: &mut Analyzer<'a>,
+    _dep: Dep<'a>,
+  ) -> Option<Vec<(bool, Entity<'a>)>> {
     match self {
       LiteralValue::String(_, _) => None,
       _ => Some(vec![]),
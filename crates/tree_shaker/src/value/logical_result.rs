@@ -83,6 +83,14 @@ impl<'a> ValueTrait<'a> for LogicalResultValue<'a> {
     self.value.iterate(analyzer, dep)
   }
 
+  fn iterate_partially(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+  ) -> IteratedElements<'a> {
+    self.value.iterate_partially(analyzer, dep)
+  }
+
   fn get_shallow_dep(&'a self, analyzer: &Analyzer<'a>) -> Dep<'a> {
     self.value.get_shallow_dep(analyzer)
   }
@@ -114,8 +122,12 @@ impl<'a> ValueTrait<'a> for LogicalResultValue<'a> {
     self.value.get_to_jsx_child(analyzer)
   }
 
-  fn get_own_keys(&'a self, analyzer: &Analyzer<'a>) -> Option<Vec<(bool, Entity<'a>)>> {
-    self.value.get_own_keys(analyzer)
+  fn get_own_keys(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+  ) -> Option<Vec<(bool, Entity<'a>)>> {
+    self.value.get_own_keys(analyzer, dep)
   }
 
   fn get_constructor_prototype(
@@ -137,4 +149,20 @@ impl<'a> ValueTrait<'a> for LogicalResultValue<'a> {
   fn test_nullish(&self) -> Option<bool> {
     if self.is_coalesce { self.result } else { self.value.test_nullish() }
   }
+
+  fn test_is_array(&self) -> Option<bool> {
+    self.value.test_is_array()
+  }
+
+  fn test_instanceof(
+    &'a self,
+    analyzer: &Analyzer<'a>,
+    prototype: ObjectPrototype<'a>,
+  ) -> Option<bool> {
+    self.value.test_instanceof(analyzer, prototype)
+  }
+
+  fn get_instance_prototype(&'a self) -> Option<ObjectPrototype<'a>> {
+    self.value.get_instance_prototype()
+  }
 }
@@ -1,14 +1,22 @@
 pub mod arguments;
 pub mod array;
+pub mod array_buffer;
+pub mod bound_function;
 pub mod builtin_fn;
+pub mod collection;
 mod consumed_object;
 mod function;
+pub mod host_object;
 mod literal;
 pub mod logical_result;
 pub mod never;
 mod object;
 pub mod primitive;
+pub mod promise;
+pub mod proxy;
 pub mod react_element;
+pub mod regexp;
+pub mod symbol;
 mod typeof_result;
 pub mod union;
 pub mod unknown;
@@ -88,6 +96,14 @@ pub trait ValueTrait<'a>: Debug {
   fn jsx(&'a self, analyzer: &mut Analyzer<'a>, props: Entity<'a>) -> Entity<'a>;
   fn r#await(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) -> Entity<'a>;
   fn iterate(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) -> IteratedElements<'a>;
+  /// Like `iterate`, but the consumer may stop before the iterator is done
+  fn iterate_partially(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+  ) -> IteratedElements<'a> {
+    self.iterate(analyzer, dep)
+  }
 
   fn get_shallow_dep(&'a self, analyzer: &Analyzer<'a>) -> Dep<'a> {
     analyzer.factory.no_dep
@@ -106,7 +122,11 @@ pub trait ValueTrait<'a>: Debug {
       .and_then(|set| if set.len() == 1 { set.into_iter().next() } else { None })
   }
   /// Returns vec![(definite, key)]
-  fn get_own_keys(&'a self, _analyzer: &Analyzer<'a>) -> Option<Vec<(bool, Entity<'a>)>> {
+  fn get_own_keys(
+    &'a self,
+    _analyzer: &mut Analyzer<'a>,
+    _dep: Dep<'a>,
+  ) -> Option<Vec<(bool, Entity<'a>)>> {
     None
   }
   fn get_constructor_prototype(
@@ -116,10 +136,57 @@ pub trait ValueTrait<'a>: Debug {
   ) -> Option<(Dep<'a>, ObjectPrototype<'a>, ObjectPrototype<'a>)> {
     None
   }
+  /// Returns the underlying array if this is exactly an array value
+  fn as_array(&'a self) -> Option<&'a array::ArrayValue<'a>> {
+    None
+  }
+  /// Returns the underlying object if this is exactly an object value
+  fn as_object(&'a self) -> Option<&'a ObjectValue<'a>> {
+    None
+  }
+  /// Returns the underlying promise if this is exactly a promise value
+  fn as_promise(&'a self) -> Option<&'a promise::PromiseValue<'a>> {
+    None
+  }
+  /// Returns the underlying regular expression if this is exactly a `RegExp` instance
+  fn as_regexp(&'a self) -> Option<&'a regexp::RegExpValue<'a>> {
+    None
+  }
+  /// Returns the underlying buffer if this is exactly an `ArrayBuffer` instance
+  fn as_array_buffer(&'a self) -> Option<&'a array_buffer::ArrayBufferValue<'a>> {
+    None
+  }
 
   fn test_typeof(&self) -> TypeofResult;
   fn test_truthy(&self) -> Option<bool>;
   fn test_nullish(&self) -> Option<bool>;
+  fn test_is_array(&self) -> Option<bool> {
+    if self.test_typeof().contains(TypeofResult::Object) { None } else { Some(false) }
+  }
+  /// Whether `prototype` is in the prototype chain of this value
+  fn test_instanceof(
+    &'a self,
+    _analyzer: &Analyzer<'a>,
+    _prototype: ObjectPrototype<'a>,
+  ) -> Option<bool> {
+    if (TypeofResult::String
+      | TypeofResult::Number
+      | TypeofResult::BigInt
+      | TypeofResult::Boolean
+      | TypeofResult::Symbol
+      | TypeofResult::Undefined)
+      .contains(self.test_typeof())
+      || self.test_nullish() == Some(true)
+    {
+      Some(false)
+    } else {
+      None
+    }
+  }
+  /// The `prototype` of a constructor, which `instanceof` looks for in the prototype chain
+  fn get_instance_prototype(&'a self) -> Option<ObjectPrototype<'a>> {
+    None
+  }
   fn test_is_undefined(&self) -> Option<bool> {
     let t = self.test_typeof();
     match (t == TypeofResult::Undefined, t.contains(TypeofResult::Undefined)) {
@@ -136,7 +203,8 @@ pub trait ValueTrait<'a>: Debug {
     length: usize,
     need_rest: bool,
   ) -> (Vec<Entity<'a>>, Option<Entity<'a>>, Dep<'a>) {
-    let (mut elements, rest, dep) = self.iterate(analyzer, dep);
+    let (mut elements, rest, dep) =
+      if need_rest { self.iterate(analyzer, dep) } else { self.iterate_partially(analyzer, dep) };
     let iterated_len = elements.len();
     let extras = match iterated_len.cmp(&length) {
       Ordering::Equal => Vec::new(),
@@ -166,8 +234,10 @@ pub trait ValueTrait<'a>: Debug {
     &'a self,
     analyzer: &mut Analyzer<'a>,
     dep: Dep<'a>,
+    partially: bool,
   ) -> Option<Entity<'a>> {
-    let (elements, rest, deps) = self.iterate(analyzer, dep);
+    let (elements, rest, deps) =
+      if partially { self.iterate_partially(analyzer, dep) } else { self.iterate(analyzer, dep) };
     if let Some(rest) = rest {
       let mut result = allocator::Vec::from_iter_in(elements.iter().copied(), analyzer.allocator);
       result.push(rest);
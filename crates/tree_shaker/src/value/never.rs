@@ -89,7 +89,11 @@ impl<'a> ValueTrait<'a> for NeverValue {
   fn get_literal(&'a self, _analyzer: &Analyzer<'a>) -> Option<super::LiteralValue<'a>> {
     None
   }
-  fn get_own_keys(&'a self, _analyzer: &Analyzer<'a>) -> Option<Vec<(bool, Entity<'a>)>> {
+  fn get_own_keys(
+    &'a self,
+    _analyzer: &mut Analyzer<'a>,
+    _dep: Dep<'a>,
+  ) -> Option<Vec<(bool, Entity<'a>)>> {
     Some(vec![])
   }
 
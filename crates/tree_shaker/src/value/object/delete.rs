@@ -1,4 +1,4 @@
-use super::ObjectValue;
+use super::{ObjectIntegrity, ObjectValue};
 use crate::{
   analyzer::Analyzer,
   dep::{CustomDepTrait, Dep},
@@ -9,6 +9,9 @@ use crate::{
 
 impl<'a> ObjectValue<'a> {
   pub fn delete_property(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>, key: Entity<'a>) {
+    if self.may_forbid_mutation(ObjectIntegrity::Sealed) {
+      self.consume(analyzer);
+    }
     if self.consumed.get() {
       return consumed_object::delete_property(analyzer, dep, key);
     }
@@ -37,6 +40,7 @@ impl<'a> ObjectValue<'a> {
       for key_literal in key_literals {
         let (key_str, key_atom) = key_literal.into();
         if let Some(property) = string_keyed.get_mut(&key_str) {
+          self.key_order.replace(None);
           property.delete(
             indeterminate,
             if mangable {
@@ -64,6 +68,7 @@ impl<'a> ObjectValue<'a> {
 
       let deps = analyzer.dep((deps, key));
 
+      self.key_order.replace(None);
       let mut string_keyed = self.keyed.borrow_mut();
       for property in string_keyed.values_mut() {
         property.delete(true, deps);
@@ -67,17 +67,15 @@ impl<'a> ObjectValue<'a> {
         }
 
         let definite = property.definite;
-        let (key, key_entity) = if let PropertyKeyValue::String(key) = key {
-          (
-            PropertyKeyValue::String(key),
+        let key_entity = match key {
+          PropertyKeyValue::String(key) => {
             if mangable {
               analyzer.factory.mangable_string(key, property.mangling.unwrap())
             } else {
               analyzer.factory.string(key)
-            },
-          )
-        } else {
-          todo!()
+            }
+          }
+          PropertyKeyValue::Symbol(_) => property.key.unwrap(),
         };
 
         property.get(analyzer, &mut context, None);
@@ -68,6 +68,9 @@ impl<'a> ObjectValue<'a> {
           ),
         };
         let existing = keyed.get_mut(&key_str);
+        if existing.as_ref().is_none_or(|existing| !existing.definite) {
+          self.track_key_order(key_str, definite && existing.is_none());
+        }
         if definite || existing.is_none() {
           let property = ObjectProperty {
             definite,
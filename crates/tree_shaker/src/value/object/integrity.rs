@@ -0,0 +1,76 @@
+use super::{ObjectPropertyValue, ObjectValue};
+use crate::{
+  analyzer::{Analyzer, exhaustive::ExhaustiveDepId},
+  dep::Dep,
+  value::{ValueTrait, consumed_object},
+};
+
+/// Set by `Object.preventExtensions`, `Object.seal` and `Object.freeze`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ObjectIntegrity {
+  Extensible,
+  NonExtensible,
+  Sealed,
+  Frozen,
+}
+
+impl<'a> ObjectValue<'a> {
+  pub fn set_integrity(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    level: ObjectIntegrity,
+  ) {
+    if self.consumed.get() {
+      return consumed_object::unknown_mutate(analyzer, dep);
+    }
+
+    let (target_depth, is_exhaustive, indeterminate, deps) = self.prepare_mutation(analyzer, dep);
+    let dep = analyzer.dep(deps);
+    self.integrity_deps.borrow_mut().push(dep);
+
+    if is_exhaustive || indeterminate {
+      self.integrity.set(None);
+      if analyzer.config.preserve_writablity {
+        self.unknown_mutate(analyzer, dep);
+      }
+      return;
+    }
+
+    if let Some(integrity) = self.integrity.get() {
+      self.integrity.set(Some(integrity.max(level)));
+    }
+
+    if analyzer.config.preserve_writablity {
+      // Writes are now ignored, so the call must be kept as long as the object is used
+      self.add_extra_dep(dep);
+      if level == ObjectIntegrity::Frozen {
+        for property in self.keyed.borrow_mut().values_mut() {
+          property.possible_values.iter_mut().for_each(mark_readonly);
+        }
+        self.unknown.borrow_mut().possible_values.iter_mut().for_each(mark_readonly);
+        if let Some(rest) = &self.rest {
+          rest.borrow_mut().possible_values.iter_mut().for_each(mark_readonly);
+        }
+      }
+      analyzer.mark_exhaustive_write(ExhaustiveDepId::ObjectAll(self.object_id), target_depth);
+    }
+  }
+
+  /// Whether new properties can't be added
+  pub(super) fn is_non_extensible(&self) -> bool {
+    self.integrity.get().is_some_and(|integrity| integrity != ObjectIntegrity::Extensible)
+  }
+
+  /// Whether a mutation may be forbidden by the integrity level, which throws in strict mode.
+  /// Whether it throws depends on the existing properties, so the object can't be tracked then.
+  pub(super) fn may_forbid_mutation(&self, level: ObjectIntegrity) -> bool {
+    self.integrity.get().is_none_or(|integrity| integrity >= level)
+  }
+}
+
+fn mark_readonly(value: &mut ObjectPropertyValue) {
+  if let ObjectPropertyValue::Field(_, readonly) = value {
+    *readonly = true;
+  }
+}
@@ -0,0 +1,87 @@
+use super::ObjectValue;
+use crate::{
+  analyzer::Analyzer,
+  dep::Dep,
+  value::{IteratedElements, TypeofResult, ValueTrait, consumed_object, never::NeverValue},
+};
+
+/// The elements after this many steps are treated as unknown
+const MAX_ITERATION_STEPS: usize = 64;
+
+impl<'a> ObjectValue<'a> {
+  /// Drives the iteration protocol via `[Symbol.iterator]`. Like the elements of arrays, the
+  /// iterator is drained before the elements are used. If the consumer may stop `partially`, how
+  /// many steps are taken is unknown, so the iterator is consumed instead.
+  pub fn iterate(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    partially: bool,
+  ) -> IteratedElements<'a> {
+    if self.consumed.get() {
+      return consumed_object::iterate(analyzer, dep);
+    }
+
+    let key = analyzer.factory.well_known_symbol("iterator");
+    let method = self.get_property(analyzer, dep, key);
+    let typeof_method = method.test_typeof();
+    if !typeof_method.contains(TypeofResult::Function) && method.test_nullish() == Some(true) {
+      analyzer.throw_builtin_error("Cannot iterate over a non-iterable object");
+      // The exception can only be dropped if it is not caught
+      return if analyzer.config.preserve_exceptions || analyzer.scoping.try_catch_depth.is_some() {
+        self.consume(analyzer);
+        analyzer.consume(dep);
+        analyzer.refer_to_global();
+        (vec![], Some(analyzer.factory.unknown), analyzer.factory.no_dep)
+      } else {
+        NeverValue.iterate(analyzer, dep)
+      };
+    }
+    if typeof_method != TypeofResult::Function {
+      self.consume(analyzer);
+      analyzer.consume(method);
+      return consumed_object::iterate(analyzer, dep);
+    }
+
+    let empty_arguments = analyzer.factory.empty_arguments;
+    let iterator = method.call(analyzer, dep, self.into(), empty_arguments);
+    let next = iterator.get_property(analyzer, dep, analyzer.factory.string("next"));
+    if next.test_typeof() != TypeofResult::Function {
+      analyzer.consume((iterator, next));
+      return consumed_object::iterate(analyzer, dep);
+    }
+    if partially {
+      // Each step may have side effects
+      analyzer.consume((iterator, next, dep));
+      analyzer.refer_to_global();
+      return (vec![], Some(analyzer.factory.unknown), analyzer.factory.no_dep);
+    }
+
+    let mut elements = vec![];
+    let mut deps = analyzer.factory.vec1(dep);
+    for _ in 0..MAX_ITERATION_STEPS {
+      let result = next.call(analyzer, dep, iterator, empty_arguments);
+      let done = result.get_property(analyzer, dep, analyzer.factory.string("done"));
+      match done.test_truthy() {
+        Some(true) => {
+          deps.push(analyzer.dep(done));
+          return (elements, None, analyzer.dep(deps));
+        }
+        Some(false) => {
+          deps.push(analyzer.dep(done));
+          elements.push(result.get_property(analyzer, dep, analyzer.factory.string("value")));
+        }
+        None => {
+          analyzer.consume((result, done));
+          break;
+        }
+      }
+    }
+
+    // The remaining steps are unknown
+    analyzer.consume((iterator, next));
+    let (_, rest, rest_dep) = consumed_object::iterate(analyzer, dep);
+    deps.push(rest_dep);
+    (elements, rest, analyzer.dep(deps))
+  }
+}
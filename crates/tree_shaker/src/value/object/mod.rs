@@ -2,14 +2,18 @@ mod delete;
 mod enumerate;
 mod get;
 mod init;
+mod integrity;
+mod iterate;
 mod property;
 mod set;
 
 use std::{
   cell::{Cell, RefCell},
   fmt::Debug,
+  ptr,
 };
 
+pub use integrity::ObjectIntegrity;
 use oxc::allocator;
 use oxc_index::define_index_type;
 pub use property::{ObjectProperty, ObjectPropertyValue};
@@ -17,7 +21,7 @@ use rustc_hash::FxHashSet;
 
 use super::{
   EnumeratedProperties, IteratedElements, LiteralValue, PropertyKeyValue, TypeofResult, ValueTrait,
-  consumed_object,
+  consumed_object, symbol::well_known_symbol_id,
 };
 use crate::{
   analyzer::{Analyzer, exhaustive::ExhaustiveDepId},
@@ -49,6 +53,43 @@ impl<'a> ObjectPrototype<'a> {
       ObjectPrototype::Unknown(dep) => analyzer.consume(*dep),
     }
   }
+
+  /// Whether `target` is in this prototype chain, like `instanceof` does
+  pub fn test_inherits(self, analyzer: &Analyzer<'a>, target: ObjectPrototype<'a>) -> Option<bool> {
+    if !matches!(target, ObjectPrototype::Builtin(_) | ObjectPrototype::Custom(_)) {
+      return None;
+    }
+    let prototypes = analyzer.builtins.prototypes;
+    let mut current = self;
+    loop {
+      current = match (current, target) {
+        // Either `Object.prototype` or `null`
+        (ObjectPrototype::ImplicitOrNull, ObjectPrototype::Builtin(target)) => {
+          return if ptr::eq(target, &prototypes.object) { None } else { Some(false) };
+        }
+        (ObjectPrototype::ImplicitOrNull, _) => return Some(false),
+        (ObjectPrototype::Builtin(prototype), _) => {
+          if matches!(target, ObjectPrototype::Builtin(target) if ptr::eq(prototype, target)) {
+            return Some(true);
+          }
+          match prototypes.get_parent(prototype) {
+            Some(parent) => ObjectPrototype::Builtin(parent),
+            None => return Some(false),
+          }
+        }
+        (ObjectPrototype::Custom(object), _) => {
+          if matches!(target, ObjectPrototype::Custom(target) if ptr::eq(object, target)) {
+            return Some(true);
+          }
+          if object.consumed.get() {
+            return None;
+          }
+          object.prototype.get()
+        }
+        (ObjectPrototype::Unknown(_), _) => return None,
+      };
+    }
+  }
 }
 
 define_index_type! {
@@ -67,9 +108,15 @@ pub struct ObjectValue<'a> {
   /// `None` if not mangable
   /// `Some(None)` if mangable at the beginning, but disabled later
   pub mangling_group: Cell<Option<ObjectManglingGroupId<'a>>>,
+  /// `None` if changed in an indeterminate scope
+  pub integrity: Cell<Option<ObjectIntegrity>>,
+  /// Deps of the calls which changed `integrity`
+  pub integrity_deps: RefCell<DepCollector<'a>>,
 
   /// Properties keyed by known string
   pub keyed: RefCell<allocator::HashMap<'a, PropertyKeyValue<'a>, ObjectProperty<'a>>>,
+  /// Insertion order of the keys in `keyed`, `None` if it isn't tracked
+  pub key_order: RefCell<Option<allocator::Vec<'a, PropertyKeyValue<'a>>>>,
   /// Properties keyed by unknown value
   pub unknown: RefCell<ObjectProperty<'a>>,
   /// Properties keyed by unknown value, but not included in `keyed`
@@ -91,8 +138,10 @@ impl<'a> ValueTrait<'a> for ObjectValue<'a> {
     use_consumed_flag!(self);
 
     self.consume_as_prototype(analyzer);
+    self.integrity_deps.borrow().consume_all(analyzer);
 
     self.keyed.borrow_mut().clear();
+    self.key_order.replace(None);
     self.unknown.replace_with(|_| ObjectProperty::new_in(analyzer.allocator));
 
     let target_depth = analyzer.find_first_different_cf_scope(self.cf_scope);
@@ -170,8 +219,15 @@ impl<'a> ValueTrait<'a> for ObjectValue<'a> {
   }
 
   fn iterate(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) -> IteratedElements<'a> {
-    self.consume(analyzer);
-    consumed_object::iterate(analyzer, dep)
+    self.iterate(analyzer, dep, false)
+  }
+
+  fn iterate_partially(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+  ) -> IteratedElements<'a> {
+    self.iterate(analyzer, dep, true)
   }
 
   fn get_to_string(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
@@ -202,7 +258,11 @@ impl<'a> ValueTrait<'a> for ObjectValue<'a> {
     self.into()
   }
 
-  fn get_own_keys(&'a self, analyzer: &Analyzer<'a>) -> Option<Vec<(bool, Entity<'a>)>> {
+  fn get_own_keys(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    _dep: Dep<'a>,
+  ) -> Option<Vec<(bool, Entity<'a>)>> {
     let mut unknown = self.unknown.borrow_mut();
     if self.consumed.get() || self.rest.is_some() || !unknown.possible_values.is_empty() {
       return None;
@@ -210,8 +270,9 @@ impl<'a> ValueTrait<'a> for ObjectValue<'a> {
 
     let mut keys = Vec::new();
     for (key, property) in self.keyed.borrow_mut().iter_mut() {
-      let key_entity = property.key.unwrap_or_else(|| {
-        if let PropertyKeyValue::String(key) = key { analyzer.factory.string(key) } else { todo!() }
+      let key_entity = property.key.unwrap_or_else(|| match key {
+        PropertyKeyValue::String(key) => analyzer.factory.string(key),
+        PropertyKeyValue::Symbol(_) => unreachable!("Symbol keys are always stored"),
       });
       let key_entity = if property.non_existent.is_empty() {
         key_entity
@@ -252,6 +313,25 @@ impl<'a> ValueTrait<'a> for ObjectValue<'a> {
   fn test_nullish(&self) -> Option<bool> {
     Some(false)
   }
+
+  fn test_is_array(&self) -> Option<bool> {
+    Some(false)
+  }
+
+  fn test_instanceof(
+    &'a self,
+    analyzer: &Analyzer<'a>,
+    prototype: ObjectPrototype<'a>,
+  ) -> Option<bool> {
+    if self.consumed.get() {
+      return None;
+    }
+    self.prototype.get().test_inherits(analyzer, prototype)
+  }
+
+  fn as_object(&'a self) -> Option<&'a ObjectValue<'a>> {
+    Some(self)
+  }
 }
 
 impl<'a> ObjectValue<'a> {
@@ -272,6 +352,23 @@ impl<'a> ObjectValue<'a> {
     analyzer.consume(suspended);
   }
 
+  /// Whether `Symbol.hasInstance` may be defined on this object or its prototypes, which
+  /// overrides `instanceof`
+  pub fn may_have_instance_check(&self) -> bool {
+    if self.consumed.get() || self.rest.is_some() {
+      return true;
+    }
+    let key = PropertyKeyValue::Symbol(well_known_symbol_id("hasInstance"));
+    if self.keyed.borrow().contains_key(&key) || !self.unknown.borrow().possible_values.is_empty() {
+      return true;
+    }
+    match self.prototype.get() {
+      ObjectPrototype::ImplicitOrNull | ObjectPrototype::Builtin(_) => false,
+      ObjectPrototype::Custom(object) => object.may_have_instance_check(),
+      ObjectPrototype::Unknown(_) => true,
+    }
+  }
+
   pub fn is_mangable(&self) -> bool {
     self.mangling_group.get().is_some_and(|group| group.get().is_some())
   }
@@ -293,7 +390,7 @@ impl<'a> ObjectValue<'a> {
     }
   }
 
-  fn disable_mangling(&self, analyzer: &mut Analyzer<'a>) {
+  pub fn disable_mangling(&self, analyzer: &mut Analyzer<'a>) {
     if let Some(group) = self.mangling_group.get() {
       if let Some(group) = group.replace(None) {
         analyzer.mangler.mark_uniqueness_group_non_mangable(group);
@@ -316,6 +413,15 @@ impl<'a> ObjectValue<'a> {
     }
   }
 
+  /// Records a key added to `keyed`. The order is lost if the key may not be added at this point.
+  fn track_key_order(&self, key: PropertyKeyValue<'a>, in_order: bool) {
+    let mut key_order = self.key_order.borrow_mut();
+    match key_order.as_mut() {
+      Some(order) if in_order => order.push(key),
+      _ => *key_order = None,
+    }
+  }
+
   pub fn add_extra_dep(&self, dep: Dep<'a>) {
     self.unknown.borrow_mut().non_existent.push(dep);
   }
@@ -335,10 +441,13 @@ impl<'a> Analyzer<'a> {
       cf_scope: self.scoping.cf.current_id(),
       object_id: self.scoping.alloc_object_id(),
       keyed: RefCell::new(allocator::HashMap::new_in(self.allocator)),
+      key_order: RefCell::new(Some(allocator::Vec::new_in(self.allocator))),
       unknown: RefCell::new(ObjectProperty::new_in(self.allocator)),
       rest: None,
       prototype: Cell::new(prototype),
       mangling_group: Cell::new(mangling_group),
+      integrity: Cell::new(Some(ObjectIntegrity::Extensible)),
+      integrity_deps: RefCell::new(DepCollector::new(allocator::Vec::new_in(self.allocator))),
     })
   }
 
@@ -1,4 +1,4 @@
-use super::{ObjectProperty, ObjectPropertyValue, ObjectPrototype, ObjectValue};
+use super::{ObjectIntegrity, ObjectProperty, ObjectPropertyValue, ObjectPrototype, ObjectValue};
 use crate::{
   analyzer::{Analyzer, exhaustive::ExhaustiveDepId},
   dep::{Dep, DepCollector, DepVec},
@@ -23,6 +23,9 @@ impl<'a> ObjectValue<'a> {
     key: Entity<'a>,
     value: Entity<'a>,
   ) {
+    if self.may_forbid_mutation(ObjectIntegrity::Frozen) {
+      self.consume(analyzer);
+    }
     if self.consumed.get() {
       return consumed_object::set_property(analyzer, dep, key, value);
     }
@@ -41,6 +44,7 @@ impl<'a> ObjectValue<'a> {
 
     let mut setters = vec![];
     let mut deferred_deps = vec![];
+    let mut forbidden = false;
 
     if self.lookup_unknown_keyed_setters(analyzer, &mut setters).may_found() {
       indeterminate = true;
@@ -100,14 +104,20 @@ impl<'a> ObjectValue<'a> {
         if found.must_found() {
           continue;
         }
+        if self.is_non_extensible() {
+          forbidden = true;
+          continue;
+        }
 
         if mangable {
           self.add_to_mangling_group(analyzer, key_atom.unwrap());
         }
+        let definite = !indeterminate && found.must_not_found();
+        self.track_key_order(key_str, definite && !keyed.contains_key(&key_str));
         keyed.insert(
           key_str,
           ObjectProperty {
-            definite: !indeterminate && found.must_not_found(),
+            definite,
             enumerable: true, /* TODO: Object.defineProperty */
             possible_values: analyzer.factory.vec1(if is_exhaustive {
               ObjectPropertyValue::new_consumed(analyzer, analyzer.factory.vec1(value))
@@ -129,6 +139,7 @@ impl<'a> ObjectValue<'a> {
       self.disable_mangling(analyzer);
 
       indeterminate = true;
+      forbidden |= self.is_non_extensible();
 
       let mut unknown_keyed = self.unknown.borrow_mut();
       unknown_keyed.possible_values.push(ObjectPropertyValue::Field(non_mangable_value, false));
@@ -160,6 +171,11 @@ impl<'a> ObjectValue<'a> {
     }
 
     analyzer.consume(deferred_deps);
+
+    if forbidden {
+      self.consume(analyzer);
+      consumed_object::set_property(analyzer, dep, key, non_mangable_value);
+    }
   }
 
   fn lookup_unknown_keyed_setters(
@@ -249,6 +265,42 @@ impl<'a> ObjectValue<'a> {
     }
   }
 
+  /// `Object.setPrototypeOf`
+  pub fn mutate_prototype(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    prototype: Entity<'a>,
+  ) {
+    if self.may_forbid_mutation(ObjectIntegrity::NonExtensible) {
+      self.consume(analyzer);
+    }
+    if self.consumed.get() {
+      analyzer.consume(prototype);
+      return consumed_object::unknown_mutate(analyzer, dep);
+    }
+
+    let (target_depth, is_exhaustive, indeterminate, deps) = self.prepare_mutation(analyzer, dep);
+    let dep = analyzer.dep(deps);
+    self.add_extra_dep(dep);
+    self.disable_mangling(analyzer);
+    analyzer.mark_exhaustive_write(ExhaustiveDepId::ObjectAll(self.object_id), target_depth);
+
+    if is_exhaustive || indeterminate {
+      // The previous prototype may still be in use
+      self.prototype.get().consume(analyzer);
+      self.set_prototype(ObjectPrototype::Unknown(analyzer.dep((dep, prototype))));
+    } else if prototype.test_nullish() == Some(true) {
+      self.set_prototype(ObjectPrototype::ImplicitOrNull);
+    } else if let Some(object) = prototype.as_object() {
+      object.disable_mangling(analyzer);
+      self.add_extra_dep(prototype.get_shallow_dep(analyzer));
+      self.set_prototype(ObjectPrototype::Custom(object));
+    } else {
+      self.set_prototype(ObjectPrototype::Unknown(analyzer.dep((dep, prototype))));
+    }
+  }
+
   pub(super) fn prepare_mutation(
     &self,
     analyzer: &mut Analyzer<'a>,
@@ -139,7 +139,11 @@ impl<'a> ValueTrait<'a> for PrimitiveValue {
       analyzer.factory.string("")
     }
   }
-  fn get_own_keys(&'a self, _analyzer: &Analyzer<'a>) -> Option<Vec<(bool, Entity<'a>)>> {
+  fn get_own_keys(
+    &'a self,
+    _analyzer: &mut Analyzer<'a>,
+    _dep: Dep<'a>,
+  ) -> Option<Vec<(bool, Entity<'a>)>> {
     match self {
       PrimitiveValue::String => None,
       _ => Some(vec![]),
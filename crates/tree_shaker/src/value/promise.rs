@@ -0,0 +1,377 @@
+use std::{cell::Cell, rc::Rc};
+
+use super::{
+  EnumeratedProperties, IteratedElements, ObjectPrototype, TypeofResult, ValueTrait,
+  consumed_object,
+};
+use crate::{
+  analyzer::{Analyzer, Factory},
+  dep::Dep,
+  entity::Entity,
+  scope::CfScopeKind,
+  use_consumed_flag,
+};
+
+/// How a promise may be settled
+#[derive(Debug, Clone, Copy)]
+pub struct PromiseSettlement<'a> {
+  /// The value it may be fulfilled with, `None` if it is never fulfilled
+  pub fulfilled: Option<Entity<'a>>,
+  /// The reason it may be rejected with, `None` if it is never rejected
+  pub rejected: Option<Entity<'a>>,
+  /// What settles it, like the call to a resolving function. The reactions depend on it
+  pub dep: Option<Dep<'a>>,
+}
+
+impl<'a> PromiseSettlement<'a> {
+  /// Never settled
+  pub fn pending() -> Self {
+    PromiseSettlement { fulfilled: None, rejected: None, dep: None }
+  }
+
+  pub fn fulfilled(value: Entity<'a>) -> Self {
+    PromiseSettlement { fulfilled: Some(value), rejected: None, dep: None }
+  }
+
+  pub fn rejected(reason: Entity<'a>) -> Self {
+    PromiseSettlement { fulfilled: None, rejected: Some(reason), dep: None }
+  }
+
+  pub fn unknown(factory: &Factory<'a>) -> Self {
+    PromiseSettlement {
+      fulfilled: Some(factory.unknown),
+      rejected: Some(factory.unknown),
+      dep: None,
+    }
+  }
+
+  pub fn union(self, other: Self, factory: &Factory<'a>) -> Self {
+    PromiseSettlement {
+      fulfilled: union_optional(factory, self.fulfilled, other.fulfilled),
+      rejected: union_optional(factory, self.rejected, other.rejected),
+      dep: match (self.dep, other.dep) {
+        (Some(a), Some(b)) => Some(factory.dep((a, b))),
+        (a, b) => a.or(b),
+      },
+    }
+  }
+
+  pub fn computed(self, dep: Dep<'a>, factory: &Factory<'a>) -> Self {
+    PromiseSettlement {
+      fulfilled: self.fulfilled.map(|value| factory.computed(value, dep)),
+      rejected: self.rejected.map(|reason| factory.computed(reason, dep)),
+      ..self.with_dep(dep, factory)
+    }
+  }
+
+  /// Adds `dep` to what settles it, without changing the values
+  pub fn with_dep(self, dep: Dep<'a>, factory: &Factory<'a>) -> Self {
+    PromiseSettlement {
+      dep: Some(match self.dep {
+        Some(settling_dep) => factory.dep((settling_dep, dep)),
+        None => dep,
+      }),
+      ..self
+    }
+  }
+
+  /// The dep of calling a reaction with `dep` on this settlement
+  pub fn reaction_dep(self, dep: Dep<'a>, factory: &Factory<'a>) -> Dep<'a> {
+    self.with_dep(dep, factory).dep.unwrap()
+  }
+
+  pub fn consume(self, analyzer: &mut Analyzer<'a>) {
+    for entity in [self.fulfilled, self.rejected].into_iter().flatten() {
+      analyzer.consume(entity);
+    }
+    analyzer.consume(self.dep);
+  }
+}
+
+fn union_optional<'a>(
+  factory: &Factory<'a>,
+  a: Option<Entity<'a>>,
+  b: Option<Entity<'a>>,
+) -> Option<Entity<'a>> {
+  match (a, b) {
+    (Some(a), Some(b)) => Some(factory.union((a, b))),
+    (a, b) => a.or(b),
+  }
+}
+
+/// `Promise` instances. The settlement is known when the promise is created, because the
+/// constructor, the statics and the reactions are all analyzed synchronously.
+#[derive(Debug)]
+pub struct PromiseValue<'a> {
+  pub consumed: Cell<bool>,
+  pub settlement: PromiseSettlement<'a>,
+}
+
+impl<'a> ValueTrait<'a> for PromiseValue<'a> {
+  fn consume(&'a self, analyzer: &mut Analyzer<'a>) {
+    use_consumed_flag!(self);
+
+    // Unknown code may register reactions
+    self.settlement.consume(analyzer);
+  }
+
+  fn unknown_mutate(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) {
+    // `then` may be overridden
+    self.consume(analyzer);
+    consumed_object::unknown_mutate(analyzer, dep)
+  }
+
+  fn get_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    key: Entity<'a>,
+  ) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::get_property(self, analyzer, dep, key);
+    }
+    analyzer.builtins.prototypes.promise.get_property(analyzer, self.into(), key, dep)
+  }
+
+  fn set_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    key: Entity<'a>,
+    value: Entity<'a>,
+  ) {
+    self.unknown_mutate(analyzer, analyzer.dep((dep, key, value)));
+  }
+
+  fn enumerate_properties(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+  ) -> EnumeratedProperties<'a> {
+    if self.consumed.get() {
+      return consumed_object::enumerate_properties(self, analyzer, dep);
+    }
+    EnumeratedProperties { known: Default::default(), unknown: None, dep }
+  }
+
+  fn delete_property(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>, key: Entity<'a>) {
+    self.unknown_mutate(analyzer, analyzer.dep((dep, key)));
+  }
+
+  fn call(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    this: Entity<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    consumed_object::call(self, analyzer, dep, this, args)
+  }
+
+  fn construct(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    consumed_object::construct(self, analyzer, dep, args)
+  }
+
+  fn jsx(&'a self, analyzer: &mut Analyzer<'a>, props: Entity<'a>) -> Entity<'a> {
+    consumed_object::jsx(self, analyzer, props)
+  }
+
+  fn r#await(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::r#await(analyzer, dep);
+    }
+
+    let PromiseSettlement { fulfilled, rejected, .. } = self.settlement;
+    let dep = self.settlement.reaction_dep(dep, analyzer.factory);
+    if let Some(reason) = rejected {
+      analyzer.consume((dep, reason));
+      if fulfilled.is_none() {
+        analyzer.exit_by_throw(true);
+        return analyzer.factory.never;
+      }
+      // May throw
+      analyzer.refer_to_global();
+    }
+    match fulfilled {
+      Some(value) => analyzer.factory.computed(value, dep),
+      None => analyzer.factory.never,
+    }
+  }
+
+  fn iterate(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) -> IteratedElements<'a> {
+    self.consume(analyzer);
+    consumed_object::iterate(analyzer, dep)
+  }
+
+  fn get_to_string(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::get_to_string(analyzer);
+    }
+    analyzer.factory.computed_unknown_string(self)
+  }
+
+  fn get_to_numeric(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::get_to_numeric(analyzer);
+    }
+    analyzer.factory.computed_unknown(self)
+  }
+
+  fn get_to_boolean(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    analyzer.factory.boolean(true)
+  }
+
+  fn get_to_property_key(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    self.get_to_string(analyzer)
+  }
+
+  fn get_to_jsx_child(&'a self, _analyzer: &Analyzer<'a>) -> Entity<'a> {
+    self.into()
+  }
+
+  fn as_promise(&'a self) -> Option<&'a PromiseValue<'a>> {
+    Some(self)
+  }
+
+  fn test_instanceof(
+    &'a self,
+    analyzer: &Analyzer<'a>,
+    prototype: ObjectPrototype<'a>,
+  ) -> Option<bool> {
+    if self.consumed.get() {
+      return None;
+    }
+    ObjectPrototype::Builtin(&analyzer.builtins.prototypes.promise)
+      .test_inherits(analyzer, prototype)
+  }
+
+  fn test_typeof(&self) -> TypeofResult {
+    TypeofResult::Object
+  }
+
+  fn test_truthy(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn test_nullish(&self) -> Option<bool> {
+    Some(false)
+  }
+
+  fn test_is_array(&self) -> Option<bool> {
+    Some(false)
+  }
+}
+
+impl<'a> Analyzer<'a> {
+  pub fn new_promise(&mut self, settlement: PromiseSettlement<'a>) -> &'a mut PromiseValue<'a> {
+    self.allocator.alloc(PromiseValue { consumed: Cell::new(false), settlement })
+  }
+
+  /// How a promise resolved with `value` is settled. Promises are adopted, and other objects with
+  /// a `then` method are thenables whose settlement is unknown.
+  pub fn resolve_promise_value(
+    &mut self,
+    dep: Dep<'a>,
+    value: Entity<'a>,
+  ) -> PromiseSettlement<'a> {
+    if let Some(promise) = value.as_promise().filter(|promise| !promise.consumed.get()) {
+      let dep = self.dep((dep, value.get_shallow_dep(self)));
+      return promise.settlement.computed(dep, self.factory);
+    }
+
+    if !value.test_typeof().intersects(TypeofResult::Object | TypeofResult::Function) {
+      return PromiseSettlement::fulfilled(self.factory.computed(value, dep));
+    }
+
+    let then = value.get_property(self, dep, self.factory.string("then"));
+    if !then.test_typeof().contains(TypeofResult::Function) {
+      return PromiseSettlement::fulfilled(self.factory.computed(value, then));
+    }
+
+    self.consume((value, then));
+    PromiseSettlement::unknown(self.factory)
+  }
+
+  /// Calls `callback`, catching the exceptions. Also returns whether it has thrown, and the dep
+  /// of the throw sites.
+  pub fn call_catching(
+    &mut self,
+    dep: Dep<'a>,
+    callback: Entity<'a>,
+    this: Entity<'a>,
+    args: Entity<'a>,
+  ) -> (Entity<'a>, Option<bool>, Dep<'a>) {
+    self.push_cf_scope(CfScopeKind::Dependent, false);
+    let old_try_catch_depth = self.scoping.try_catch_depth.replace(self.scoping.cf.current_depth());
+    let ret_val = callback.call(self, dep, this, args);
+    self.scoping.try_catch_depth = old_try_catch_depth;
+    let factory = self.factory;
+    let cf_scope = self.cf_scope_mut();
+    let thrown = cf_scope.exited;
+    // Only the exits by throwing are collected into this scope
+    let thrown_dep = cf_scope.deps.collect(factory);
+    self.pop_cf_scope();
+    (ret_val, thrown, thrown_dep)
+  }
+
+  /// Calls a reaction registered by `then`, `catch` or `finally`. The result is only tracked if
+  /// it can't be changed by later code.
+  pub fn call_promise_reaction(
+    &mut self,
+    dep: Dep<'a>,
+    callback: Entity<'a>,
+    args: Entity<'a>,
+  ) -> PromiseSettlement<'a> {
+    let factory = self.factory;
+    let later_awaits =
+      *self.call_scope_mut().later_awaits.get_or_insert_with(|| factory.lazy_dep(factory.vec()));
+    let dep = self.dep((dep, later_awaits));
+    let first_run = Rc::new(Cell::new(None));
+    let registered = self.exec_async_callback({
+      let first_run = first_run.clone();
+      move |analyzer| {
+        let settlement = analyzer.call_reaction_once(dep, callback, args);
+        if first_run.get().is_none() {
+          first_run.set(Some(settlement));
+        } else {
+          settlement.consume(analyzer);
+        }
+      }
+    });
+    let settlement = first_run.get().unwrap();
+    if registered {
+      settlement.consume(self);
+      PromiseSettlement::unknown(self.factory)
+    } else {
+      settlement
+    }
+  }
+
+  fn call_reaction_once(
+    &mut self,
+    dep: Dep<'a>,
+    callback: Entity<'a>,
+    args: Entity<'a>,
+  ) -> PromiseSettlement<'a> {
+    let (ret_val, thrown, thrown_dep) =
+      self.call_catching(dep, callback, self.factory.undefined, args);
+    let settlement = if thrown == Some(true) {
+      PromiseSettlement::pending()
+    } else {
+      self.resolve_promise_value(dep, ret_val)
+    };
+    let settlement = if thrown == Some(false) {
+      settlement
+    } else {
+      let rejected =
+        PromiseSettlement::rejected(self.factory.unknown).with_dep(thrown_dep, self.factory);
+      settlement.union(rejected, self.factory)
+    };
+    settlement.with_dep(dep, self.factory)
+  }
+}
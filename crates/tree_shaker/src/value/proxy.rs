@@ -0,0 +1,318 @@
+use std::cell::Cell;
+
+use oxc::allocator;
+
+use super::{EnumeratedProperties, IteratedElements, TypeofResult, ValueTrait, consumed_object};
+use crate::{analyzer::Analyzer, dep::Dep, entity::Entity, use_consumed_flag};
+
+/// `Proxy` instances. Operations call the corresponding trap on the handler if it exists, and are
+/// forwarded to the target otherwise.
+#[derive(Debug)]
+pub struct ProxyValue<'a> {
+  pub consumed: Cell<bool>,
+  pub target: Entity<'a>,
+  pub handler: Entity<'a>,
+}
+
+impl<'a> ValueTrait<'a> for ProxyValue<'a> {
+  fn consume(&'a self, analyzer: &mut Analyzer<'a>) {
+    use_consumed_flag!(self);
+
+    analyzer.consume((self.target, self.handler));
+  }
+
+  fn unknown_mutate(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) {
+    // Any trap may be called with unknown arguments
+    self.consume(analyzer);
+    consumed_object::unknown_mutate(analyzer, dep)
+  }
+
+  fn get_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    key: Entity<'a>,
+  ) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::get_property(self, analyzer, dep, key);
+    }
+
+    let key = key.get_to_property_key(analyzer);
+    self.with_trap(
+      analyzer,
+      dep,
+      "get",
+      |_| vec![self.target, key, self.into()],
+      |analyzer, dep| self.target.get_property(analyzer, dep, key),
+    )
+  }
+
+  fn set_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    key: Entity<'a>,
+    value: Entity<'a>,
+  ) {
+    if self.consumed.get() {
+      return consumed_object::set_property(analyzer, dep, key, value);
+    }
+
+    let key = key.get_to_property_key(analyzer);
+    self.with_trap(
+      analyzer,
+      dep,
+      "set",
+      |_| vec![self.target, key, value, self.into()],
+      |analyzer, dep| {
+        self.target.set_property(analyzer, dep, key, value);
+        analyzer.factory.undefined
+      },
+    );
+  }
+
+  fn enumerate_properties(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+  ) -> EnumeratedProperties<'a> {
+    if self.consumed.get() {
+      return consumed_object::enumerate_properties(self, analyzer, dep);
+    }
+
+    let Some(traps_dep) =
+      self.test_no_traps(analyzer, dep, &["ownKeys", "getOwnPropertyDescriptor", "get"])
+    else {
+      self.consume(analyzer);
+      return consumed_object::enumerate_properties(self, analyzer, dep);
+    };
+    self.target.enumerate_properties(analyzer, traps_dep)
+  }
+
+  fn get_own_keys(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+  ) -> Option<Vec<(bool, Entity<'a>)>> {
+    if self.consumed.get() {
+      return None;
+    }
+
+    let trap = self.handler.get_property(analyzer, dep, analyzer.factory.string("ownKeys"));
+    match trap.test_nullish() {
+      Some(true) => {
+        let keys = self.target.get_own_keys(analyzer, analyzer.dep((dep, trap)))?;
+        Some(
+          keys
+            .into_iter()
+            .map(|(definite, key)| {
+              let target_dep = self.target.get_shallow_dep(analyzer);
+              (definite, analyzer.factory.computed(key, (trap, target_dep)))
+            })
+            .collect(),
+        )
+      }
+      Some(false) => {
+        let args = analyzer
+          .factory
+          .arguments(allocator::Vec::from_iter_in([(false, self.target)], analyzer.allocator));
+        let result = trap.call(analyzer, dep, self.handler, args);
+        let (elements, rest, iterate_dep) = result.iterate(analyzer, dep);
+        if let Some(rest) = rest {
+          analyzer.consume((elements, rest, iterate_dep));
+          return None;
+        }
+        Some(
+          elements
+            .into_iter()
+            .map(|key| (true, analyzer.factory.computed(key, (trap, iterate_dep))))
+            .collect(),
+        )
+      }
+      None => {
+        self.consume(analyzer);
+        analyzer.consume((dep, trap));
+        None
+      }
+    }
+  }
+
+  fn delete_property(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>, key: Entity<'a>) {
+    if self.consumed.get() {
+      return consumed_object::delete_property(analyzer, dep, key);
+    }
+
+    let key = key.get_to_property_key(analyzer);
+    self.with_trap(
+      analyzer,
+      dep,
+      "deleteProperty",
+      |_| vec![self.target, key],
+      |analyzer, dep| {
+        self.target.delete_property(analyzer, dep, key);
+        analyzer.factory.undefined
+      },
+    );
+  }
+
+  fn call(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    this: Entity<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::call(self, analyzer, dep, this, args);
+    }
+
+    self.with_trap(
+      analyzer,
+      dep,
+      "apply",
+      |analyzer| {
+        let args_array = args.destruct_as_array(analyzer, dep, 0, true).1.unwrap();
+        vec![self.target, this, args_array]
+      },
+      |analyzer, dep| self.target.call(analyzer, dep, this, args),
+    )
+  }
+
+  fn construct(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::construct(self, analyzer, dep, args);
+    }
+
+    self.with_trap(
+      analyzer,
+      dep,
+      "construct",
+      |analyzer| {
+        let args_array = args.destruct_as_array(analyzer, dep, 0, true).1.unwrap();
+        vec![self.target, args_array, self.into()]
+      },
+      |analyzer, dep| self.target.construct(analyzer, dep, args),
+    )
+  }
+
+  fn jsx(&'a self, analyzer: &mut Analyzer<'a>, props: Entity<'a>) -> Entity<'a> {
+    consumed_object::jsx(self, analyzer, props)
+  }
+
+  fn r#await(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) -> Entity<'a> {
+    self.consume(analyzer);
+    consumed_object::r#await(analyzer, dep)
+  }
+
+  fn iterate(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) -> IteratedElements<'a> {
+    self.consume(analyzer);
+    consumed_object::iterate(analyzer, dep)
+  }
+
+  fn get_to_string(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::get_to_string(analyzer);
+    }
+    analyzer.factory.computed_unknown_string(self)
+  }
+
+  fn get_to_numeric(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::get_to_numeric(analyzer);
+    }
+    analyzer.factory.computed_unknown(self)
+  }
+
+  fn get_to_boolean(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    analyzer.factory.boolean(true)
+  }
+
+  fn get_to_property_key(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    self.get_to_string(analyzer)
+  }
+
+  fn get_to_jsx_child(&'a self, _analyzer: &Analyzer<'a>) -> Entity<'a> {
+    self.into()
+  }
+
+  fn test_typeof(&self) -> TypeofResult {
+    let result = self.target.test_typeof() & (TypeofResult::Object | TypeofResult::Function);
+    if result.is_empty() { TypeofResult::Object | TypeofResult::Function } else { result }
+  }
+
+  fn test_truthy(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn test_nullish(&self) -> Option<bool> {
+    Some(false)
+  }
+
+  fn test_is_array(&self) -> Option<bool> {
+    self.target.test_is_array()
+  }
+}
+
+impl<'a> ProxyValue<'a> {
+  /// Calls the trap if the handler has it, otherwise runs `fallback`.
+  /// Both are run indeterminately if the existence of the trap is unknown.
+  fn with_trap(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    name: &'static str,
+    trap_args: impl FnOnce(&mut Analyzer<'a>) -> Vec<Entity<'a>>,
+    fallback: impl FnOnce(&mut Analyzer<'a>, Dep<'a>) -> Entity<'a>,
+  ) -> Entity<'a> {
+    let trap = self.handler.get_property(analyzer, dep, analyzer.factory.string(name));
+    let call_trap = |analyzer: &mut Analyzer<'a>| {
+      let trap_args = trap_args(analyzer);
+      let args = analyzer.factory.arguments(allocator::Vec::from_iter_in(
+        trap_args.into_iter().map(|arg| (false, arg)),
+        analyzer.allocator,
+      ));
+      trap.call(analyzer, dep, self.handler, args)
+    };
+    let fallback_dep = analyzer.dep((dep, trap));
+    match trap.test_nullish() {
+      Some(true) => fallback(analyzer, fallback_dep),
+      Some(false) => call_trap(analyzer),
+      None => analyzer.exec_indeterminately(|analyzer| {
+        let trapped = call_trap(analyzer);
+        analyzer.cf_scope_mut().reset_indeterminate();
+        let forwarded = fallback(analyzer, fallback_dep);
+        analyzer.factory.union((trapped, forwarded))
+      }),
+    }
+  }
+
+  /// Returns the dep of the lookups if none of the traps exist
+  fn test_no_traps(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    names: &[&'static str],
+  ) -> Option<Dep<'a>> {
+    let mut deps = analyzer.factory.vec1(dep);
+    for name in names {
+      let trap = self.handler.get_property(analyzer, dep, analyzer.factory.string(name));
+      if trap.test_nullish() != Some(true) {
+        analyzer.consume(trap);
+        return None;
+      }
+      deps.push(analyzer.dep(trap));
+    }
+    Some(analyzer.dep(deps))
+  }
+}
+
+impl<'a> Analyzer<'a> {
+  pub fn new_proxy(&mut self, target: Entity<'a>, handler: Entity<'a>) -> &'a mut ProxyValue<'a> {
+    self.allocator.alloc(ProxyValue { consumed: Cell::new(false), target, handler })
+  }
+}
@@ -0,0 +1,199 @@
+use std::cell::Cell;
+
+use super::{
+  EnumeratedProperties, IteratedElements, LiteralValue, ObjectPrototype, TypeofResult, ValueTrait,
+  consumed_object,
+};
+use crate::{analyzer::Analyzer, dep::Dep, entity::Entity, use_consumed_flag, utils::JsRegex};
+
+/// `RegExp` instances. `lastIndex` is not tracked, so any mutation makes the regular expression
+/// unknown, including calling `exec` and `test` when it is global or sticky.
+#[derive(Debug)]
+pub struct RegExpValue<'a> {
+  pub consumed: Cell<bool>,
+  pub source: &'a str,
+  pub flags: &'a str,
+}
+
+impl<'a> ValueTrait<'a> for RegExpValue<'a> {
+  fn consume(&'a self, _analyzer: &mut Analyzer<'a>) {
+    use_consumed_flag!(self);
+  }
+
+  fn unknown_mutate(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) {
+    self.consume(analyzer);
+    consumed_object::unknown_mutate(analyzer, dep)
+  }
+
+  fn get_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    key: Entity<'a>,
+  ) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::get_property(self, analyzer, dep, key);
+    }
+    if let Some(LiteralValue::String(key_str, _)) = key.get_literal(analyzer) {
+      if let Some(value) = self.get_flag_property(analyzer, key_str) {
+        return analyzer.factory.computed(value, (dep, key));
+      }
+    }
+    analyzer.builtins.prototypes.regexp.get_property(analyzer, self.into(), key, dep)
+  }
+
+  fn set_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    key: Entity<'a>,
+    value: Entity<'a>,
+  ) {
+    self.unknown_mutate(analyzer, analyzer.dep((dep, key, value)));
+  }
+
+  fn enumerate_properties(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+  ) -> EnumeratedProperties<'a> {
+    if self.consumed.get() {
+      return consumed_object::enumerate_properties(self, analyzer, dep);
+    }
+    // `lastIndex` is not enumerable
+    EnumeratedProperties { known: Default::default(), unknown: None, dep }
+  }
+
+  fn delete_property(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>, key: Entity<'a>) {
+    self.unknown_mutate(analyzer, analyzer.dep((dep, key)));
+  }
+
+  fn call(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    this: Entity<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    consumed_object::call(self, analyzer, dep, this, args)
+  }
+
+  fn construct(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    consumed_object::construct(self, analyzer, dep, args)
+  }
+
+  fn jsx(&'a self, analyzer: &mut Analyzer<'a>, props: Entity<'a>) -> Entity<'a> {
+    consumed_object::jsx(self, analyzer, props)
+  }
+
+  fn r#await(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::r#await(analyzer, dep);
+    }
+    analyzer.factory.computed(self.into(), dep)
+  }
+
+  fn iterate(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) -> IteratedElements<'a> {
+    self.consume(analyzer);
+    consumed_object::iterate(analyzer, dep)
+  }
+
+  fn get_to_string(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::get_to_string(analyzer);
+    }
+    let str = analyzer.allocator.alloc_str(&format!("/{}/{}", self.source, self.flags));
+    analyzer.factory.string(str)
+  }
+
+  fn get_to_numeric(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::get_to_numeric(analyzer);
+    }
+    analyzer.factory.nan
+  }
+
+  fn get_to_boolean(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    analyzer.factory.boolean(true)
+  }
+
+  fn get_to_property_key(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    self.get_to_string(analyzer)
+  }
+
+  fn get_to_jsx_child(&'a self, _analyzer: &Analyzer<'a>) -> Entity<'a> {
+    self.into()
+  }
+
+  fn as_regexp(&'a self) -> Option<&'a RegExpValue<'a>> {
+    Some(self)
+  }
+
+  fn test_instanceof(
+    &'a self,
+    analyzer: &Analyzer<'a>,
+    prototype: ObjectPrototype<'a>,
+  ) -> Option<bool> {
+    if self.consumed.get() {
+      return None;
+    }
+    ObjectPrototype::Builtin(&analyzer.builtins.prototypes.regexp)
+      .test_inherits(analyzer, prototype)
+  }
+
+  fn test_typeof(&self) -> TypeofResult {
+    TypeofResult::Object
+  }
+
+  fn test_truthy(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn test_nullish(&self) -> Option<bool> {
+    Some(false)
+  }
+
+  fn test_is_array(&self) -> Option<bool> {
+    Some(false)
+  }
+}
+
+impl<'a> RegExpValue<'a> {
+  /// `lastIndex` and the accessors of the flags on `RegExp.prototype`
+  fn get_flag_property(&self, analyzer: &Analyzer<'a>, key: &str) -> Option<Entity<'a>> {
+    let flag = match key {
+      "lastIndex" => return Some(analyzer.factory.number(0.0, None)),
+      "source" => return Some(analyzer.factory.string(self.source)),
+      "flags" => return Some(analyzer.factory.string(self.flags)),
+      "global" => 'g',
+      "ignoreCase" => 'i',
+      "multiline" => 'm',
+      "dotAll" => 's',
+      "unicode" => 'u',
+      "unicodeSets" => 'v',
+      "sticky" => 'y',
+      "hasIndices" => 'd',
+      _ => return None,
+    };
+    Some(analyzer.factory.boolean(self.flags.contains(flag)))
+  }
+
+  /// The compiled regular expression, `None` if it is consumed or not supported
+  pub fn compile(&self) -> Option<JsRegex> {
+    if self.consumed.get() {
+      return None;
+    }
+    JsRegex::new(self.source, self.flags)
+  }
+}
+
+impl<'a> Analyzer<'a> {
+  pub fn new_regexp(&mut self, source: &'a str, flags: &'a str) -> Entity<'a> {
+    self.allocator.alloc(RegExpValue { consumed: Cell::new(false), source, flags }).into()
+  }
+}
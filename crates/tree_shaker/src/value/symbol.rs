@@ -2,8 +2,8 @@ use oxc::semantic::SymbolId;
 use oxc_index::Idx;
 use rustc_hash::FxHashMap;
 use std::sync::{
-  atomic::{AtomicUsize, Ordering},
   LazyLock, Mutex,
+  atomic::{AtomicUsize, Ordering},
 };
 
 static SYMBOL_ID: AtomicUsize = AtomicUsize::new(0);
@@ -24,3 +24,13 @@ pub fn new_symbol_id_from_name(name: &str) -> SymbolId {
   map.insert(name.to_string(), symbol_id);
   symbol_id
 }
+
+/// `Symbol.iterator` and the like, shared by all the analyzers
+pub fn well_known_symbol_id(name: &str) -> SymbolId {
+  new_symbol_id_from_name(&format!("Symbol.{name}"))
+}
+
+/// Symbols created by `Symbol.for`, which are distinct from the well-known ones
+pub fn registered_symbol_id(key: &str) -> SymbolId {
+  new_symbol_id_from_name(&format!("Symbol.for({key})"))
+}
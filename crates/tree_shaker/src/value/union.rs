@@ -181,21 +181,15 @@ impl<'a, V: UnionValues<'a> + Debug + 'a> ValueTrait<'a> for UnionValue<'a, V> {
   }
 
   fn iterate(&'a self, analyzer: &mut Analyzer<'a>, dep: Dep<'a>) -> IteratedElements<'a> {
-    let mut results = analyzer.factory.vec();
-    let mut has_undefined = false;
-    analyzer.push_indeterminate_cf_scope();
-    for entity in self.values.iter() {
-      if let Some(result) = entity.iterate_result_union(analyzer, dep) {
-        results.push(result);
-      } else {
-        has_undefined = true;
-      }
-    }
-    analyzer.pop_cf_scope();
-    if has_undefined {
-      results.push(analyzer.factory.undefined);
-    }
-    (vec![], analyzer.factory.try_union(results), analyzer.factory.no_dep)
+    self.iterate_values(analyzer, dep, false)
+  }
+
+  fn iterate_partially(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+  ) -> IteratedElements<'a> {
+    self.iterate_values(analyzer, dep, true)
   }
 
   fn get_shallow_dep(&'a self, analyzer: &Analyzer<'a>) -> Dep<'a> {
@@ -242,10 +236,14 @@ impl<'a, V: UnionValues<'a> + Debug + 'a> ValueTrait<'a> for UnionValue<'a, V> {
     Some(result)
   }
 
-  fn get_own_keys(&'a self, _analyzer: &Analyzer<'a>) -> Option<Vec<(bool, Entity<'a>)>> {
+  fn get_own_keys(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+  ) -> Option<Vec<(bool, Entity<'a>)>> {
     let mut result = Vec::new();
     for entity in self.values.iter() {
-      let keys = entity.get_own_keys(_analyzer)?;
+      let keys = entity.get_own_keys(analyzer, dep)?;
       result.extend(keys.into_iter().map(|(_, key)| (false, key)));
     }
     Some(result)
@@ -289,6 +287,57 @@ impl<'a, V: UnionValues<'a> + Debug + 'a> ValueTrait<'a> for UnionValue<'a, V> {
     }
     Some(result)
   }
+
+  fn test_is_array(&self) -> Option<bool> {
+    let mut iter = self.values.iter();
+    let result = iter.next().unwrap().test_is_array()?;
+    for entity in iter {
+      if entity.test_is_array()? != result {
+        return None;
+      }
+    }
+    Some(result)
+  }
+
+  fn test_instanceof(
+    &'a self,
+    analyzer: &Analyzer<'a>,
+    prototype: ObjectPrototype<'a>,
+  ) -> Option<bool> {
+    let mut iter = self.values.iter();
+    let result = iter.next().unwrap().test_instanceof(analyzer, prototype)?;
+    for entity in iter {
+      if entity.test_instanceof(analyzer, prototype)? != result {
+        return None;
+      }
+    }
+    Some(result)
+  }
+}
+
+impl<'a, V: UnionValues<'a> + Debug + 'a> UnionValue<'a, V> {
+  fn iterate_values(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Dep<'a>,
+    partially: bool,
+  ) -> IteratedElements<'a> {
+    let mut results = analyzer.factory.vec();
+    let mut has_undefined = false;
+    analyzer.push_indeterminate_cf_scope();
+    for entity in self.values.iter() {
+      if let Some(result) = entity.iterate_result_union(analyzer, dep, partially) {
+        results.push(result);
+      } else {
+        has_undefined = true;
+      }
+    }
+    analyzer.pop_cf_scope();
+    if has_undefined {
+      results.push(analyzer.factory.undefined);
+    }
+    (vec![], analyzer.factory.try_union(results), analyzer.factory.no_dep)
+  }
 }
 
 pub trait UnionValues<'a> {
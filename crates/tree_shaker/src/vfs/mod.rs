@@ -1,7 +1,23 @@
+mod node_resolver;
+
+use std::{io, path::Path};
+
+pub use node_resolver::{NodeResolver, ResolverHost};
+
 pub trait Vfs {
   fn resolve_module(&self, importer: &str, specifier: &str) -> Option<String>;
-  fn read_file(&self, path: &str) -> String;
+  fn read_file(&self, path: &str) -> io::Result<String>;
   fn normalize_path(&self, path: String) -> String;
+
+  /// Modules without side effects can be removed if none of their exports are used
+  fn has_side_effects(&self, _path: &str) -> bool {
+    true
+  }
+
+  /// The source map of the input file itself, which is chained into the generated source map
+  fn read_source_map(&self, _path: &str) -> Option<String> {
+    None
+  }
 }
 
 pub struct StdFs;
@@ -15,34 +31,83 @@ impl Vfs for StdFs {
     let mut path = std::path::PathBuf::from(importer);
     path.pop();
     path.push(specifier);
-    Some(
-      path
-        .exists()
-        .then(|| path.to_string_lossy().into_owned())
-        .or_else(|| {
-          path.set_extension("js");
-          path.exists().then(|| path.to_string_lossy().into_owned())
-        })
-        .or_else(|| {
-          path.set_extension("mjs");
-          path.exists().then(|| path.to_string_lossy().into_owned())
-        })
-        .or_else(|| {
-          path.set_extension("cjs");
-          path.exists().then(|| path.to_string_lossy().into_owned())
-        })
-        .unwrap_or_else(|| {
-          panic!("Cannot resolve module: {} from {}", specifier, importer);
-        }),
-    )
-  }
-
-  fn read_file(&self, path: &str) -> String {
-    std::fs::read_to_string(path).unwrap()
+    path
+      .exists()
+      .then(|| path.to_string_lossy().into_owned())
+      .or_else(|| {
+        path.set_extension("js");
+        path.exists().then(|| path.to_string_lossy().into_owned())
+      })
+      .or_else(|| {
+        path.set_extension("mjs");
+        path.exists().then(|| path.to_string_lossy().into_owned())
+      })
+      .or_else(|| {
+        path.set_extension("cjs");
+        path.exists().then(|| path.to_string_lossy().into_owned())
+      })
+      .or_else(|| {
+        path.set_extension("ts");
+        path.exists().then(|| path.to_string_lossy().into_owned())
+      })
+      .or_else(|| {
+        path.set_extension("tsx");
+        path.exists().then(|| path.to_string_lossy().into_owned())
+      })
+  }
+
+  fn read_file(&self, path: &str) -> io::Result<String> {
+    std::fs::read_to_string(path)
+  }
+
+  fn read_source_map(&self, path: &str) -> Option<String> {
+    std::fs::read_to_string(format!("{}.map", path)).ok()
   }
 
   fn normalize_path(&self, path: String) -> String {
-    normalize_path::normalize(std::path::Path::new(&path)).to_string_lossy().into_owned()
+    normalize_path::normalize(Path::new(&path)).to_string_lossy().into_owned()
+  }
+}
+
+impl ResolverHost for StdFs {
+  fn is_file(&self, path: &str) -> bool {
+    Path::new(path).is_file()
+  }
+
+  fn is_dir(&self, path: &str) -> bool {
+    Path::new(path).is_dir()
+  }
+
+  fn read_to_string(&self, path: &str) -> Option<String> {
+    std::fs::read_to_string(path).ok()
+  }
+}
+
+/// The real file system, with Node.js style module resolution.
+#[derive(Default)]
+pub struct NodeResolverFs {
+  pub resolver: NodeResolver,
+}
+
+impl Vfs for NodeResolverFs {
+  fn resolve_module(&self, importer: &str, specifier: &str) -> Option<String> {
+    self.resolver.resolve(&StdFs, importer, specifier)
+  }
+
+  fn read_file(&self, path: &str) -> io::Result<String> {
+    StdFs.read_file(path)
+  }
+
+  fn normalize_path(&self, path: String) -> String {
+    StdFs.normalize_path(path)
+  }
+
+  fn has_side_effects(&self, path: &str) -> bool {
+    self.resolver.has_side_effects(&StdFs, path)
+  }
+
+  fn read_source_map(&self, path: &str) -> Option<String> {
+    StdFs.read_source_map(path)
   }
 }
 
@@ -57,11 +122,11 @@ impl Vfs for SingleFileFs {
     None
   }
 
-  fn read_file(&self, path: &str) -> String {
+  fn read_file(&self, path: &str) -> io::Result<String> {
     if path == Self::ENTRY_PATH {
-      self.0.clone()
+      Ok(self.0.clone())
     } else {
-      unreachable!("Unexpected path: {}", path);
+      Err(io::Error::new(io::ErrorKind::NotFound, format!("Unexpected path: {}", path)))
     }
   }
 
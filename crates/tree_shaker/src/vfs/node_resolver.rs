@@ -0,0 +1,276 @@
+use std::{cell::RefCell, path::Path, rc::Rc};
+
+use globset::Glob;
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+
+use super::normalize_path::normalize;
+
+/// File system operations required by [`NodeResolver`].
+pub trait ResolverHost {
+  fn is_file(&self, path: &str) -> bool;
+  fn is_dir(&self, path: &str) -> bool;
+  fn read_to_string(&self, path: &str) -> Option<String>;
+}
+
+/// Resolves modules like Node.js and bundlers do.
+#[derive(Debug, Clone)]
+pub struct NodeResolver {
+  /// Extensions to try when the specifier has no extension, without the leading dot
+  pub extensions: Vec<String>,
+  /// Conditions to match in the `exports` field of `package.json`. `default` always matches.
+  pub conditions: Vec<String>,
+  /// Fields of `package.json` used as the entry when there is no `exports` field
+  pub main_fields: Vec<String>,
+  /// The parsed `package.json` of each directory looked up by [`Self::has_side_effects`]
+  package_jsons: RefCell<FxHashMap<String, Option<Rc<Value>>>>,
+}
+
+impl Default for NodeResolver {
+  fn default() -> Self {
+    Self {
+      extensions: vec![
+        "js".into(),
+        "mjs".into(),
+        "cjs".into(),
+        "ts".into(),
+        "tsx".into(),
+        "mts".into(),
+        "cts".into(),
+      ],
+      conditions: vec!["import".into()],
+      main_fields: vec!["module".into(), "main".into()],
+      package_jsons: Default::default(),
+    }
+  }
+}
+
+impl NodeResolver {
+  pub fn with_extensions(mut self, extensions: &[&str]) -> Self {
+    self.extensions = extensions.iter().map(|s| s.to_string()).collect();
+    self
+  }
+
+  pub fn with_conditions(mut self, conditions: &[&str]) -> Self {
+    self.conditions = conditions.iter().map(|s| s.to_string()).collect();
+    self
+  }
+
+  pub fn with_main_fields(mut self, main_fields: &[&str]) -> Self {
+    self.main_fields = main_fields.iter().map(|s| s.to_string()).collect();
+    self
+  }
+
+  pub fn resolve(
+    &self,
+    host: &impl ResolverHost,
+    importer: &str,
+    specifier: &str,
+  ) -> Option<String> {
+    let dir = Path::new(importer).parent().unwrap_or(Path::new("/"));
+    if specifier.starts_with("./")
+      || specifier.starts_with("../")
+      || specifier.starts_with('/')
+      || specifier == "."
+      || specifier == ".."
+    {
+      let path = join(dir, specifier);
+      self.resolve_as_file(host, &path).or_else(|| self.resolve_as_directory(host, &path))
+    } else {
+      self.resolve_package(host, dir, specifier)
+    }
+  }
+
+  fn resolve_as_file(&self, host: &impl ResolverHost, path: &str) -> Option<String> {
+    if host.is_file(path) {
+      return Some(path.to_string());
+    }
+    self
+      .extensions
+      .iter()
+      .map(|ext| format!("{}.{}", path, ext))
+      .find(|p| host.is_file(p))
+      .or_else(|| resolve_typescript_source(host, path))
+  }
+
+  fn resolve_as_directory(&self, host: &impl ResolverHost, path: &str) -> Option<String> {
+    if !host.is_dir(path) {
+      return None;
+    }
+    if let Some(package_json) = read_package_json(host, path) {
+      for field in &self.main_fields {
+        if let Some(Value::String(main)) = package_json.get(field) {
+          let main = join(Path::new(path), main);
+          let resolved =
+            self.resolve_as_file(host, &main).or_else(|| self.resolve_index(host, &main));
+          if resolved.is_some() {
+            return resolved;
+          }
+        }
+      }
+    }
+    self.resolve_index(host, path)
+  }
+
+  fn resolve_index(&self, host: &impl ResolverHost, path: &str) -> Option<String> {
+    let index = join(Path::new(path), "index");
+    self.extensions.iter().map(|ext| format!("{}.{}", index, ext)).find(|p| host.is_file(p))
+  }
+
+  fn resolve_package(
+    &self,
+    host: &impl ResolverHost,
+    dir: &Path,
+    specifier: &str,
+  ) -> Option<String> {
+    let (name, subpath) = split_package_specifier(specifier)?;
+    for ancestor in dir.ancestors() {
+      let package_dir = join(&ancestor.join("node_modules"), name);
+      if !host.is_dir(&package_dir) {
+        continue;
+      }
+      if let Some(exports) =
+        read_package_json(host, &package_dir).and_then(|json| json.get("exports").cloned())
+      {
+        // The `exports` field is authoritative
+        let target = self.resolve_exports(&exports, &format!(".{}", subpath))?;
+        let path = join(Path::new(&package_dir), &target);
+        return host.is_file(&path).then_some(path);
+      }
+      let path = join(Path::new(&package_dir), &format!(".{}", subpath));
+      return self.resolve_as_file(host, &path).or_else(|| self.resolve_as_directory(host, &path));
+    }
+    None
+  }
+
+  /// Whether the module may have side effects, according to the `sideEffects` field of the
+  /// nearest `package.json`.
+  pub fn has_side_effects(&self, host: &impl ResolverHost, path: &str) -> bool {
+    for dir in Path::new(path).ancestors().skip(1) {
+      let Some(package_json) = self.read_cached_package_json(host, &dir.to_string_lossy()) else {
+        continue;
+      };
+      return match package_json.get("sideEffects") {
+        Some(Value::Bool(side_effects)) => *side_effects,
+        Some(Value::Array(patterns)) => {
+          let relative = Path::new(path).strip_prefix(dir).unwrap();
+          patterns.iter().filter_map(Value::as_str).any(|pattern| {
+            let pattern = pattern.strip_prefix("./").unwrap_or(pattern);
+            // Patterns without slashes match files in any directory
+            let pattern =
+              if pattern.contains('/') { pattern.to_string() } else { format!("**/{}", pattern) };
+            Glob::new(&pattern).is_ok_and(|glob| glob.compile_matcher().is_match(relative))
+          })
+        }
+        _ => true,
+      };
+    }
+    true
+  }
+
+  fn read_cached_package_json(&self, host: &impl ResolverHost, dir: &str) -> Option<Rc<Value>> {
+    if let Some(package_json) = self.package_jsons.borrow().get(dir) {
+      return package_json.clone();
+    }
+    let package_json = read_package_json(host, dir).map(Rc::new);
+    self.package_jsons.borrow_mut().insert(dir.to_string(), package_json.clone());
+    package_json
+  }
+
+  /// Returns the target path relative to the package directory
+  fn resolve_exports(&self, exports: &Value, subpath: &str) -> Option<String> {
+    let is_subpath_map = matches!(
+      exports,
+      Value::Object(map) if map.keys().next().is_some_and(|key| key.starts_with('.'))
+    );
+    if !is_subpath_map {
+      return if subpath == "." { self.resolve_exports_target(exports, None) } else { None };
+    }
+    let Value::Object(map) = exports else { unreachable!() };
+
+    if let Some(target) = map.get(subpath) {
+      return self.resolve_exports_target(target, None);
+    }
+
+    // Subpath patterns. The longest matching prefix wins.
+    let mut best: Option<(&str, &Value, &str)> = None;
+    for (key, target) in map {
+      if let Some((prefix, suffix)) = key.split_once('*') {
+        if subpath.len() >= key.len() - 1
+          && subpath.starts_with(prefix)
+          && subpath.ends_with(suffix)
+        {
+          let matched = &subpath[prefix.len()..subpath.len() - suffix.len()];
+          if best.is_none_or(|(best_prefix, _, _)| prefix.len() > best_prefix.len()) {
+            best = Some((prefix, target, matched));
+          }
+        }
+      }
+    }
+    let (_, target, matched) = best?;
+    self.resolve_exports_target(target, Some(matched))
+  }
+
+  fn resolve_exports_target(&self, target: &Value, pattern_match: Option<&str>) -> Option<String> {
+    match target {
+      Value::String(target) => {
+        if !target.starts_with("./") {
+          return None;
+        }
+        Some(match pattern_match {
+          Some(matched) => target.replace('*', matched),
+          None => target.clone(),
+        })
+      }
+      Value::Array(targets) => {
+        targets.iter().find_map(|target| self.resolve_exports_target(target, pattern_match))
+      }
+      Value::Object(conditions) => conditions.iter().find_map(|(condition, target)| {
+        (condition == "default" || self.conditions.contains(condition))
+          .then(|| self.resolve_exports_target(target, pattern_match))
+          .flatten()
+      }),
+      _ => None,
+    }
+  }
+}
+
+/// Finds the TypeScript source of a path with a JavaScript extension, since TypeScript sources
+/// import each other with the extension of the output file, e.g. `./foo.js` refers to `./foo.ts`
+fn resolve_typescript_source(host: &impl ResolverHost, path: &str) -> Option<String> {
+  let (stem, candidates): (_, &[&str]) = if let Some(stem) = path.strip_suffix(".js") {
+    (stem, &["ts", "tsx"])
+  } else if let Some(stem) = path.strip_suffix(".jsx") {
+    (stem, &["tsx"])
+  } else if let Some(stem) = path.strip_suffix(".mjs") {
+    (stem, &["mts"])
+  } else if let Some(stem) = path.strip_suffix(".cjs") {
+    (stem, &["cts"])
+  } else {
+    return None;
+  };
+  candidates.iter().map(|ext| format!("{}.{}", stem, ext)).find(|p| host.is_file(p))
+}
+
+/// Splits `@scope/name/sub/path` into `@scope/name` and `/sub/path`
+fn split_package_specifier(specifier: &str) -> Option<(&str, &str)> {
+  let name_end = if specifier.starts_with('@') {
+    let scope_end = specifier.find('/')?;
+    specifier[scope_end + 1..].find('/').map(|i| scope_end + 1 + i)
+  } else {
+    specifier.find('/')
+  };
+  Some(match name_end {
+    Some(end) => (&specifier[..end], &specifier[end..]),
+    None => (specifier, ""),
+  })
+}
+
+fn read_package_json(host: &impl ResolverHost, dir: &str) -> Option<Value> {
+  let content = host.read_to_string(&join(Path::new(dir), "package.json"))?;
+  serde_json::from_str(&content).ok()
+}
+
+fn join(base: &Path, path: &str) -> String {
+  normalize(&base.join(path)).to_string_lossy().into_owned()
+}
@@ -0,0 +1,44 @@
+export function constructor(n) {
+  const a = new Array(3)
+  t1 = a.length
+  t2 = a[1]
+  const b = Array(1, "x")
+  t3 = b.length
+  t4 = b[1]
+  const c = new Array("y")
+  t5 = c[0]
+  t6 = new Array(n).length
+}
+
+export function is_array(x) {
+  t1 = Array.isArray([])
+  t2 = Array.isArray({})
+  t3 = Array.isArray("s")
+  t4 = Array.isArray(x)
+  t5 = Array.isArray(new Array(2))
+}
+
+export function from(x) {
+  const a = Array.from([1, 2, 3])
+  t1 = a.length
+  t2 = a[2]
+  const b = Array.from(new Set([1, 1, 2]))
+  t3 = b.length
+  const c = Array.from([1, 2], (v, i) => v * 10 + i)
+  t4 = c[1]
+  t5 = c.length
+  t6 = Array.from(x).length
+}
+
+export function of(x) {
+  const a = Array.of(7, 8)
+  t1 = a.length
+  t2 = a[0]
+  t3 = Array.of(...x).length
+}
+
+export function invalid_length() {
+  effect()
+  new Array(-1)
+  unreachable()
+}
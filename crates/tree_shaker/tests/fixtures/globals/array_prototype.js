@@ -0,0 +1,144 @@
+const list = [1, 2, 3]
+
+export function callbacks() {
+  t1 = list.map((x) => x * 2)[2]
+  t2 = list.filter((x) => x > 1).length
+  t3 = list.reduce((acc, x) => acc + x, 0)
+  t4 = list.reduce((acc, x) => acc * x)
+  t5 = list.find((x) => x > 1)
+  t6 = list.findIndex((x) => x > 5)
+  t7 = list.some((x) => x === 2)
+  t8 = list.every((x) => x > 0)
+  let sum = 0
+  list.forEach((x) => {
+    sum += x
+  })
+  t9 = sum
+}
+
+export function unused_callbacks() {
+  list.map((x) => x + 1)
+  list.forEach((x) => {
+    log(x)
+  })
+}
+
+export function search(x) {
+  t1 = list.includes(2)
+  t2 = list.includes(4)
+  t3 = list.indexOf(3)
+  t4 = [NaN].includes(NaN)
+  t5 = [NaN].indexOf(NaN)
+  t6 = list.includes(x)
+  t7 = list.indexOf(1, 1)
+  t8 = [1, 2, 1].lastIndexOf(1)
+  t9 = [1, 2, 1].lastIndexOf(1, -2)
+  t10 = [1, 2, 1].lastIndexOf(1, -4)
+  t11 = list.at(-1)
+  t12 = list.at(3)
+  t13 = list.at(x)
+}
+
+export function non_mutating() {
+  t1 = list.join("-")
+  t2 = list.join()
+  t3 = list.slice(1).length
+  t4 = list.slice(-1)[0]
+  t5 = list.concat([4, 5], 6).length
+  t6 = [1, [2, [3]]].flat().length
+  t7 = [1, [2, [3]]].flat(Infinity)[2]
+  t8 = list.flatMap((x) => [x, x]).length
+  t9 = list.toReversed().join()
+  t10 = [3, 1, 10, 2].toSorted().join()
+  t11 = list.toSpliced(1, 1, "a", "b").join()
+  t12 = list.with(-1, 0).join()
+}
+
+export function copies_retained(x) {
+  const a = [{}]
+  a.toReversed()[0].x = 1
+  t1 = a[0].x
+  const b = [x, 1]
+  t2 = b.toSorted((l, r) => l - r).length
+  t3 = list.with(x, 0).length
+  const c = [{ v: 1 }]
+  for (const [, o] of c.entries()) {
+    o.v = 2
+  }
+  t4 = c[0].v
+}
+
+export function mutators() {
+  const a = [1, 2]
+  t1 = a.push(3, 4)
+  t2 = a.pop()
+  t3 = a.shift()
+  t4 = a.unshift(0)
+  t5 = a.length
+  t6 = a[0]
+  const b = [3, 1, 10, 2]
+  b.sort()
+  t7 = b.join()
+  b.reverse()
+  t8 = b[0]
+  const c = [1, 2, 3, 4]
+  t9 = c.splice(1, 2, "x").length
+  t10 = c.join()
+  t11 = new Array(3).fill(0).join()
+}
+
+export function retained(x) {
+  const a = []
+  a.push(x)
+  effect(a)
+  const b = [1, 2]
+  b.sort((l, r) => r - l)
+  t1 = b[0]
+  const c = [1]
+  if (x) {
+    c.push(2)
+  }
+  t2 = c.length
+}
+
+export function mutating_callbacks() {
+  const a1 = [1, 2, 3]
+  let sum1 = 0
+  a1.forEach((x) => {
+    sum1 += x
+    a1.pop()
+  })
+  t1 = sum1
+  const a2 = [1, 2, 3]
+  let sum2 = 0
+  a2.forEach((x) => {
+    a2[2] = 9
+    sum2 += x
+  })
+  t2 = sum2
+  const a3 = [1, 2, 3]
+  t3 = a3.map((x) => {
+    a3[2] = 9
+    return x
+  }).join()
+  const a4 = [1, 2, 3]
+  t4 = a4.filter(() => {
+    a4.shift()
+    return true
+  }).join()
+  const a5 = [1, 2, 3]
+  t5 = a5.some((x) => {
+    a5.pop()
+    return x === 3
+  })
+  const a6 = [1, 2, 3]
+  t6 = a6.map((x) => {
+    a6.pop()
+    return x
+  }).length
+  const a7 = [1, 2]
+  t7 = a7.findIndex((x) => {
+    a7.pop()
+    return x === undefined
+  })
+}
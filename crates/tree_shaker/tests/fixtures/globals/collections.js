@@ -0,0 +1,102 @@
+const cache = new Map()
+cache.set("a", 1)
+cache.set("b", 2)
+
+const unused = new Map([["x", 1]])
+unused.set("y", effect())
+
+const written = new Set()
+written.add(1)
+
+const weak = new WeakMap()
+export function register(obj) {
+  weak.set(obj, 1)
+}
+
+export function map() {
+  t1 = cache.get("a")
+  t2 = cache.has("b")
+  t3 = cache.has("c")
+  t4 = cache.size
+  t5 = cache.get("c")
+}
+
+export function map_from_entries() {
+  const m = new Map([["k", 1], [2, "v"]])
+  t1 = m.get("k")
+  t2 = m.get(2)
+  t3 = m.size
+  m.delete("k")
+  t4 = m.has("k")
+  t5 = m.size
+}
+
+export function set(a) {
+  const s = new Set([1, 2, 2, 3])
+  t1 = s.size
+  t2 = s.has(2)
+  s.add(a)
+  t3 = s.has(2)
+  t4 = s.size
+}
+
+export function indeterminate(a) {
+  const m = new Map()
+  if (a) {
+    m.set("a", 1)
+  }
+  t1 = m.has("a")
+  t2 = m.get("a")
+  m.set("b", 2)
+  t3 = m.get("b")
+}
+
+export function iterate() {
+  const s = new Set(["x", "y"])
+  for (const v of s) {
+    console.log(v)
+  }
+  const m = new Map([["a", 1]])
+  m.forEach((value, key) => console.log(key, value))
+  t1 = [...s].length
+}
+
+export function escape() {
+  const m = new Map()
+  m.set("a", 1)
+  unknown(m)
+  t1 = m.get("a")
+}
+
+export function weak_map(obj) {
+  const w = new WeakMap()
+  w.set(obj, 1)
+  t1 = w.get(obj)
+}
+
+export function instance_of(x) {
+  t1 = new Map() instanceof Map
+  t2 = new Set() instanceof Map
+  t3 = new WeakSet() instanceof Object
+  t4 = new Number(1) instanceof Number
+  t5 = new Proxy({}, {}) instanceof Object
+  t6 = new Intl.NumberFormat() instanceof Intl.Collator
+  t7 = x instanceof Map
+}
+
+export function delete_then_set() {
+  const m = new Map([["a", 1], ["b", 2]])
+  m.delete("a")
+  m.set("a", 3)
+  t1 = [...m.keys()].join()
+}
+
+export function mutating_for_each() {
+  const s = new Set([1, 2, 3])
+  let sum = 0
+  s.forEach((x) => {
+    sum += x
+    s.delete(3)
+  })
+  t1 = sum
+}
@@ -0,0 +1,25 @@
+// @pure-console debug
+
+export function console_calls(a) {
+  console.log('kept', a)
+  console.debug('removed', a.expensive())
+  const unused = console.debug
+}
+
+export function dates(a) {
+  const start = Date.now()
+  work()
+  t1 = Date.now() - start
+  const unused = new Date(a)
+  t2 = new Date() instanceof Date
+  t3 = typeof Date()
+  t4 = new Date(a).getTime()
+}
+
+export function intl(a) {
+  const unused1 = new Intl.NumberFormat('en-US', { style: 'currency', currency: 'USD' })
+  const unused2 = Intl.DateTimeFormat()
+  const formatter = new Intl.NumberFormat(a)
+  t1 = formatter.format(1)
+  t2 = new Intl.Locale('en').language
+}
@@ -0,0 +1,34 @@
+// @define process.env.NODE_ENV="production"
+// @define __DEV__=false
+// @define import.meta.env.MODE="production"
+// @define BUILD_INFO={"version":"1.2.3","features":["a","b"]}
+
+export function node_env() {
+  if (process.env.NODE_ENV !== 'production') {
+    effect1()
+  }
+  t1 = process.env.NODE_ENV
+  t2 = process.env.OTHER
+  t3 = process.cwd()
+}
+
+export function flags() {
+  if (__DEV__) {
+    effect2()
+  }
+  t1 = typeof __DEV__
+}
+
+export function import_meta() {
+  if (import.meta.env.MODE === 'development') {
+    effect3()
+  }
+  t1 = import.meta.env.MODE
+  t2 = import.meta.url
+}
+
+export function json_values() {
+  t1 = BUILD_INFO.version
+  t2 = BUILD_INFO.features.length
+  t3 = BUILD_INFO.features[1]
+}
@@ -0,0 +1,14 @@
+// @define import.meta.env.MODE="production"
+// @define BUILD_INFO={"version":"1.2.3","features":["a","b"]}
+
+export function unfolded_uses() {
+  use(BUILD_INFO)
+  BUILD_INFO.features.push('c')
+  use(BUILD_INFO.features)
+  use(import.meta.env.MODE)
+  t1 = BUILD_INFO.version.split('.')
+}
+
+export function shadowed(BUILD_INFO) {
+  use(BUILD_INFO.features)
+}
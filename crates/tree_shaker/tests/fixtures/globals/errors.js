@@ -0,0 +1,65 @@
+export function properties() {
+  const e = new TypeError('bad value', { cause: 1 })
+  t1 = e.message
+  t2 = e.name
+  t3 = e.cause
+  t4 = String(e)
+  t5 = Error('x').message
+  t6 = new RangeError().message
+  t7 = Object.keys(e).length
+  t8 = new AggregateError([1, 2], 'many').errors[1]
+  t9 = typeof e.stack
+}
+
+export function builtin_instanceof(a) {
+  const e = new TypeError('x')
+  t1 = e instanceof TypeError
+  t2 = e instanceof Error
+  t3 = e instanceof RangeError
+  t4 = e instanceof Object
+  t5 = [] instanceof Array
+  t6 = /a/ instanceof RegExp
+  t7 = 'str' instanceof String
+  t8 = a instanceof Error
+  t9 = new RangeError() instanceof SyntaxError
+}
+
+export function class_instanceof(a) {
+  class A {}
+  class B extends A {}
+  function F() {}
+  t1 = new B() instanceof A
+  t2 = new A() instanceof B
+  t3 = new F() instanceof F
+  t4 = new F() instanceof A
+  t5 = {} instanceof A
+  t6 = (a ? new A() : new B()) instanceof A
+  t7 = (a ? new A() : new F()) instanceof A
+}
+
+export function catch_handler(a) {
+  Promise.reject(a ? new TypeError('type') : new RangeError('range')).catch((e) => {
+    if (e instanceof SyntaxError) {
+      unreachable()
+    }
+    if (e instanceof Error) {
+      handleError(e.message)
+    } else {
+      unreachable()
+    }
+  })
+}
+
+export function unknown_cases(a) {
+  class C {
+    static [Symbol.hasInstance]() {
+      return true
+    }
+  }
+  function G() {}
+  const g = new G()
+  G.prototype = a
+  t1 = {} instanceof C
+  t2 = g instanceof G
+  t3 = a instanceof TypeError
+}
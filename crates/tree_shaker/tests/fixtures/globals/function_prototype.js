@@ -0,0 +1,57 @@
+export function call_and_apply() {
+  function f(a, b, c) {
+    if (b) effect1(a)
+    return this.x + c
+  }
+  t1 = f.call({ x: 1 }, 'a', false, 2)
+  t2 = f.apply({ x: 3 }, ['b', false, 4])
+  t3 = f.apply({ x: 5 }, null)
+  t4 = f.apply({ x: 0 }, [null, true, 0])
+}
+
+export function bind() {
+  function f(a, b) {
+    if (a) effect2()
+    return this.x + a + b
+  }
+  const bound = f.bind({ x: 1 }, 2)
+  t1 = bound(3)
+  t2 = bound.call({ x: 100 }, 4)
+  t3 = typeof bound
+  const twice = f.bind({ x: 10 }).bind({ x: 20 }, 0, 1)
+  t4 = twice()
+}
+
+export function bind_construct() {
+  class Point {
+    constructor(x, y) {
+      this.x = x
+      this.y = y
+    }
+  }
+  const AtOrigin = Point.bind(null, 0)
+  const p = new AtOrigin(1)
+  t1 = p.x + p.y
+}
+
+export function unused_bind() {
+  function f() {
+    effect3()
+  }
+  const bound = f.bind(null)
+}
+
+export function bound_length() {
+  function add(a, b) {
+    return a + b
+  }
+  t1 = add.bind(null, 5).length
+  t2 = add.bind(null, 1, 2, 3).length
+}
+
+export function apply_array_like() {
+  function count() {
+    return arguments.length
+  }
+  t1 = count.apply(null, { length: 2 })
+}
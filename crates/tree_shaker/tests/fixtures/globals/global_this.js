@@ -0,0 +1,14 @@
+export function aliases() {
+  t1 = globalThis.Math.max(1, 2)
+  t2 = globalThis.Object.keys({ a: 1 })
+  t3 = globalThis.globalThis === globalThis
+  t4 = typeof globalThis
+  t5 = globalThis['undefined']
+  t6 = typeof window
+}
+
+export function unknown_properties() {
+  t1 = globalThis.foo
+  globalThis.bar = 1
+  t2 = globalThis.bar
+}
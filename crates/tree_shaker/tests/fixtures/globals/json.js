@@ -0,0 +1,58 @@
+const config = JSON.parse('{"mode":"production","debug":false,"targets":["es2020"]}')
+
+export function parse() {
+  t1 = config.mode
+  t2 = config.debug ? "debug" : "release"
+  t3 = config.targets[0]
+  t4 = config.targets.length
+  t5 = JSON.parse("[1, 2, 3]")[1]
+  if (config.debug) {
+    console.log("unreachable")
+  }
+}
+
+export function parse_unknown(a) {
+  JSON.parse(a)
+  JSON.parse('{"a":1}', (k, v) => v)
+  JSON.parse("{invalid")
+  t1 = JSON.parse('{"a":1}')
+}
+
+export function stringify() {
+  t1 = JSON.stringify({ a: [1, "b", null, undefined] }) === '{"a":[1,"b",null,null]}'
+  t2 = JSON.stringify("a\"b")
+  t3 = JSON.stringify(undefined)
+  t4 = JSON.stringify([1, () => {}, { 2: 1, 1: 2 }], null, 2) === '[\n  1,\n  null,\n  {\n    "1": 2,\n    "2": 1\n  }\n]'
+  t5 = JSON.stringify({ f() {} })
+  t6 = JSON.stringify({ x: NaN })
+  t7 = JSON.stringify({ a: 1, b: 2 }).length
+  const o = { z: 1 }
+  o.a = 2
+  o[1] = 3
+  t8 = JSON.stringify(o) === '{"1":3,"z":1,"a":2}'
+  t9 = JSON.stringify(JSON.parse('{"b":1,"a":{"y":2,"x":3}}')) === '{"b":1,"a":{"y":2,"x":3}}'
+}
+
+export function stringify_non_finite() {
+  t1 = JSON.stringify(1 / 0)
+  t2 = JSON.stringify([2 ** 1024])
+  t3 = JSON.stringify(Number.MAX_VALUE * 2)
+}
+
+export function stringify_unordered(a) {
+  const o = { a: 1, b: 2 }
+  delete o.a
+  o.a = 3
+  t1 = JSON.stringify(o) === '{"b":2,"a":3}'
+  const p = {}
+  if (a) p.x = 1
+  p.y = 2
+  t2 = JSON.stringify(p) === '{"y":2}'
+}
+
+export function stringify_unknown(a) {
+  t1 = JSON.stringify(a)
+  JSON.stringify({ toJSON() { console.log("effect") } })
+  JSON.stringify({ a })
+  JSON.stringify([1])
+}
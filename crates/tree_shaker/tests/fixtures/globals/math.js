@@ -0,0 +1,34 @@
+export function constants() {
+  t1 = Math.floor(Math.PI)
+  t2 = Math.round(Math.E * 100)
+  t3 = Math.SQRT2 * Math.SQRT2 > 2
+}
+
+export function functions() {
+  t1 = Math.abs(-3)
+  t2 = Math.max(1, 5, "3")
+  t3 = Math.min()
+  t4 = Math.round(-5 / 2)
+  t5 = Math.pow(2, 8)
+  t6 = Math.sqrt(-1)
+  t7 = Math.sign(-1 / 10)
+  t8 = Math.hypot(3, 4)
+  t9 = Math.imul(0xffffffff, 5)
+  t10 = Math.clz32(1)
+  t11 = Math.trunc("4.7")
+  t12 = Math.ceil(1 / 0)
+}
+
+export function unknown_args(a) {
+  t1 = Math.abs(a)
+  t2 = Math.max(1, ...a)
+  t3 = typeof Math.floor(a)
+  Math.sin(a)
+  Math.max(1, 2)
+}
+
+export function random() {
+  t1 = Math.random()
+  t2 = typeof Math.random()
+  Math.random()
+}
@@ -0,0 +1,73 @@
+export function prototypes() {
+  const proto = {
+    greet() {
+      return 'hi'
+    },
+  }
+  const obj = {}
+  Object.setPrototypeOf(obj, proto)
+  t1 = obj.greet()
+  t2 = Object.getPrototypeOf(obj) === proto
+  t3 = Object.getPrototypeOf(Object.create(null)) === null
+}
+
+export function own_properties() {
+  const obj = { a: 1 }
+  t1 = Object.hasOwn(obj, 'a')
+  t2 = Object.hasOwn(obj, 'b')
+  t3 = Object.getOwnPropertyDescriptor(obj, 'a').value
+  t4 = Object.getOwnPropertyDescriptor(obj, 'a').writable
+  Object.defineProperties(obj, { b: { value: 2 } })
+  t5 = obj.b
+  t6 = Object.getOwnPropertyDescriptor(obj, 'b').writable
+  t7 = Object.getOwnPropertyDescriptor(obj, 'b').enumerable
+  Object.freeze(obj)
+  t8 = Object.getOwnPropertyDescriptor(obj, 'a').writable
+  t9 = Object.getOwnPropertyDescriptor(obj, 'a').configurable
+}
+
+export function from_entries() {
+  const obj = Object.fromEntries([
+    ['a', 1],
+    ['b', 2],
+  ])
+  t1 = obj.a + obj.b
+}
+
+export function integrity() {
+  const obj = { a: 1 }
+  t1 = Object.isFrozen(obj)
+  Object.freeze(obj)
+  t2 = Object.isFrozen(obj)
+  t3 = Object.isExtensible(obj)
+  t4 = Object.isSealed(1)
+  const unused = { b: 2 }
+  Object.seal(unused)
+}
+
+export function same_value(x) {
+  t1 = Object.is(NaN, NaN)
+  t2 = Object.is(0, -0)
+  t3 = Object.is('a', 'a')
+  t4 = Object.is(x, x)
+  t5 = Object.is(x, 1)
+}
+
+export function descriptor_of_literal() {
+  t1 = Object.getOwnPropertyDescriptor({ a: 1 }, 'a').writable
+}
+
+export function forbidden_writes() {
+  const sealed = { a: 1 }
+  Object.seal(sealed)
+  sealed.b = 2
+  t1 = sealed.b
+  const frozen = { a: 1 }
+  Object.freeze(frozen)
+  frozen.a = 2
+  t2 = frozen.a
+  const undeletable = { a: 1 }
+  Object.seal(undeletable)
+  delete undeletable.a
+  t3 = undeletable.a
+}
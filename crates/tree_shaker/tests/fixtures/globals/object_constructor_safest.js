@@ -0,0 +1,19 @@
+// @safest
+
+export function define_properties() {
+  const obj = {}
+  Object.defineProperties(obj, { a: { value: 1 } })
+  t1 = obj.a
+}
+
+export function forbidden_writes() {
+  const frozen = { a: 1 }
+  Object.freeze(frozen)
+  frozen.a = 2
+  t1 = frozen.a
+}
+
+export function reflect_mutations() {
+  t1 = Reflect.set(Object.freeze({ a: 1 }), 'a', 2)
+  t2 = Reflect.set({}, 'a', 1)
+}
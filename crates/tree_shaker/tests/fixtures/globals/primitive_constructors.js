@@ -0,0 +1,55 @@
+export function conversions(x) {
+  t1 = Number('42')
+  t2 = Number('')
+  t3 = Number(true)
+  t4 = Number()
+  t5 = Number(undefined)
+  t6 = String(12)
+  t7 = String(null)
+  t8 = Boolean('')
+  t9 = Boolean(x ? 1 : 2)
+  t10 = typeof Number(x)
+  t11 = typeof String(x)
+  t12 = BigInt(10) === 10n
+}
+
+export function statics(x) {
+  t1 = Number.isInteger(5)
+  t2 = Number.isInteger('5')
+  t3 = Number.isNaN(NaN)
+  t4 = Number.isSafeInteger(Number.MAX_SAFE_INTEGER + 1)
+  t5 = Number.MAX_SAFE_INTEGER > 0
+  t6 = Number.isFinite(x)
+  t7 = String.fromCharCode(104, 105)
+  t8 = Number.parseInt === parseInt
+}
+
+export function parsing() {
+  t1 = parseInt('  42px')
+  t2 = parseInt('ff', 16)
+  t3 = parseInt('0x1f')
+  t4 = parseInt('z')
+  t5 = parseFloat('1.5e2abc') === 150
+  t6 = parseFloat('-Infinity')
+  t7 = isNaN('abc')
+  t8 = isFinite('12')
+}
+
+export function wrappers() {
+  t1 = typeof new Number(1)
+  t2 = typeof new String('a')
+  t3 = new Boolean(false) ? 1 : 2
+  new Number(1)
+  t4 = new Number(1) instanceof Number
+  t5 = new String('a') instanceof String
+  t6 = new Boolean(true) instanceof Number
+  t7 = 1 instanceof Number
+}
+
+export function string_wrapper(x) {
+  t1 = new String('ab').length
+  t2 = new String('ab')[1]
+  t3 = new String().length
+  t4 = Object.keys(new String('ab')).length
+  t5 = new String(x).length
+}
@@ -0,0 +1,63 @@
+export async function statics() {
+  t1 = await Promise.resolve(1)
+  t2 = (await Promise.all([1, Promise.resolve(2)]))[1]
+  t3 = (await Promise.allSettled([Promise.reject(1)]))[0].status
+  t4 = await Promise.race([Promise.resolve('a'), Promise.resolve('b')])
+  t5 = await Promise.any([Promise.reject(1), Promise.resolve(2)])
+  t6 = new Promise(() => {})
+}
+
+export async function constructor() {
+  t1 = await new Promise((resolve) => resolve(1))
+  t2 = await new Promise((resolve) => {
+    resolve(2)
+    resolve(3)
+  })
+  t3 = await new Promise((resolve, reject) => reject(4)).catch((e) => e + 1)
+  t4 = await new Promise((resolve) => setTimeout(resolve, 100))
+}
+
+export async function reactions() {
+  const p = Promise.resolve(1)
+  t1 = await p.then((x) => x + 1)
+  t2 = await p.then().then((x) => x * 10)
+  t3 = await p.finally(() => {}).then((x) => x)
+  p.catch(() => {
+    effect()
+  })
+  Promise.reject(1).then(() => {
+    effect()
+  })
+}
+
+export async function unknown_promise(p) {
+  t1 = await p.then((x) => x + 1)
+}
+
+export async function settling_deps() {
+  new Promise((resolve) => resolve(1)).then(() => effect(1))
+  new Promise((resolve, reject) => reject(1)).catch(() => effect(2))
+  new Promise(() => {
+    throw 7
+  }).catch(() => effect(3))
+  t1 = await new Promise(() => {
+    throw 7
+  }).catch((e) => e)
+}
+
+t1 = await new Promise(() => {
+  throw 7
+}).catch((e) => e)
+
+export async function effects_before_await() {
+  let x = 1
+  await Promise.resolve().then(() => {
+    x = 2
+  })
+  t1 = x
+  let v
+  await Promise.resolve(2).then((y) => {
+    v = y * 2
+  })
+  t2 = v
+}
@@ -0,0 +1,91 @@
+export function reflect() {
+  const obj = { a: 1 }
+  t1 = Reflect.get(obj, 'a')
+  Reflect.set(obj, 'b', 2)
+  t2 = obj.b
+  t3 = Reflect.apply(Math.max, null, [1, 3, 2])
+  class A {
+    constructor(x) {
+      this.x = x
+    }
+  }
+  t4 = Reflect.construct(A, [5]).x
+  t5 = Reflect.getPrototypeOf(Object.create(null)) === null
+  t6 = Reflect.deleteProperty(obj, 'a')
+  t7 = obj.a
+}
+
+export function proxy_with_traps() {
+  const target = { a: 1 }
+  const p = new Proxy(target, {
+    get(t, k) {
+      return k === 'a' ? 10 : t[k]
+    },
+  })
+  t1 = p.a
+  t2 = p.b
+  const unused = new Proxy(target, {
+    get() {
+      effect()
+    },
+  })
+}
+
+export function proxy_forwarding() {
+  const target = { a: 1 }
+  const p = new Proxy(target, {})
+  t1 = p.a
+  p.b = 2
+  t2 = target.b
+  const f = new Proxy((x) => x + 1, {})
+  t3 = f(1)
+}
+
+export function proxy_unknown_handler(handler) {
+  const target = { a: 1 }
+  const p = new Proxy(target, handler)
+  t1 = p.a
+}
+
+export function proxy_own_keys() {
+  const p = new Proxy({ a: 1 }, {})
+  t1 = Object.keys(p)
+  t2 = Object.keys(p).length
+  const q = new Proxy(
+    { a: 1 },
+    {
+      ownKeys() {
+        return ['b', 'c']
+      },
+    },
+  )
+  t3 = Object.keys(q).length
+  t4 = Reflect.ownKeys(q)[1]
+  const r = new Proxy(
+    {},
+    {
+      ownKeys(t) {
+        effect()
+        return Reflect.ownKeys(t)
+      },
+    },
+  )
+  Object.keys(r)
+  const s = new Proxy(
+    {},
+    {
+      ownKeys() {
+        effect()
+        return []
+      },
+    },
+  )
+  for (const k in s) {
+  }
+}
+
+export function failed_mutations() {
+  t1 = Reflect.set(Object.freeze({ a: 1 }), 'a', 2)
+  const obj = Object.preventExtensions({})
+  t2 = Reflect.defineProperty(obj, 'a', { value: 1 })
+}
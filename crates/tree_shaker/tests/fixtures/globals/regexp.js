@@ -0,0 +1,68 @@
+export function test_and_exec() {
+  t1 = /^\/admin/.test('/admin/users')
+  t2 = /^\/admin/.test('/home')
+  t3 = /(\d+)-(\d+)/.exec('range: 10-20').join('|')
+  t4 = /(\d+)-(\d+)/.exec('range: 10-20')[2]
+  t5 = /(\d+)-(\d+)/.exec('range: 10-20').index
+  t6 = /(?<year>\d{4})-(?<month>\d{2})/.exec('2024-05').groups.month
+  t7 = /x/.exec('abc')
+  t8 = [/\bfoo\b/.test('a foo b'), /a.c/.test('a\nc'), /a.c/s.test('a\nc'), /^\s+$/.test('\u00a0\t')]
+  t9 = /[\w-]+/.exec('ab-c!')[0] + /a{2,}/.exec('baaa')[0] + /a{/.exec('a{')[0]
+  if (/chrome/i.test('Mozilla Chrome')) {
+    effect1()
+  } else {
+    effect2()
+  }
+}
+
+export function properties() {
+  const re = /a.c/gi
+  t1 = re.source
+  t2 = re.flags
+  t3 = re.global
+  t4 = re.sticky
+  t5 = String(/a\/b/mg)
+  t6 = re.lastIndex
+}
+
+export function string_methods() {
+  t1 = 'a1b22c333'.match(/\d+/g).join('|')
+  t2 = 'a1b22c333'.match(/\d+/)[0]
+  t3 = 'abc'.match(/x/g)
+  t4 = 'hello world'.search(/o/)
+  t5 = 'hello world'.search('z')
+  t6 = 'John Smith'.replace(/(\w+)\s(\w+)/, '$2, $1')
+  t7 = 'a-b_c'.replace(/[-_]/g, '.')
+  t8 = 'aaa'.replaceAll(/a/g, '$&$&')
+  t9 = 'a1b2c3'.split(/\d/).join('|')
+  t10 = 'a1b2c3'.split(/(\d)/, 3).join('|')
+  t11 = 'abc'.split(/(?:)/).join('|')
+  t12 = '2024-05'.replace(/(?<y>\d+)-(?<m>\d+)/, '$<m>/$<y>')
+}
+
+export function constructor() {
+  t1 = new RegExp('^a+$').test('aaa')
+  t2 = RegExp('b', 'g').flags
+  t3 = new RegExp(/x/g, 'i').flags
+  const re = /y/
+  t4 = RegExp(re) === re
+}
+
+export function unsupported(x) {
+  t1 = /(?<=\$)\d+/.test('$10')
+  t2 = /(a)\1/.test('aa')
+  t3 = /a/y.test('a')
+  t4 = /a/g.test('a')
+  t5 = /a/.test(x)
+  t6 = 'ſ'.match(/s/i)
+  t7 = /\cJ/.test('\n')
+}
+
+export function mutations() {
+  const re = /a/
+  re.lastIndex = 1
+  t1 = re.test('a')
+  const re2 = /b/
+  x(re2)
+  t2 = re2.test('b')
+}
@@ -0,0 +1,44 @@
+const str = 'abc'
+
+export function transform() {
+  t1 = str.toUpperCase()
+  t2 = 'x'.padStart(3, '0')
+  t3 = 'ab'.padEnd(5, 'xy')
+  t4 = '  a b  '.trim()
+  t5 = 'ab'.repeat(3)
+  t6 = 'a-b-c'.replace('-', '+')
+  t7 = 'a-b-c'.replaceAll('-', '')
+  t8 = str.concat('d', 1)
+  t9 = str.slice(-2)
+  t10 = str.substring(2, 0)
+}
+
+export function split() {
+  const parts = 'a-b'.split('-')
+  t1 = parts.length
+  t2 = parts[0]
+  t3 = parts[1]
+  t4 = 'abc'.split('').length
+}
+
+export function search() {
+  t1 = str.startsWith('ab')
+  t2 = str.endsWith('ab')
+  t3 = str.includes('b')
+  t4 = str.indexOf('c')
+  t5 = str.lastIndexOf('z')
+  t6 = str.charAt(1)
+  t7 = str.charCodeAt(0)
+  t8 = str.at(-1)
+}
+
+export function unknown_receiver(s) {
+  t1 = s.startsWith('foo')
+  t2 = s.indexOf('foo')
+  t3 = s.toUpperCase()
+  t4 = str.startsWith(s)
+  s.toLowerCase()
+  if (str.includes('a')) {
+    t5 = 1
+  }
+}
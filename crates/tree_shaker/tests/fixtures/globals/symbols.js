@@ -0,0 +1,104 @@
+export function symbol_keys() {
+  const key = Symbol('key')
+  const obj = { [key]: 1, a: 2 }
+  t1 = obj[key]
+  obj[key] = 3
+  t2 = obj[key]
+  t3 = obj[Symbol('key')]
+  t4 = Object.keys(obj).length
+  t5 = { ...obj }[key]
+  t6 = typeof key
+  for (const k in obj) {
+    t7 = k
+  }
+}
+
+export function registered_symbols() {
+  t1 = Symbol.for('a') === Symbol.for('a')
+  t2 = Symbol.for('a') === Symbol('a')
+  t3 = Symbol() === Symbol()
+  t4 = Symbol.iterator === Symbol.iterator
+  t5 = Symbol.iterator === Symbol.asyncIterator
+  t6 = String(Symbol('desc'))
+}
+
+export function well_known_symbols() {
+  const arr = [1, 2]
+  t1 = typeof arr[Symbol.iterator]
+  t2 = typeof 'str'[Symbol.iterator]
+  t3 = arr[Symbol.toStringTag]
+}
+
+export function custom_iterables() {
+  const iterable = {
+    [Symbol.iterator]() {
+      let i = 0
+      return {
+        next() {
+          i++
+          return { done: i > 3, value: i * 10 }
+        },
+      }
+    },
+  }
+  t1 = [...iterable]
+  const [a, b] = iterable
+  t2 = a + b
+  for (const x of iterable) {
+    if (x > 100) effect()
+  }
+  class Range {
+    constructor(n) {
+      this.n = n
+    }
+    [Symbol.iterator]() {
+      let i = 0
+      const n = this.n
+      return { next: () => (i < n ? { done: false, value: i++ } : { done: true }) }
+    }
+  }
+  t3 = Math.max(...new Range(3))
+}
+
+export function unknown_iterables(x) {
+  t1 = [...{ [Symbol.iterator]: x }]
+  t2 = [...{ [Symbol.iterator]: () => x }]
+}
+
+export function early_exit() {
+  let n = 0
+  const it = {
+    [Symbol.iterator]() {
+      let i = 0
+      return {
+        next() {
+          n++
+          i++
+          return { done: i > 5, value: i }
+        },
+      }
+    },
+  }
+  for (const x of it) {
+    if (x === 2) break
+  }
+  t1 = n
+  const [a] = it
+  t2 = n
+}
+
+export function caught_non_iterables() {
+  const obj = { [Symbol.iterator]: null }
+  try {
+    ;[...obj]
+    t1 = 'ok'
+  } catch {
+    t1 = 'threw'
+  }
+  try {
+    ;[...{}]
+    t2 = 'ok'
+  } catch {
+    t2 = 'threw'
+  }
+}
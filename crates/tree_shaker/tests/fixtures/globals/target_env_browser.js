@@ -0,0 +1,9 @@
+// @env browser
+export function main() {
+  t1 = typeof window !== 'undefined'
+  t2 = typeof process !== 'undefined' && process.env.NODE_ENV
+  t3 = window === globalThis && self === window
+  t4 = window.Math.PI
+  t5 = typeof document
+  window.foo = 1
+}
@@ -0,0 +1,11 @@
+// @env browser
+export function reads() {
+  t1 = typeof process
+  t2 = process.env.NODE_ENV
+}
+
+export function writes() {
+  t1 = typeof document
+  globalThis.process = { env: {} }
+  t2 = typeof process
+}
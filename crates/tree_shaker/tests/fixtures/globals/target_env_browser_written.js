@@ -0,0 +1,4 @@
+// @env browser
+window = 1
+t1 = typeof window
+t2 = typeof document
@@ -0,0 +1,9 @@
+// @env node
+export function main() {
+  t1 = typeof window !== 'undefined' ? window.location.href : null
+  t2 = typeof process
+  t3 = global.JSON === JSON
+  if (typeof self === 'undefined') {
+    effect()
+  }
+}
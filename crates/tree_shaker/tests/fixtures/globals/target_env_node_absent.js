@@ -0,0 +1,5 @@
+// @env node
+global.window = { location: { href: '/' } }
+t1 = typeof window
+t2 = typeof self
+t3 = typeof document
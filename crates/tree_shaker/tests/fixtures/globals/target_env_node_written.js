@@ -0,0 +1,11 @@
+// @env node
+export function through_global_object() {
+  t1 = typeof process
+  globalThis.process = 1
+  t2 = typeof process
+}
+
+export function assigned() {
+  process = 1
+  t1 = typeof process
+}
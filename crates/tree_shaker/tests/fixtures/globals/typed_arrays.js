@@ -0,0 +1,122 @@
+const cachedTextDecoder = new TextDecoder('utf-8', { ignoreBOM: true, fatal: true })
+cachedTextDecoder.decode()
+const cachedTextEncoder = new TextEncoder()
+let cachedUint8ArrayMemory0 = null
+
+function getUint8ArrayMemory0() {
+  if (cachedUint8ArrayMemory0 === null || cachedUint8ArrayMemory0.byteLength === 0) {
+    cachedUint8ArrayMemory0 = new Uint8Array(wasm.memory.buffer)
+  }
+  return cachedUint8ArrayMemory0
+}
+
+function getStringFromWasm0(ptr, len) {
+  return cachedTextDecoder.decode(getUint8ArrayMemory0().subarray(ptr, ptr + len))
+}
+
+export function greet(ptr, len) {
+  return getStringFromWasm0(ptr, len)
+}
+
+export function lengths() {
+  const bytes = new Uint8Array(16)
+  t1 = bytes.length
+  t2 = bytes.byteLength
+  t3 = new Float64Array(4).byteLength
+  t4 = new ArrayBuffer(8).byteLength
+  t5 = new Int32Array(new ArrayBuffer(16), 4).length
+  t6 = new Uint16Array([1, 2, 3]).length
+  t7 = new DataView(new ArrayBuffer(8)).byteLength
+  t8 = new TextEncoder().encode('héllo').length
+  t9 = Uint32Array.BYTES_PER_ELEMENT
+}
+
+export function unused_allocations(a) {
+  const buffer = new ArrayBuffer(1024)
+  const view = new DataView(buffer)
+  view.setUint32(0, a | 0)
+  const bytes = new Uint8Array(buffer)
+  bytes[1] = a + 1
+  bytes.fill(0)
+  new Float64Array(1)
+  // `a` may be iterable
+  new Float32Array(a)
+}
+
+export function used_writes(a) {
+  const buffer = new ArrayBuffer(4)
+  const bytes = new Uint8Array(buffer)
+  bytes[0] = a
+  new DataView(buffer).setUint8(1, a)
+  const unused = new Uint8Array(4)
+  unused[0] = a
+  t1 = new Uint8Array(buffer)[0]
+}
+
+export function shared_memory(a) {
+  const bytes = new Uint8Array(a.buffer)
+  bytes[0] = 1
+  const view = new Uint8Array(a)
+  view.set([1, 2])
+}
+
+export function element_reads(a) {
+  const bytes = new Uint8Array(2)
+  t1 = bytes[1]
+  t2 = bytes[2]
+  t3 = bytes instanceof Uint8Array
+  t4 = bytes instanceof Int8Array
+  t5 = new ArrayBuffer(1) instanceof ArrayBuffer
+  t6 = typeof new BigInt64Array(1)[0]
+}
+
+export function huge_length() {
+  effect()
+  new Float64Array(1e300)
+  unreachable()
+}
+
+export function negative_length() {
+  effect()
+  new ArrayBuffer(-1)
+  unreachable()
+}
+
+export function index_lengths() {
+  t1 = new Uint8Array(3 / 2).length
+  t2 = new Uint8Array(-1 / 2).length
+}
+
+export function unknown_length(a) {
+  t1 = new Uint8Array(a ? 2 ** 53 : 1).length
+}
+
+export function view_geometry(n) {
+  try {
+    new Uint32Array(new ArrayBuffer(7))
+    t1 = 'ok'
+  } catch {
+    t1 = 'threw'
+  }
+  try {
+    new Uint32Array(new ArrayBuffer(8), 2)
+    t2 = 'ok'
+  } catch {
+    t2 = 'threw'
+  }
+  try {
+    new Uint8Array(new ArrayBuffer(8), 9)
+    t3 = 'ok'
+  } catch {
+    t3 = 'threw'
+  }
+  try {
+    new Uint16Array(new ArrayBuffer(8), 2, 4)
+    t4 = 'ok'
+  } catch {
+    t4 = 'threw'
+  }
+  t5 = new Uint16Array(new ArrayBuffer(8), 2, 3).length
+  t6 = new DataView(new ArrayBuffer(8), 2).byteLength
+  new Uint32Array(new ArrayBuffer(8), n)
+}
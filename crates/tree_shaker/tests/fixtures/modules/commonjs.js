@@ -0,0 +1,43 @@
+// @entry /main.js
+import lib, { add } from './lib.cjs';
+import * as ns from './lib.cjs';
+import app from './app.js';
+import interop from './interop.cjs';
+console.log(add(1, 2), lib.version, ns.sub, app, interop);
+
+// @file /lib.cjs
+exports.add = function (a, b) {
+  return a + b;
+};
+exports.sub = function (a, b) {
+  return a - b;
+};
+exports.mul = function (a, b) {
+  return a * b;
+};
+exports.version = '1.0.0';
+
+// @file /app.js
+const { format } = require('./utils.js');
+module.exports = format('x');
+
+// @file /utils.js
+const helper = require('./helper.js');
+module.exports = {
+  format(s) {
+    return helper.wrap(s);
+  },
+  unused() {
+    return helper.other();
+  },
+};
+
+// @file /helper.js
+console.log('helper loaded');
+exports.wrap = (s) => `[${s}]`;
+exports.other = () => 'other';
+
+// @file /interop.cjs
+exports.__esModule = true;
+exports.default = 'interop';
+exports.named = 'named';
@@ -0,0 +1,49 @@
+// @entry /main.js
+import { a, b, utils } from './index.js';
+import * as ns from './index.js';
+a();
+b();
+utils.c();
+ns.d();
+console.log(ns.shared, ns.default);
+
+// @file /index.js
+export * from './a.js';
+export * from './b.js';
+export * from './chain.js';
+export * as utils from './c.js';
+export * from './unused.js';
+export default 'index';
+
+// @file /a.js
+export function a() {
+  console.log('a');
+}
+export const shared = 'from a';
+export function unusedA() {}
+export default 'a-default';
+
+// @file /b.js
+export function b() {
+  console.log('b');
+}
+export const shared = 'from b';
+
+// @file /chain.js
+export * from './d.js';
+export { a } from './a.js';
+
+// @file /c.js
+export function c() {
+  console.log('c');
+}
+export function unusedC() {}
+
+// @file /d.js
+export function d() {
+  console.log('d');
+}
+export function unusedD() {}
+
+// @file /unused.js
+export function e() {}
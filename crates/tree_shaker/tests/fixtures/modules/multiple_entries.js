@@ -0,0 +1,30 @@
+// @entry /main.js
+import { format, unusedInMain } from './shared';
+export function render(value) {
+  return format(value, "main");
+}
+
+// @entry /worker.js
+import { format, parse } from './shared';
+self.onmessage = (e) => {
+  postMessage(format(parse(e.data), "worker"));
+};
+
+// @entry /sw.js
+import { VERSION } from './shared';
+export const cacheName = "cache-" + VERSION;
+
+// @file /shared.js
+export const VERSION = 1;
+export function format(value, source) {
+  return source + ":" + value;
+}
+export function parse(data) {
+  return JSON.parse(data);
+}
+export function unusedInMain() {
+  return "unused";
+}
+export function unusedEverywhere() {
+  return "unused";
+}
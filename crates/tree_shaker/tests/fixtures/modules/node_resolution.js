@@ -0,0 +1,45 @@
+// @entry /src/main.js
+import { pick } from 'exports-pkg';
+import { helper } from 'exports-pkg/utils/helper';
+import legacy from 'legacy-pkg';
+import scoped from '@scope/pkg/sub';
+import local from './lib';
+import missing from './missing.js';
+console.log(pick(), helper(), legacy, scoped, local, missing);
+
+// @file /src/lib/index.js
+export default 'local';
+
+// @file /node_modules/exports-pkg/package.json
+{
+  "name": "exports-pkg",
+  "main": "./dist/index.cjs",
+  "exports": {
+    ".": {
+      "require": "./dist/index.cjs",
+      "import": "./dist/index.mjs"
+    },
+    "./utils/*": "./dist/utils/*.js"
+  }
+}
+
+// @file /node_modules/exports-pkg/dist/index.mjs
+export function pick() {
+  return 'esm';
+}
+export function unused() {}
+
+// @file /node_modules/exports-pkg/dist/index.cjs
+exports.pick = () => 'cjs';
+
+// @file /node_modules/exports-pkg/dist/utils/helper.js
+export const helper = () => 'helper';
+
+// @file /node_modules/legacy-pkg/package.json
+{ "name": "legacy-pkg", "module": "es/index.js", "main": "lib/index.js" }
+
+// @file /node_modules/legacy-pkg/es/index.js
+export default 'legacy';
+
+// @file /node_modules/@scope/pkg/sub/index.js
+export default 'scoped';
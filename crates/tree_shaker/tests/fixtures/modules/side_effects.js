@@ -0,0 +1,56 @@
+// @entry /main.js
+import { unused } from 'pure-lib';
+import { used } from 'pure-lib/used';
+import 'globs-lib/src/polyfill.js';
+import 'globs-lib/src/styles.css.js';
+import 'globs-lib/src/pure.js';
+import { impure } from './impure.js';
+import './unused-local.js';
+import { run } from './registry.js';
+import './plugin.js';
+used();
+run();
+
+// @file /impure.js
+console.log('impure loaded');
+export const impure = 1;
+
+// @file /unused-local.js
+export const value = 1;
+
+// @file /registry.js
+export const registry = [];
+export function run() {
+  for (const plugin of registry) {
+    plugin();
+  }
+}
+
+// @file /plugin.js
+import { registry } from './registry.js';
+registry.push(() => console.log('plugin'));
+
+// @file /node_modules/pure-lib/package.json
+{ "name": "pure-lib", "sideEffects": false }
+
+// @file /node_modules/pure-lib/index.js
+console.log('pure-lib loaded');
+export function unused() {}
+
+// @file /node_modules/pure-lib/used.js
+console.log('pure-lib/used loaded');
+export function used() {
+  console.log('used');
+}
+
+// @file /node_modules/globs-lib/package.json
+{ "name": "globs-lib", "sideEffects": ["./src/polyfill.js", "*.css.js"] }
+
+// @file /node_modules/globs-lib/src/polyfill.js
+globalThis.polyfilled = true;
+
+// @file /node_modules/globs-lib/src/styles.css.js
+document.head.append('style');
+
+// @file /node_modules/globs-lib/src/pure.js
+console.log('pure.js loaded');
@@ -0,0 +1,18 @@
+// @entry /main.js
+// @source-map
+import { used } from './lib.js';
+if (used(globalValue)) {
+  console.log('used');
+}
+
+// @file /lib.js
+export function used(a) {
+  console.log(a);
+  return a;
+}
+export function unused() {
+  console.log('unused');
+}
+
+// @file /lib.js.map
+{"version":3,"sources":["/src/lib.ts"],"sourcesContent":["// compiled\n\nexport function used(a: number) {\n  console.log(a);\n  return a;\n}\nexport function unused() {\n  console.log('unused');\n}\n"],"names":[],"mappings":"AAEA;EACE;EACA;AACF;AACA;EACE;AACF"}
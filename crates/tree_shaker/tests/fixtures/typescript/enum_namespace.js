@@ -0,0 +1,83 @@
+// @entry /main.ts
+import { Color, Direction, Dynamic, Flags, Utils, Geometry } from './lib';
+import Legacy = require('./legacy');
+import area = Geometry.Shapes.area;
+
+const enum Size {
+  Small = 1,
+  Medium,
+  Large = Medium * 2,
+}
+
+enum Unused {
+  A,
+  B,
+}
+
+enum Effect {
+  A = sideEffect(),
+}
+
+export function main() {
+  console.log(Size.Small, Size.Medium, Size.Large);
+  console.log(Color.Red, Color[Color.Green], Color[Color.Cyan]);
+  console.log(Direction.Up, Flags.Write);
+  console.log(Utils.double(2), Utils.version);
+  console.log(area(2));
+  console.log(Legacy.name, new Legacy());
+}
+
+export { Dynamic, Flags, Utils, Geometry };
+
+// @file /lib.ts
+export enum Color {
+  Red,
+  Green = 'green',
+  Blue = 4,
+  Cyan,
+}
+
+export enum Direction {
+  Up = 'UP',
+  Down = 'DOWN',
+}
+
+export enum Flags {
+  None = 0,
+  Read = 1 << 0,
+  Write = 1 << 1,
+  ReadWrite = Read | Write,
+}
+
+export enum Dynamic {
+  A = Math.random(),
+  B,
+  C = A * 2,
+}
+
+export namespace Utils {
+  export const version = '1.0';
+  export function double(x: number) {
+    return x * 2;
+  }
+  export function unused() {}
+  export interface Options {}
+  export type Id = string;
+}
+
+export namespace Geometry.Shapes {
+  const PI = 3;
+  export function area(r: number) {
+    return PI * r * r;
+  }
+}
+
+export namespace Types {
+  export type A = string;
+}
+
+// @file /legacy.ts
+class Legacy {
+  static name = 'legacy';
+}
+export = Legacy;
@@ -0,0 +1,67 @@
+// @entry /main.ts
+import type { Options } from './types';
+import { type Shape, createShape, unusedHelper } from './shapes';
+export type { Options };
+
+interface Point {
+  x: number;
+  y: number;
+}
+type Pair<T> = [T, T];
+declare const injected: string;
+declare function external(value: unknown): void;
+declare class Ambient {}
+declare module 'virtual' {
+  export const value: number;
+}
+declare global {
+  interface Window { shaken: boolean }
+}
+
+function distance(a: Point, b: Point): number {
+  return Math.sqrt((a.x - b.x) ** 2 + (a.y - b.y) ** 2);
+}
+
+function overloaded(value: string): string;
+function overloaded(value: number): number;
+function overloaded(value: any) {
+  return value;
+}
+
+const origin = { x: 0, y: 0 } satisfies Point;
+const pair = [1, 2] as Pair<number>;
+const shape = createShape<Shape>('circle')!;
+let assigned: number;
+(assigned as any) = 1;
+assigned! += 1;
+
+export function main(options?: Options) {
+  console.log(distance(origin, { x: pair[0], y: <number>pair[1] }));
+  console.log(overloaded('a'), shape, assigned);
+}
+
+export abstract class Base<T> implements Point {
+  declare tag: string;
+  abstract area(): number;
+  public x = 1;
+  protected y!: number;
+  [key: string]: unknown;
+
+  constructor(private readonly name: string, public value?: T) {}
+
+  describe(this: Base<T>): string {
+    return this.name;
+  }
+}
+
+// @file /types.ts
+export interface Options {
+  verbose: boolean;
+}
+
+// @file /shapes.ts
+export type Shape = 'circle' | 'square';
+export function createShape<T extends string>(kind: T) {
+  return { kind };
+}
+export function unusedHelper() {}
@@ -1,24 +1,129 @@
-use std::fs;
+use std::{fs, io};
 
 use insta::{assert_snapshot, glob};
 use oxc::{codegen::CodegenOptions, minifier::MinifierOptions};
-use tree_shaker::{TreeShakeConfig, TreeShakeOptions, tree_shake, vfs::SingleFileFs};
+use oxc_sourcemap::SourcemapVisualizer;
+use rustc_hash::FxHashMap;
+use tree_shaker::{
+  TreeShakeConfig, TreeShakeOptions, TreeShakeTargetEnv, tree_shake,
+  vfs::{NodeResolver, ResolverHost, SingleFileFs, StdFs, Vfs},
+};
 
-fn do_tree_shake(input: String) -> String {
+/// An in-memory file system, with Node.js style module resolution.
+#[derive(Default)]
+struct MemoryFs {
+  files: FxHashMap<String, String>,
+  resolver: NodeResolver,
+}
+
+impl Vfs for MemoryFs {
+  fn resolve_module(&self, importer: &str, specifier: &str) -> Option<String> {
+    self.resolver.resolve(self, importer, specifier)
+  }
+
+  fn read_file(&self, path: &str) -> io::Result<String> {
+    self.files.get(path).cloned().ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+  }
+
+  fn normalize_path(&self, path: String) -> String {
+    StdFs.normalize_path(path)
+  }
+
+  fn has_side_effects(&self, path: &str) -> bool {
+    self.resolver.has_side_effects(self, path)
+  }
+
+  fn read_source_map(&self, path: &str) -> Option<String> {
+    self.files.get(&format!("{}.map", path)).cloned()
+  }
+}
+
+impl ResolverHost for MemoryFs {
+  fn is_file(&self, path: &str) -> bool {
+    self.files.contains_key(path)
+  }
+
+  fn is_dir(&self, path: &str) -> bool {
+    let prefix = if path.ends_with('/') { path.to_string() } else { format!("{}/", path) };
+    self.files.keys().any(|key| key.starts_with(&prefix))
+  }
+
+  fn read_to_string(&self, path: &str) -> Option<String> {
+    self.files.get(path).cloned()
+  }
+}
+
+fn get_options<F: Vfs>(vfs: F, input: &str) -> TreeShakeOptions<F> {
   let do_minify = input.contains("@minify");
   let react_jsx = input.contains("@react-jsx");
+  let source_map = input.contains("@source-map");
+  let safest = input.contains("@safest");
+  let target_env = match input.split("@env ").nth(1).and_then(|s| s.split_whitespace().next()) {
+    Some("browser") => TreeShakeTargetEnv::Browser,
+    Some("node") => TreeShakeTargetEnv::Node,
+    Some("worker") => TreeShakeTargetEnv::Worker,
+    _ => TreeShakeTargetEnv::Unknown,
+  };
+  let preset = if safest { TreeShakeConfig::safest() } else { TreeShakeConfig::recommended() };
+  let mut config = preset.with_react_jsx(react_jsx).with_target_env(target_env);
+  for line in input.lines() {
+    if let Some((path, value)) = line.strip_prefix("// @define ").and_then(|s| s.split_once('=')) {
+      config = config.with_define(path, serde_json::from_str(value).unwrap());
+    }
+    if let Some(method) = line.strip_prefix("// @pure-console ") {
+      config = config.with_pure_console_method(method.trim());
+    }
+  }
+  TreeShakeOptions {
+    vfs,
+    config,
+    minify_options: do_minify.then(|| MinifierOptions { mangle: None, ..Default::default() }),
+    codegen_options: CodegenOptions { annotation_comments: true, ..Default::default() },
+    source_map,
+  }
+}
+
+fn do_tree_shake(input: String) -> String {
   let result = tree_shake(
-    TreeShakeOptions {
-      vfs: SingleFileFs(input),
-      config: TreeShakeConfig::recommended().with_react_jsx(react_jsx),
-      minify_options: do_minify.then(|| MinifierOptions { mangle: None, ..Default::default() }),
-      codegen_options: CodegenOptions { annotation_comments: true, ..Default::default() },
-    },
-    SingleFileFs::ENTRY_PATH.to_string(),
+    get_options(SingleFileFs(input.clone()), &input),
+    vec![SingleFileFs::ENTRY_PATH.to_string()],
   );
   result.codegen_return[SingleFileFs::ENTRY_PATH].code.clone()
 }
 
+/// Files are separated by `// @entry <path>` or `// @file <path>` lines.
+fn do_tree_shake_modules(input: String) -> String {
+  let mut files = FxHashMap::default();
+  let mut entries = vec![];
+  let mut current = None;
+  for line in input.lines() {
+    if let Some(path) = line.strip_prefix("// @entry ") {
+      entries.push(path.to_string());
+      current = Some(path.to_string());
+    } else if let Some(path) = line.strip_prefix("// @file ") {
+      current = Some(path.to_string());
+    } else if let Some(current) = &current {
+      let content: &mut String = files.entry(current.clone()).or_default();
+      content.push_str(line);
+      content.push('\n');
+    }
+  }
+
+  let result = tree_shake(get_options(MemoryFs { files, ..Default::default() }, &input), entries);
+  let mut paths = result.codegen_return.keys().collect::<Vec<_>>();
+  paths.sort();
+  let mut output = String::new();
+  for path in paths {
+    output.push_str(&format!("// @file {}\n", path));
+    let codegen_return = &result.codegen_return[path];
+    output.push_str(&codegen_return.code);
+    if let Some(map) = &codegen_return.map {
+      output.push_str(&SourcemapVisualizer::new(&codegen_return.code, map).into_visualizer_text());
+    }
+  }
+  output
+}
+
 #[test]
 fn test() {
   glob!("fixtures/**/*.js", |path| {
@@ -26,7 +131,11 @@ fn test() {
     let mut settings = insta::Settings::clone_current();
     settings.set_prepend_module_to_snapshot(false);
     settings.bind(|| {
-      assert_snapshot!(do_tree_shake(input));
+      if input.starts_with("// @entry ") {
+        assert_snapshot!(do_tree_shake_modules(input));
+      } else {
+        assert_snapshot!(do_tree_shake(input));
+      }
     })
   });
 }
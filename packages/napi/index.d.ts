@@ -3,13 +3,15 @@
 
 /* auto-generated by NAPI-RS */
 
-export declare function treeShake(input: string, preset: 'safest' | 'recommended' | 'smallest' | 'disabled', minify: boolean): TreeShakeResultBinding
+export declare function treeShake(input: string, preset: 'safest' | 'recommended' | 'smallest' | 'disabled', minify: boolean, sourceMap?: boolean): TreeShakeResultBinding
 export interface TreeShakeEntryResultBinding {
   output: Record<string, string>
+  sourceMaps: Record<string, string>
   diagnostics: Array<string>
 }
-export declare function treeShakeEntry(entryPath: string, preset: 'safest' | 'recommended' | 'smallest' | 'disabled', minify: boolean): TreeShakeEntryResultBinding
+export declare function treeShakeEntries(entryPaths: string[], preset: 'safest' | 'recommended' | 'smallest' | 'disabled', minify: boolean, sourceMap?: boolean): TreeShakeEntryResultBinding
 export declare class TreeShakeResultBinding {
   output: string
+  sourceMap?: string
   diagnostics: Array<string>
 }
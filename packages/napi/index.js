@@ -310,8 +310,8 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { TreeShakeResultBinding, treeShake, treeShakeEntry } = nativeBinding
+const { TreeShakeResultBinding, treeShake, treeShakeEntries } = nativeBinding
 
 module.exports.TreeShakeResultBinding = TreeShakeResultBinding
 module.exports.treeShake = treeShake
-module.exports.treeShakeEntry = treeShakeEntry
+module.exports.treeShakeEntries = treeShakeEntries
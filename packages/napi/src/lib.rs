@@ -6,25 +6,33 @@ use napi_derive::napi;
 use oxc::{codegen::CodegenOptions, minifier::MinifierOptions};
 use tree_shaker::{
   TreeShakeOptions,
-  vfs::{SingleFileFs, StdFs, Vfs},
+  vfs::{NodeResolverFs, SingleFileFs, Vfs},
 };
 
 #[napi]
 pub struct TreeShakeResultBinding {
   pub output: String,
+  pub source_map: Option<String>,
   pub diagnostics: Vec<String>,
 }
 
 #[napi(
-  ts_args_type = "input: string, preset: 'safest' | 'recommended' | 'smallest' | 'disabled', minify: boolean"
+  ts_args_type = "input: string, preset: 'safest' | 'recommended' | 'smallest' | 'disabled', minify: boolean, sourceMap?: boolean"
 )]
-pub fn tree_shake(source_text: String, preset: String, minify: bool) -> TreeShakeResultBinding {
+pub fn tree_shake(
+  source_text: String,
+  preset: String,
+  minify: bool,
+  source_map: Option<bool>,
+) -> TreeShakeResultBinding {
   let result = tree_shaker::tree_shake(
-    get_options(SingleFileFs(source_text), preset.as_str(), minify),
-    SingleFileFs::ENTRY_PATH.to_string(),
+    get_options(SingleFileFs(source_text), preset.as_str(), minify, source_map.unwrap_or(false)),
+    vec![SingleFileFs::ENTRY_PATH.to_string()],
   );
+  let codegen_result = &result.codegen_return[SingleFileFs::ENTRY_PATH];
   TreeShakeResultBinding {
-    output: result.codegen_return[SingleFileFs::ENTRY_PATH].code.clone(),
+    output: codegen_result.code.clone(),
+    source_map: codegen_result.map.as_ref().map(|map| map.to_json_string()),
     diagnostics: result.diagnostics.into_iter().collect(),
   }
 }
@@ -32,27 +40,46 @@ pub fn tree_shake(source_text: String, preset: String, minify: bool) -> TreeShak
 #[napi(object)]
 pub struct TreeShakeEntryResultBinding {
   pub output: HashMap<String, String>,
+  pub source_maps: HashMap<String, String>,
   pub diagnostics: Vec<String>,
 }
 
+/// Imports are resolved like Node.js does. Input source maps are read from `<path>.map` next to
+/// each module.
 #[napi(
-  ts_args_type = "entryPath: string, preset: 'safest' | 'recommended' | 'smallest' | 'disabled', minify: boolean"
+  ts_args_type = "entryPaths: string[], preset: 'safest' | 'recommended' | 'smallest' | 'disabled', minify: boolean, sourceMap?: boolean"
 )]
-pub fn tree_shake_entry(
-  entry_path: String,
+pub fn tree_shake_entries(
+  entry_paths: Vec<String>,
   preset: String,
   minify: bool,
+  source_map: Option<bool>,
 ) -> TreeShakeEntryResultBinding {
-  let result =
-    tree_shaker::tree_shake(get_options(StdFs, preset.as_str(), minify), entry_path.clone());
+  let result = tree_shaker::tree_shake(
+    get_options(NodeResolverFs::default(), preset.as_str(), minify, source_map.unwrap_or(false)),
+    entry_paths,
+  );
   let mut output = HashMap::default();
+  let mut source_maps = HashMap::default();
   for (entry, codegen_result) in result.codegen_return {
+    if let Some(map) = codegen_result.map {
+      source_maps.insert(entry.clone(), map.to_json_string());
+    }
     output.insert(entry, codegen_result.code);
   }
-  TreeShakeEntryResultBinding { output, diagnostics: result.diagnostics.into_iter().collect() }
+  TreeShakeEntryResultBinding {
+    output,
+    source_maps,
+    diagnostics: result.diagnostics.into_iter().collect(),
+  }
 }
 
-fn get_options<F: Vfs>(vfs: F, preset: &str, minify: bool) -> TreeShakeOptions<F> {
+fn get_options<F: Vfs>(
+  vfs: F,
+  preset: &str,
+  minify: bool,
+  source_map: bool,
+) -> TreeShakeOptions<F> {
   TreeShakeOptions {
     vfs,
     config: match preset {
@@ -64,5 +91,6 @@ fn get_options<F: Vfs>(vfs: F, preset: &str, minify: bool) -> TreeShakeOptions<F
     },
     minify_options: minify.then(|| MinifierOptions { mangle: None, ..Default::default() }),
     codegen_options: CodegenOptions { minify, ..Default::default() },
+    source_map,
   }
 }
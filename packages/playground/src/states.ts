@@ -54,7 +54,7 @@ function treeShake(...args: Parameters<(typeof import('@kermanx/tree-shaker'))['
     }).catch(err => {
       console.error(err)
       library.value = {
-        tree_shake: () => ({ output: `Failed to load library.\n${err}`, diagnostics: [], free() { } }),
+        tree_shake: () => ({ output: `Failed to load library.\n${err}`, source_map: undefined, diagnostics: [], free() { } }),
         Result: null!,
       }
     })
@@ -71,10 +71,10 @@ function treeShake(...args: Parameters<(typeof import('@kermanx/tree-shaker'))['
   }
 }
 
-const copyOnly = computed(() => treeShake(debouncedInput.value, "disabled", false, false))
-const minifiedOnly = computed(() => treeShake(debouncedInput.value, "disabled", true, false))
-const treeShakedOnly = computed(() => treeShake(debouncedInput.value, preset.value, false, alwaysInline.value))
-const treeShakedMinified = computed(() => treeShake(treeShakedOnly.value.output, "disabled", true, false))
+const copyOnly = computed(() => treeShake(debouncedInput.value, "disabled", false, false, false))
+const minifiedOnly = computed(() => treeShake(debouncedInput.value, "disabled", true, false, false))
+const treeShakedOnly = computed(() => treeShake(debouncedInput.value, preset.value, false, alwaysInline.value, false))
+const treeShakedMinified = computed(() => treeShake(treeShakedOnly.value.output, "disabled", true, false, false))
 
 const result = computed(() => {
   return {
@@ -10,6 +10,7 @@ use wasm_bindgen::prelude::*;
 #[wasm_bindgen(getter_with_clone)]
 pub struct Result {
   pub output: String,
+  pub source_map: Option<String>,
   pub diagnostics: Vec<String>,
 }
 
@@ -19,6 +20,7 @@ pub fn tree_shake(
   preset: String,
   do_minify: bool,
   always_inline_literal: bool,
+  source_map: bool,
 ) -> Result {
   console_error_panic_hook::set_once();
 
@@ -45,11 +47,14 @@ pub fn tree_shake(
         comments: !do_minify,
         ..Default::default()
       },
+      source_map,
     },
-    SingleFileFs::ENTRY_PATH.to_string(),
+    vec![SingleFileFs::ENTRY_PATH.to_string()],
   );
+  let codegen_return = &result.codegen_return[SingleFileFs::ENTRY_PATH];
   Result {
-    output: result.codegen_return[SingleFileFs::ENTRY_PATH].code.clone(),
+    output: codegen_return.code.clone(),
+    source_map: codegen_return.map.as_ref().map(|map| map.to_json_string()),
     diagnostics: result.diagnostics.into_iter().collect(),
   }
 }
@@ -10,8 +10,9 @@ fn run_tree_shaker(source_text: String) -> String {
       config: TreeShakeConfig::recommended(),
       minify_options: None,
       codegen_options: Default::default(),
+      source_map: false,
     },
-    SingleFileFs::ENTRY_PATH.to_string(),
+    vec![SingleFileFs::ENTRY_PATH.to_string()],
   );
 
   result.codegen_return[SingleFileFs::ENTRY_PATH].code.clone()